@@ -38,17 +38,15 @@
 #[openbrush::contract]
 pub mod access_control {
     use ink_prelude::string::String;
+    use ink_storage::traits::PackedLayout;
     use ink_storage::traits::SpreadAllocate;
-    use openbrush::{
-        modifier_definition,
-        modifiers,
-        storage::Mapping,
-        traits::Storage,
-    };
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::modifier_definition;
+    use openbrush::modifiers;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -56,23 +54,33 @@ pub mod access_control {
         Custom(String),
     }
 
-    pub const DEFAULT_ADMIN_ROLE: [u8; 32] = &hex::decode("0x00");
+    pub const DEFAULT_ADMIN_ROLE: [u8; 32] = [0; 32];
+
+    /// The storage of `AccessControl`, accessed by its modifiers
+    pub trait AccessControlStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut AccessControl;
+    }
+
+    impl AccessControlStorage for AccessControl {
+        fn contract(&mut self) -> &mut AccessControl {
+            self
+        }
+    }
 
     /// @dev Modifier that checks that an account has a specific role. Reverts
     /// with a standardized message including the required role.
     /// The format of the revert reason is given by the following regular expression:
     /// /^AccessControl: account (0x[0-9a-f]{40}) is missing role (0x[0-9a-f]{64})$/
     /// _Available since v4.1._
-    ///The type of `T` should be the trait which implements the storage
-    ///This will be implemented in Sol2Ink in upcoming version
     #[modifier_definition]
     pub fn only_role<T, F, R>(instance: &mut T, body: F, role: [u8; 32]) -> Result<R, Error>
     where
-        T: AccessControl,
+        T: AccessControlStorage,
         F: FnOnce(&mut T) -> Result<R, Error>,
     {
-        self._check_role(role)?;
-        body(instance);
+        instance.contract()._check_role_bytes_32(role)?;
+        body(instance)
     }
 
     /// @dev Emitted when `newAdminRole` is set as ``role``'s admin role, replacing `previousAdminRole`
@@ -116,11 +124,13 @@ pub mod access_control {
         sender: AccountId,
     }
 
-    #[derive(Default, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
     pub struct RoleData {
-        members: Mapping<AccountId, bool>,
-        admin_role: [u8; 32],
+        pub admin_role: [u8; 32],
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -129,6 +139,8 @@ pub mod access_control {
     #[openbrush::upgradeable_storage(STORAGE_KEY)]
     pub struct Data {
         pub roles: Mapping<[u8; 32], RoleData>,
+        /// The `members` of the `_roles`
+        pub roles_members: Mapping<([u8; 32], AccountId), bool>,
     }
 
     #[ink(storage)]
@@ -144,11 +156,16 @@ pub mod access_control {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
         }
 
+        #[ink(message)]
+        pub fn default_admin_role(&self) -> Result<[u8; 32], Error> {
+            return Ok(DEFAULT_ADMIN_ROLE);
+        }
+
         /// @dev See {IERC165-supportsInterface}.
         #[ink(message)]
         pub fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
             return Ok(interface_id == i_access_control.interface_id
-                || super.supports_interface(interface_id)?)
+                || super.supports_interface(interface_id)?);
         }
 
         /// @dev Returns `true` if `account` has been granted `role`.
@@ -156,36 +173,39 @@ pub mod access_control {
         pub fn has_role(&self, role: [u8; 32], account: AccountId) -> Result<bool, Error> {
             return Ok(self
                 .data
-                .roles
-                .get(&role)
-                .unwrap_or_default()
-                .members
-                .get(&account)
-                .unwrap_or_default())
+                .roles_members
+                .get(&(role, account))
+                .unwrap_or_default());
         }
 
         /// @dev Revert with a standard message if `msg.sender` is missing `role`.
         /// Overriding this function changes the behavior of the {onlyRole} modifier.
         /// Format of the revert message is described in {_checkRole}.
         /// _Available since v4.6._
-        fn _check_role(&self, role: [u8; 32]) -> Result<(), Error> {
-            self._check_role(role, msg.sender)?;
+        fn _check_role_bytes_32(&self, role: [u8; 32]) -> Result<(), Error> {
+            self._check_role_bytes_32_address(role, self.env().caller())?;
             Ok(())
         }
 
         /// @dev Revert with a standard message if `account` is missing `role`.
         /// The format of the revert reason is given by the following regular expression:
         /// /^AccessControl: account (0x[0-9a-f]{40}) is missing role (0x[0-9a-f]{64})$/
-        fn _check_role(&self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
+        fn _check_role_bytes_32_address(
+            &self,
+            role: [u8; 32],
+            account: AccountId,
+        ) -> Result<(), Error> {
             if !self.has_role(role, account)? {
-                revert(
-                    (abi.encode_packed(
-                        "AccessControl: account ",
-                        strings.to_hex_string(account)?,
-                        " is missing role ",
-                        strings.to_hex_string((role as u128), 32)?,
-                    )? as String),
-                )?;
+                return Err(Error::Custom(ink_prelude::format!(
+                    "{}{}{}{}",
+                    "AccessControl: account ",
+                    strings.to_hex_string(account)?,
+                    " is missing role ",
+                    strings.to_hex_string(
+                        u128::from_be_bytes(<[u8; 16]>::try_from(&role[16..]).unwrap()),
+                        32
+                    )?
+                )));
             }
             Ok(())
         }
@@ -195,7 +215,7 @@ pub mod access_control {
         /// To change a role's admin, use {_setRoleAdmin}.
         #[ink(message)]
         pub fn get_role_admin(&self, role: [u8; 32]) -> Result<[u8; 32], Error> {
-            return Ok(self.data.roles.get(&role).unwrap_or_default().admin_role)
+            return Ok(self.data.roles.get(&role).unwrap_or_default().admin_role);
         }
 
         /// @dev Grants `role` to `account`.
@@ -205,7 +225,7 @@ pub mod access_control {
         /// - the caller must have ``role``'s admin role.
         /// May emit a {RoleGranted} event.
         #[ink(message)]
-        # [modifiers (only_role (self . get_role_admin (role) ?) ?)]
+        # [modifiers (only_role (self . get_role_admin (role) ?))]
         pub fn grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             self._grant_role(role, account)?;
             Ok(())
@@ -217,7 +237,7 @@ pub mod access_control {
         /// - the caller must have ``role``'s admin role.
         /// May emit a {RoleRevoked} event.
         #[ink(message)]
-        # [modifiers (only_role (self . get_role_admin (role) ?) ?)]
+        # [modifiers (only_role (self . get_role_admin (role) ?))]
         pub fn revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             self._revoke_role(role, account)?;
             Ok(())
@@ -237,7 +257,7 @@ pub mod access_control {
             if account != self.env().caller() {
                 return Err(Error::Custom(String::from(
                     "AccessControl: can only renounce roles for self",
-                )))
+                )));
             }
             self._revoke_role(role, account)?;
             Ok(())
@@ -265,7 +285,9 @@ pub mod access_control {
         /// Emits a {RoleAdminChanged} event.
         fn _set_role_admin(&mut self, role: [u8; 32], admin_role: [u8; 32]) -> Result<(), Error> {
             let previous_admin_role: [u8; 32] = self.get_role_admin(role)?;
-            self.data.roles.get(&role).unwrap_or_default().admin_role = admin_role;
+            let mut entry = self.data.roles.get(&role).unwrap_or_default();
+            entry.admin_role = admin_role;
+            self.data.roles.insert(&role, &(entry));
             self.env().emit_event(RoleAdminChanged {
                 role,
                 previous_admin_role,
@@ -279,13 +301,7 @@ pub mod access_control {
         /// May emit a {RoleGranted} event.
         fn _grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             if !self.has_role(role, account)? {
-                self.data
-                    .roles
-                    .get(&role)
-                    .unwrap_or_default()
-                    .members
-                    .get(&account)
-                    .unwrap_or_default() = true;
+                self.data.roles_members.insert(&(role, account), &(true));
                 self.env().emit_event(RoleGranted {
                     role,
                     account,
@@ -300,13 +316,7 @@ pub mod access_control {
         /// May emit a {RoleRevoked} event.
         fn _revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             if self.has_role(role, account)? {
-                self.data
-                    .roles
-                    .get(&role)
-                    .unwrap_or_default()
-                    .members
-                    .get(&account)
-                    .unwrap_or_default() = false;
+                self.data.roles_members.insert(&(role, account), &(false));
                 self.env().emit_event(RoleRevoked {
                     role,
                     account,
@@ -12,23 +12,15 @@
 /// _Available since v3.1._
 #[openbrush::contract]
 pub mod erc_1155 {
-    use ink_prelude::{
-        string::String,
-        vec::Vec,
-    };
+    use ink_prelude::string::String;
+    use ink_prelude::vec::Vec;
     use ink_storage::traits::SpreadAllocate;
-    use openbrush::{
-        storage::Mapping,
-        traits::{
-            AccountIdExt,
-            Storage,
-            ZERO_ADDRESS,
-        },
-    };
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use openbrush::storage::Mapping;
+    use openbrush::traits::AccountIdExt;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -120,7 +112,7 @@ pub mod erc_1155 {
         pub fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
             return Ok(interface_id == ierc_1155.interface_id
                 || interface_id == ierc_1155_metadata_uri.interface_id
-                || super.supports_interface(interface_id)?)
+                || super.supports_interface(interface_id)?);
         }
 
         /// @dev See {IERC1155MetadataURI-uri}.
@@ -130,8 +122,8 @@ pub mod erc_1155 {
         /// Clients calling this function must replace the `\{id\}` substring with the
         /// actual token type ID.
         #[ink(message)]
-        pub fn uri(&self) -> Result<String, Error> {
-            return Ok(self.data.uri)
+        pub fn uri(&self, arg_0: u128) -> Result<String, Error> {
+            return Ok(self.data.uri.clone());
         }
 
         /// @dev See {IERC1155-balanceOf}.
@@ -142,9 +134,9 @@ pub mod erc_1155 {
             if account.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: address zero is not a valid owner",
-                )))
+                )));
             }
-            return Ok(self.data.balances.get(&(id, account)).unwrap_or_default())
+            return Ok(self.data.balances.get(&(id, account)).unwrap_or_default());
         }
 
         /// @dev See {IERC1155-balanceOfBatch}.
@@ -156,24 +148,21 @@ pub mod erc_1155 {
             accounts: Vec<AccountId>,
             ids: Vec<u128>,
         ) -> Result<Vec<u128>, Error> {
-            if accounts.length != ids.length {
+            if (accounts.len() as u128) != (ids.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: accounts and ids length mismatch",
-                )))
+                )));
             }
-            let batch_balances: Vec<u128> = vec![u128::default(); accounts.length];
+            let batch_balances: Vec<u128> = vec![u128::default(); accounts.len()];
             let i: u128 = 0;
-            while i < accounts.length {
-                batch_balances.insert(
-                    &i,
-                    &(self.balance_of(
-                        accounts.get(&i).unwrap_or_default(),
-                        ids.get(&i).unwrap_or_default(),
-                    )?),
-                );
-                i += 1;
+            while i < (accounts.len() as u128) {
+                batch_balances[(i as usize)] =
+                    self.balance_of(accounts[(i as usize)], ids[(i as usize)])?;
+                i = i
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
-            return Ok(batch_balances)
+            return Ok(batch_balances);
         }
 
         /// @dev See {IERC1155-setApprovalForAll}.
@@ -198,7 +187,7 @@ pub mod erc_1155 {
                 .data
                 .operator_approvals
                 .get(&(account, operator))
-                .unwrap_or_default())
+                .unwrap_or_default());
         }
 
         /// @dev See {IERC1155-safeTransferFrom}.
@@ -211,10 +200,12 @@ pub mod erc_1155 {
             amount: u128,
             data: Vec<u8>,
         ) -> Result<(), Error> {
-            if from != self.env().caller() || self.is_approved_for_all(from, msg.sender)? {
+            if !(from == self.env().caller()
+                || self.is_approved_for_all(from, self.env().caller())?)
+            {
                 return Err(Error::Custom(String::from(
                     "ERC1155: caller is not token owner nor approved",
-                )))
+                )));
             }
             self._safe_transfer_from(from, to, id, amount, data)?;
             Ok(())
@@ -230,10 +221,12 @@ pub mod erc_1155 {
             amounts: Vec<u128>,
             data: Vec<u8>,
         ) -> Result<(), Error> {
-            if from != self.env().caller() || self.is_approved_for_all(from, msg.sender)? {
+            if !(from == self.env().caller()
+                || self.is_approved_for_all(from, self.env().caller())?)
+            {
                 return Err(Error::Custom(String::from(
                     "ERC1155: caller is not token owner nor approved",
-                )))
+                )));
             }
             self._safe_batch_transfer_from(from, to, ids, amounts, data)?;
             Ok(())
@@ -257,7 +250,7 @@ pub mod erc_1155 {
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: transfer to the zero address",
-                )))
+                )));
             }
             let operator: AccountId = self.env().caller();
             let ids: Vec<u128> = self._as_singleton_array(id)?;
@@ -267,16 +260,20 @@ pub mod erc_1155 {
             if from_balance < amount {
                 return Err(Error::Custom(String::from(
                     "ERC1155: insufficient balance for transfer",
-                )))
+                )));
             }
-            // Please handle unchecked blocks manually >>>
             self.data
                 .balances
-                .insert(&(id, from), &(from_balance - amount));
-            // <<< Please handle unchecked blocks manually
+                .insert(&(id, from), &(from_balance.wrapping_sub(amount)));
             self.data.balances.insert(
                 &(id, to),
-                &(self.data.balances.get(&(id, to)).unwrap_or_default() + amount),
+                &(self
+                    .data
+                    .balances
+                    .get(&(id, to))
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
             );
             self.env().emit_event(TransferSingle {
                 operator,
@@ -303,38 +300,44 @@ pub mod erc_1155 {
             amounts: Vec<u128>,
             data: Vec<u8>,
         ) -> Result<(), Error> {
-            if ids.length != amounts.length {
+            if (ids.len() as u128) != (amounts.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: ids and amounts length mismatch",
-                )))
+                )));
             }
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: transfer to the zero address",
-                )))
+                )));
             }
             let operator: AccountId = self.env().caller();
             self._before_token_transfer(operator, from, to, ids, amounts, data)?;
             let i: u128 = 0;
-            while i < ids.length {
-                let id: u128 = ids.get(&i).unwrap_or_default();
-                let amount: u128 = amounts.get(&i).unwrap_or_default();
+            while i < (ids.len() as u128) {
+                let id: u128 = ids[(i as usize)];
+                let amount: u128 = amounts[(i as usize)];
                 let from_balance: u128 = self.data.balances.get(&(id, from)).unwrap_or_default();
                 if from_balance < amount {
                     return Err(Error::Custom(String::from(
                         "ERC1155: insufficient balance for transfer",
-                    )))
+                    )));
                 }
-                // Please handle unchecked blocks manually >>>
                 self.data
                     .balances
-                    .insert(&(id, from), &(from_balance - amount));
-                // <<< Please handle unchecked blocks manually
+                    .insert(&(id, from), &(from_balance.wrapping_sub(amount)));
                 self.data.balances.insert(
                     &(id, to),
-                    &(self.data.balances.get(&(id, to)).unwrap_or_default() + amount),
+                    &(self
+                        .data
+                        .balances
+                        .get(&(id, to))
+                        .unwrap_or_default()
+                        .checked_add(amount)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
                 );
-                i += 1;
+                i = i
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             self.env().emit_event(TransferBatch {
                 operator,
@@ -382,7 +385,7 @@ pub mod erc_1155 {
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: mint to the zero address",
-                )))
+                )));
             }
             let operator: AccountId = self.env().caller();
             let ids: Vec<u128> = self._as_singleton_array(id)?;
@@ -390,7 +393,13 @@ pub mod erc_1155 {
             self._before_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
             self.data.balances.insert(
                 &(id, to),
-                &(self.data.balances.get(&(id, to)).unwrap_or_default() + amount),
+                &(self
+                    .data
+                    .balances
+                    .get(&(id, to))
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
             );
             self.env().emit_event(TransferSingle {
                 operator,
@@ -427,27 +436,30 @@ pub mod erc_1155 {
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: mint to the zero address",
-                )))
+                )));
             }
-            if ids.length != amounts.length {
+            if (ids.len() as u128) != (amounts.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: ids and amounts length mismatch",
-                )))
+                )));
             }
             let operator: AccountId = self.env().caller();
             self._before_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
             let i: u128 = 0;
-            while i < ids.length {
+            while i < (ids.len() as u128) {
                 self.data.balances.insert(
-                    &(ids.get(&i).unwrap_or_default(), to),
+                    &(ids[(i as usize)], to),
                     &(self
                         .data
                         .balances
-                        .get(&(ids.get(&i).unwrap_or_default(), to))
+                        .get(&(ids[(i as usize)], to))
                         .unwrap_or_default()
-                        + amounts.get(&i).unwrap_or_default()),
+                        .checked_add(amounts[(i as usize)])
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
                 );
-                i += 1;
+                i = i
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             self.env().emit_event(TransferBatch {
                 operator,
@@ -477,7 +489,7 @@ pub mod erc_1155 {
             if from.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: burn from the zero address",
-                )))
+                )));
             }
             let operator: AccountId = self.env().caller();
             let ids: Vec<u128> = self._as_singleton_array(id)?;
@@ -487,13 +499,11 @@ pub mod erc_1155 {
             if from_balance < amount {
                 return Err(Error::Custom(String::from(
                     "ERC1155: burn amount exceeds balance",
-                )))
+                )));
             }
-            // Please handle unchecked blocks manually >>>
             self.data
                 .balances
-                .insert(&(id, from), &(from_balance - amount));
-            // <<< Please handle unchecked blocks manually
+                .insert(&(id, from), &(from_balance.wrapping_sub(amount)));
             self.env().emit_event(TransferSingle {
                 operator,
                 from,
@@ -518,31 +528,31 @@ pub mod erc_1155 {
             if from.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC1155: burn from the zero address",
-                )))
+                )));
             }
-            if ids.length != amounts.length {
+            if (ids.len() as u128) != (amounts.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: ids and amounts length mismatch",
-                )))
+                )));
             }
             let operator: AccountId = self.env().caller();
             self._before_token_transfer(operator, from, ZERO_ADDRESS.into(), ids, amounts, "")?;
             let i: u128 = 0;
-            while i < ids.length {
-                let id: u128 = ids.get(&i).unwrap_or_default();
-                let amount: u128 = amounts.get(&i).unwrap_or_default();
+            while i < (ids.len() as u128) {
+                let id: u128 = ids[(i as usize)];
+                let amount: u128 = amounts[(i as usize)];
                 let from_balance: u128 = self.data.balances.get(&(id, from)).unwrap_or_default();
                 if from_balance < amount {
                     return Err(Error::Custom(String::from(
                         "ERC1155: burn amount exceeds balance",
-                    )))
+                    )));
                 }
-                // Please handle unchecked blocks manually >>>
                 self.data
                     .balances
-                    .insert(&(id, from), &(from_balance - amount));
-                // <<< Please handle unchecked blocks manually
-                i += 1;
+                    .insert(&(id, from), &(from_balance.wrapping_sub(amount)));
+                i = i
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             self.env().emit_event(TransferBatch {
                 operator,
@@ -566,7 +576,7 @@ pub mod erc_1155 {
             if owner == operator {
                 return Err(Error::Custom(String::from(
                     "ERC1155: setting approval status for self",
-                )))
+                )));
             }
             self.data
                 .operator_approvals
@@ -641,20 +651,26 @@ pub mod erc_1155 {
             data: Vec<u8>,
         ) -> Result<(), Error> {
             if to.is_contract()? {
-                // Please handle try/catch blocks manually >>>
-                if true {
-                    // try IERC1155Receiver(to).onERC1155Received(operator, from, id, amount, data) returns (bytes4 response) {
-                    if response != ierc_1155_receiver.on_erc_1155_received.selector {
-                        revert("ERC1155: ERC1155Receiver rejected tokens")?;
+                match ERC1155ReceiverRef::on_erc_1155_received_builder(
+                    &to, operator, from, id, amount, data,
+                )
+                .fire()
+                {
+                    Ok(Ok(response)) => {
+                        if response != ierc_1155_receiver.on_erc_1155_received.selector {
+                            return Err(Error::Custom(String::from(
+                                "ERC1155: ERC1155Receiver rejected tokens",
+                            )));
+                        }
+                    }
+                    Ok(Err(Error::Custom(reason))) => {
+                        return Err(Error::Custom(reason));
+                    }
+                    _ => {
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: transfer to non-ERC1155Receiver implementer",
+                        )));
                     }
-                } else if false {
-                    // catch Error(string reason) {
-                    revert(reason)?;
-                    // <<< Please handle try/catch blocks manually
-                } else if false {
-                    // catch {
-                    revert("ERC1155: transfer to non-ERC1155Receiver implementer")?;
-                    // <<< Please handle try/catch blocks manually
                 }
             }
             Ok(())
@@ -670,20 +686,26 @@ pub mod erc_1155 {
             data: Vec<u8>,
         ) -> Result<(), Error> {
             if to.is_contract()? {
-                // Please handle try/catch blocks manually >>>
-                if true {
-                    // try IERC1155Receiver(to).onERC1155BatchReceived(operator, from, ids, amounts, data) returns ( bytes4 response ) {
-                    if response != ierc_1155_receiver.on_erc_1155_batch_received.selector {
-                        revert("ERC1155: ERC1155Receiver rejected tokens")?;
+                match ERC1155ReceiverRef::on_erc_1155_batch_received_builder(
+                    &to, operator, from, ids, amounts, data,
+                )
+                .fire()
+                {
+                    Ok(Ok(response)) => {
+                        if response != ierc_1155_receiver.on_erc_1155_batch_received.selector {
+                            return Err(Error::Custom(String::from(
+                                "ERC1155: ERC1155Receiver rejected tokens",
+                            )));
+                        }
+                    }
+                    Ok(Err(Error::Custom(reason))) => {
+                        return Err(Error::Custom(reason));
+                    }
+                    _ => {
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: transfer to non-ERC1155Receiver implementer",
+                        )));
                     }
-                } else if false {
-                    // catch Error(string reason) {
-                    revert(reason)?;
-                    // <<< Please handle try/catch blocks manually
-                } else if false {
-                    // catch {
-                    revert("ERC1155: transfer to non-ERC1155Receiver implementer")?;
-                    // <<< Please handle try/catch blocks manually
                 }
             }
             Ok(())
@@ -691,8 +713,8 @@ pub mod erc_1155 {
 
         fn _as_singleton_array(&self, element: u128) -> Result<Vec<u128>, Error> {
             let array: Vec<u128> = vec![u128::default(); 1];
-            array.insert(&0, &(element));
-            return Ok(array)
+            array[0] = element;
+            return Ok(array);
         }
 
     }
@@ -28,18 +28,12 @@
 pub mod erc_20 {
     use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
-    use openbrush::{
-        storage::Mapping,
-        traits::{
-            AccountIdExt,
-            Storage,
-            ZERO_ADDRESS,
-        },
-    };
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use openbrush::storage::Mapping;
+    use openbrush::traits::AccountIdExt;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -72,17 +66,33 @@ pub mod erc_20 {
     }
 
     /// This enum is added just to test enum parsing
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Enum {
-        First,
-        Second,
+        #[default]
+        First = 0,
+        Second = 1,
+    }
+
+    impl Enum {
+        /// Converts the discriminant to the value of the enum
+        ///
+        /// returns None if the discriminant is out of the range of the enum
+        pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+            match index.try_into().ok()? {
+                0 => Some(Self::First),
+                1 => Some(Self::Second),
+                _ => None,
+            }
+        }
     }
 
     /// This struct is added just to test struct parsing
-    #[derive(Default, Encode, Decode)]
+    #[derive(Default, Clone, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct Struct {
-        field_1: u128,
-        field_2: u128,
+        pub field_1: u128,
+        pub field_2: u128,
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -121,14 +131,14 @@ pub mod erc_20 {
         /// @dev Returns the name of the token.
         #[ink(message)]
         pub fn name(&self) -> Result<String, Error> {
-            return Ok(self.data.name)
+            return Ok(self.data.name.clone());
         }
 
         /// @dev Returns the symbol of the token, usually a shorter version of the
         /// name.
         #[ink(message)]
         pub fn symbol(&self) -> Result<String, Error> {
-            return Ok(self.data.symbol)
+            return Ok(self.data.symbol.clone());
         }
 
         /// @dev Returns the number of decimals used to get its user representation.
@@ -142,19 +152,19 @@ pub mod erc_20 {
         /// {IERC20-balanceOf} and {IERC20-transfer}.
         #[ink(message)]
         pub fn decimals(&self) -> Result<u8, Error> {
-            return Ok(18)
+            return Ok(18);
         }
 
         /// @dev See {IERC20-totalSupply}.
         #[ink(message)]
         pub fn total_supply(&self) -> Result<u128, Error> {
-            return Ok(self.data.total_supply)
+            return Ok(self.data.total_supply);
         }
 
         /// @dev See {IERC20-balanceOf}.
         #[ink(message)]
         pub fn balance_of(&self, account: AccountId) -> Result<u128, Error> {
-            return Ok(self.data.balances.get(&account).unwrap_or_default())
+            return Ok(self.data.balances.get(&account).unwrap_or_default());
         }
 
         /// @dev See {IERC20-transfer}.
@@ -165,7 +175,7 @@ pub mod erc_20 {
         pub fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error> {
             let owner: AccountId = self.env().caller();
             self._transfer(owner, to, amount)?;
-            return Ok(true)
+            return Ok(true);
         }
 
         /// @dev See {IERC20-allowance}.
@@ -175,7 +185,7 @@ pub mod erc_20 {
                 .data
                 .allowances
                 .get(&(owner, spender))
-                .unwrap_or_default())
+                .unwrap_or_default());
         }
 
         /// @dev See {IERC20-approve}.
@@ -187,7 +197,7 @@ pub mod erc_20 {
         pub fn approve(&mut self, spender: AccountId, amount: u128) -> Result<bool, Error> {
             let owner: AccountId = self.env().caller();
             self._approve(owner, spender, amount)?;
-            return Ok(true)
+            return Ok(true);
         }
 
         /// @dev See {IERC20-transferFrom}.
@@ -210,7 +220,7 @@ pub mod erc_20 {
             let spender: AccountId = self.env().caller();
             self._spend_allowance(from, spender, amount)?;
             self._transfer(from, to, amount)?;
-            return Ok(true)
+            return Ok(true);
         }
 
         /// @dev Atomically increases the allowance granted to `spender` by the caller.
@@ -229,9 +239,11 @@ pub mod erc_20 {
             self._approve(
                 owner,
                 spender,
-                self.allowance(owner, spender)? + added_value,
+                self.allowance(owner, spender)?
+                    .checked_add(added_value)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
             )?;
-            return Ok(true)
+            return Ok(true);
         }
 
         /// @dev Atomically decreases the allowance granted to `spender` by the caller.
@@ -253,12 +265,14 @@ pub mod erc_20 {
             if current_allowance < subtracted_value {
                 return Err(Error::Custom(String::from(
                     "ERC20: decreased allowance below zero",
-                )))
+                )));
             }
-            // Please handle unchecked blocks manually >>>
-            self._approve(owner, spender, current_allowance - subtracted_value)?;
-            // <<< Please handle unchecked blocks manually
-            return Ok(true)
+            self._approve(
+                owner,
+                spender,
+                current_allowance.wrapping_sub(subtracted_value),
+            )?;
+            return Ok(true);
         }
 
         /// @dev Moves `amount` of tokens from `from` to `to`.
@@ -273,26 +287,32 @@ pub mod erc_20 {
             if from.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC20: transfer from the zero address",
-                )))
+                )));
             }
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC20: transfer to the zero address",
-                )))
+                )));
             }
             self._before_token_transfer(from, to, amount)?;
             let from_balance: u128 = self.data.balances.get(&from).unwrap_or_default();
             if from_balance < amount {
                 return Err(Error::Custom(String::from(
                     "ERC20: transfer amount exceeds balance",
-                )))
+                )));
             }
-            // Please handle unchecked blocks manually >>>
-            self.data.balances.insert(&from, &(from_balance - amount));
-            // <<< Please handle unchecked blocks manually
+            self.data
+                .balances
+                .insert(&from, &(from_balance.wrapping_sub(amount)));
             self.data.balances.insert(
                 &to,
-                &(self.data.balances.get(&to).unwrap_or_default() + amount),
+                &(self
+                    .data
+                    .balances
+                    .get(&to)
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
             );
             self.env().emit_event(Transfer {
                 from,
@@ -312,13 +332,23 @@ pub mod erc_20 {
             if account.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC20: mint to the zero address",
-                )))
+                )));
             }
             self._before_token_transfer(ZERO_ADDRESS.into(), account, amount)?;
-            self.data.total_supply += amount;
+            self.data.total_supply = self
+                .data
+                .total_supply
+                .checked_add(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             self.data.balances.insert(
                 &account,
-                &(self.data.balances.get(&account).unwrap_or_default() + amount),
+                &(self
+                    .data
+                    .balances
+                    .get(&account)
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
             );
             self.env().emit_event(Transfer {
                 from: ZERO_ADDRESS.into(),
@@ -339,21 +369,23 @@ pub mod erc_20 {
             if account.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC20: burn from the zero address",
-                )))
+                )));
             }
             self._before_token_transfer(account, ZERO_ADDRESS.into(), amount)?;
             let account_balance: u128 = self.data.balances.get(&account).unwrap_or_default();
             if account_balance < amount {
                 return Err(Error::Custom(String::from(
                     "ERC20: burn amount exceeds balance",
-                )))
+                )));
             }
-            // Please handle unchecked blocks manually >>>
             self.data
                 .balances
-                .insert(&account, &(account_balance - amount));
-            // <<< Please handle unchecked blocks manually
-            self.data.total_supply -= amount;
+                .insert(&account, &(account_balance.wrapping_sub(amount)));
+            self.data.total_supply = self
+                .data
+                .total_supply
+                .checked_sub(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             self.env().emit_event(Transfer {
                 from: account,
                 to: ZERO_ADDRESS.into(),
@@ -379,12 +411,12 @@ pub mod erc_20 {
             if owner.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC20: approve from the zero address",
-                )))
+                )));
             }
             if spender.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC20: approve to the zero address",
-                )))
+                )));
             }
             self.data.allowances.insert(&(owner, spender), &(amount));
             self.env().emit_event(Approval {
@@ -406,13 +438,11 @@ pub mod erc_20 {
             amount: u128,
         ) -> Result<(), Error> {
             let current_allowance: u128 = self.allowance(owner, spender)?;
-            if current_allowance != u128.max {
+            if current_allowance != u128::MAX {
                 if current_allowance < amount {
-                    return Err(Error::Custom(String::from("ERC20: insufficient allowance")))
+                    return Err(Error::Custom(String::from("ERC20: insufficient allowance")));
                 }
-                // Please handle unchecked blocks manually >>>
-                self._approve(owner, spender, current_allowance - amount)?;
-                // <<< Please handle unchecked blocks manually
+                self._approve(owner, spender, current_allowance.wrapping_sub(amount))?;
             }
             Ok(())
         }
@@ -11,23 +11,15 @@
 /// {ERC721Enumerable}.
 #[openbrush::contract]
 pub mod erc_721 {
-    use ink_prelude::{
-        string::String,
-        vec::Vec,
-    };
+    use ink_prelude::string::String;
+    use ink_prelude::vec::Vec;
     use ink_storage::traits::SpreadAllocate;
-    use openbrush::{
-        storage::Mapping,
-        traits::{
-            AccountIdExt,
-            Storage,
-            ZERO_ADDRESS,
-        },
-    };
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use openbrush::storage::Mapping;
+    use openbrush::traits::AccountIdExt;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -109,7 +101,7 @@ pub mod erc_721 {
         pub fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
             return Ok(interface_id == ierc_721.interface_id
                 || interface_id == ierc_721_metadata.interface_id
-                || super.supports_interface(interface_id)?)
+                || super.supports_interface(interface_id)?);
         }
 
         /// @dev See {IERC721-balanceOf}.
@@ -118,9 +110,9 @@ pub mod erc_721 {
             if owner.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC721: address zero is not a valid owner",
-                )))
+                )));
             }
-            return Ok(self.data.balances.get(&owner).unwrap_or_default())
+            return Ok(self.data.balances.get(&owner).unwrap_or_default());
         }
 
         /// @dev See {IERC721-ownerOf}.
@@ -128,21 +120,21 @@ pub mod erc_721 {
         pub fn owner_of(&self, token_id: u128) -> Result<AccountId, Error> {
             let owner: AccountId = self.data.owners.get(&token_id).unwrap_or_default();
             if owner.is_zero() {
-                return Err(Error::Custom(String::from("ERC721: invalid token ID")))
+                return Err(Error::Custom(String::from("ERC721: invalid token ID")));
             }
-            return Ok(owner)
+            return Ok(owner);
         }
 
         /// @dev See {IERC721Metadata-name}.
         #[ink(message)]
         pub fn name(&self) -> Result<String, Error> {
-            return Ok(self.data.name)
+            return Ok(self.data.name.clone());
         }
 
         /// @dev See {IERC721Metadata-symbol}.
         #[ink(message)]
         pub fn symbol(&self) -> Result<String, Error> {
-            return Ok(self.data.symbol)
+            return Ok(self.data.symbol.clone());
         }
 
         /// @dev See {IERC721Metadata-tokenURI}.
@@ -150,18 +142,18 @@ pub mod erc_721 {
         pub fn token_uri(&self, token_id: u128) -> Result<String, Error> {
             self._require_minted(token_id)?;
             let base_uri: String = self._base_uri()?;
-            return Ok(if Vec::<u8>::from(base_uri).length > 0 {
-                (abi.encode_packed(base_uri, token_id.to_string()?)? as String)
+            return Ok(if (Vec::<u8>::from(base_uri).len() as u128) > 0 {
+                ink_prelude::format!("{}{}", base_uri, token_id.to_string()?)
             } else {
                 ""
-            })
+            });
         }
 
         /// @dev Base URI for computing {tokenURI}. If set, the resulting URI for each
         /// token will be the concatenation of the `baseURI` and the `tokenId`. Empty
         /// by default, can be overridden in child contracts.
         fn _base_uri(&self) -> Result<String, Error> {
-            return Ok("")
+            return Ok("");
         }
 
         /// @dev See {IERC721-approve}.
@@ -171,12 +163,14 @@ pub mod erc_721 {
             if to == owner {
                 return Err(Error::Custom(String::from(
                     "ERC721: approval to current owner",
-                )))
+                )));
             }
-            if self.env().caller() != owner || self.is_approved_for_all(owner, msg.sender)? {
+            if !(self.env().caller() == owner
+                || self.is_approved_for_all(owner, self.env().caller())?)
+            {
                 return Err(Error::Custom(String::from(
                     "ERC721: approve caller is not token owner nor approved for all",
-                )))
+                )));
             }
             self._approve(to, token_id)?;
             Ok(())
@@ -186,7 +180,7 @@ pub mod erc_721 {
         #[ink(message)]
         pub fn get_approved(&self, token_id: u128) -> Result<AccountId, Error> {
             self._require_minted(token_id)?;
-            return Ok(self.data.token_approvals.get(&token_id).unwrap_or_default())
+            return Ok(self.data.token_approvals.get(&token_id).unwrap_or_default());
         }
 
         /// @dev See {IERC721-setApprovalForAll}.
@@ -211,7 +205,7 @@ pub mod erc_721 {
                 .data
                 .operator_approvals
                 .get(&(owner, operator))
-                .unwrap_or_default())
+                .unwrap_or_default());
         }
 
         /// @dev See {IERC721-transferFrom}.
@@ -226,7 +220,7 @@ pub mod erc_721 {
             if !self._is_approved_or_owner(self.env().caller(), token_id)? {
                 return Err(Error::Custom(String::from(
                     "ERC721: caller is not token owner nor approved",
-                )))
+                )));
             }
             self._transfer(from, to, token_id)?;
             Ok(())
@@ -234,19 +228,19 @@ pub mod erc_721 {
 
         /// @dev See {IERC721-safeTransferFrom}.
         #[ink(message)]
-        pub fn safe_transfer_from(
+        pub fn safe_transfer_from_address_address_uint_256(
             &mut self,
             from: AccountId,
             to: AccountId,
             token_id: u128,
         ) -> Result<(), Error> {
-            self.safe_transfer_from(from, to, token_id, "")?;
+            self.safe_transfer_from_address_address_uint_256_bytes(from, to, token_id, "")?;
             Ok(())
         }
 
         /// @dev See {IERC721-safeTransferFrom}.
         #[ink(message)]
-        pub fn safe_transfer_from(
+        pub fn safe_transfer_from_address_address_uint_256_bytes(
             &mut self,
             from: AccountId,
             to: AccountId,
@@ -256,7 +250,7 @@ pub mod erc_721 {
             if !self._is_approved_or_owner(self.env().caller(), token_id)? {
                 return Err(Error::Custom(String::from(
                     "ERC721: caller is not token owner nor approved",
-                )))
+                )));
             }
             self._safe_transfer(from, to, token_id, data)?;
             Ok(())
@@ -284,7 +278,7 @@ pub mod erc_721 {
             if !self._check_on_erc_721_received(from, to, token_id, data)? {
                 return Err(Error::Custom(String::from(
                     "ERC721: transfer to non ERC721Receiver implementer",
-                )))
+                )));
             }
             Ok(())
         }
@@ -299,7 +293,7 @@ pub mod erc_721 {
                 .owners
                 .get(&token_id)
                 .unwrap_or_default()
-                .is_zero())
+                .is_zero());
         }
 
         /// @dev Returns whether `spender` is allowed to manage `tokenId`.
@@ -309,7 +303,7 @@ pub mod erc_721 {
             let owner: AccountId = erc_721.owner_of(token_id)?;
             return Ok((spender == owner
                 || self.is_approved_for_all(owner, spender)?
-                || self.get_approved(token_id)? == spender))
+                || self.get_approved(token_id)? == spender));
         }
 
         /// @dev Safely mints `tokenId` and transfers it to `to`.
@@ -317,14 +311,18 @@ pub mod erc_721 {
         /// - `tokenId` must not exist.
         /// - If `to` refers to a smart contract, it must implement {IERC721Receiver-onERC721Received}, which is called upon a safe transfer.
         /// Emits a {Transfer} event.
-        fn _safe_mint(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
-            self._safe_mint(to, token_id, "")?;
+        fn _safe_mint_address_uint_256(
+            &mut self,
+            to: AccountId,
+            token_id: u128,
+        ) -> Result<(), Error> {
+            self._safe_mint_address_uint_256_bytes(to, token_id, "")?;
             Ok(())
         }
 
         /// @dev Same as {xref-ERC721-_safeMint-address-uint256-}[`_safeMint`], with an additional `data` parameter which is
         /// forwarded in {IERC721Receiver-onERC721Received} to contract recipients.
-        fn _safe_mint(
+        fn _safe_mint_address_uint_256_bytes(
             &mut self,
             to: AccountId,
             token_id: u128,
@@ -334,7 +332,7 @@ pub mod erc_721 {
             if !self._check_on_erc_721_received(ZERO_ADDRESS.into(), to, token_id, data)? {
                 return Err(Error::Custom(String::from(
                     "ERC721: transfer to non ERC721Receiver implementer",
-                )))
+                )));
             }
             Ok(())
         }
@@ -349,15 +347,22 @@ pub mod erc_721 {
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC721: mint to the zero address",
-                )))
+                )));
             }
             if self._exists(token_id)? {
-                return Err(Error::Custom(String::from("ERC721: token already minted")))
+                return Err(Error::Custom(String::from("ERC721: token already minted")));
             }
             self._before_token_transfer(ZERO_ADDRESS.into(), to, token_id)?;
-            self.data
-                .balances
-                .insert(&to, &(self.data.balances.get(&to).unwrap_or_default() + 1));
+            self.data.balances.insert(
+                &to,
+                &(self
+                    .data
+                    .balances
+                    .get(&to)
+                    .unwrap_or_default()
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
             self.data.owners.insert(&token_id, &(to));
             self.env().emit_event(Transfer {
                 from: ZERO_ADDRESS.into(),
@@ -377,12 +382,18 @@ pub mod erc_721 {
             let owner: AccountId = erc_721.owner_of(token_id)?;
             self._before_token_transfer(owner, ZERO_ADDRESS.into(), token_id)?;
             // Clear approvals
-            // Sol2Ink Not Implemented yet: delete _tokenApprovals[tokenId];
+            self.data.token_approvals.remove(&token_id);
             self.data.balances.insert(
                 &owner,
-                &(self.data.balances.get(&owner).unwrap_or_default() - 1),
+                &(self
+                    .data
+                    .balances
+                    .get(&owner)
+                    .unwrap_or_default()
+                    .checked_sub(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
             );
-            // Sol2Ink Not Implemented yet: delete _owners[tokenId];
+            self.data.owners.remove(&token_id);
             self.env().emit_event(Transfer {
                 from: owner,
                 to: ZERO_ADDRESS.into(),
@@ -407,23 +418,36 @@ pub mod erc_721 {
             if erc_721.owner_of(token_id)? != from {
                 return Err(Error::Custom(String::from(
                     "ERC721: transfer from incorrect owner",
-                )))
+                )));
             }
             if to.is_zero() {
                 return Err(Error::Custom(String::from(
                     "ERC721: transfer to the zero address",
-                )))
+                )));
             }
             self._before_token_transfer(from, to, token_id)?;
             // Clear approvals from the previous owner
-            // Sol2Ink Not Implemented yet: delete _tokenApprovals[tokenId];
+            self.data.token_approvals.remove(&token_id);
             self.data.balances.insert(
                 &from,
-                &(self.data.balances.get(&from).unwrap_or_default() - 1),
+                &(self
+                    .data
+                    .balances
+                    .get(&from)
+                    .unwrap_or_default()
+                    .checked_sub(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
+            self.data.balances.insert(
+                &to,
+                &(self
+                    .data
+                    .balances
+                    .get(&to)
+                    .unwrap_or_default()
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
             );
-            self.data
-                .balances
-                .insert(&to, &(self.data.balances.get(&to).unwrap_or_default() + 1));
             self.data.owners.insert(&token_id, &(to));
             self.env().emit_event(Transfer { from, to, token_id });
             self._after_token_transfer(from, to, token_id)?;
@@ -451,7 +475,7 @@ pub mod erc_721 {
             approved: bool,
         ) -> Result<(), Error> {
             if owner == operator {
-                return Err(Error::Custom(String::from("ERC721: approve to caller")))
+                return Err(Error::Custom(String::from("ERC721: approve to caller")));
             }
             self.data
                 .operator_approvals
@@ -467,7 +491,7 @@ pub mod erc_721 {
         /// @dev Reverts if the `tokenId` has not been minted yet.
         fn _require_minted(&self, token_id: u128) -> Result<(), Error> {
             if !self._exists(token_id)? {
-                return Err(Error::Custom(String::from("ERC721: invalid token ID")))
+                return Err(Error::Custom(String::from("ERC721: invalid token ID")));
             }
             Ok(())
         }
@@ -487,24 +511,34 @@ pub mod erc_721 {
             data: Vec<u8>,
         ) -> Result<bool, Error> {
             if to.is_contract()? {
-                // Please handle try/catch blocks manually >>>
-                if true {
-                    // try IERC721Receiver(to).onERC721Received(msg.sender, from, tokenId, data) returns (bytes4 retval) {
-                    return Ok(retval == ierc_721_receiver.on_erc_721_received.selector)
-                } else if false {
-                    // catch (bytes reason) {
-                    if reason.length == 0 {
-                        revert("ERC721: transfer to non ERC721Receiver implementer")?;
-                    } else {
-                        // @solidity memory-safe-assembly
-                        // Please handle assembly blocks manually >>>
-                        // revert(add(32, reason), mload(reason))
-                        // <<< Please handle assembly blocks manually
+                match ERC721ReceiverRef::on_erc_721_received_builder(
+                    &to,
+                    self.env().caller(),
+                    from,
+                    token_id,
+                    data,
+                )
+                .fire()
+                {
+                    Ok(Ok(retval)) => {
+                        return Ok(retval == ierc_721_receiver.on_erc_721_received.selector)
+                    }
+                    _ => {
+                        let reason: Vec<u8> = Vec::new();
+                        if (reason.len() as u128) == 0 {
+                            return Err(Error::Custom(String::from(
+                                "ERC721: transfer to non ERC721Receiver implementer",
+                            )));
+                        } else {
+                            // @solidity memory-safe-assembly
+                            // Please handle assembly blocks manually >>>
+                            // revert(add(32, reason), mload(reason))
+                            // <<< Please handle assembly blocks manually
+                        }
                     }
-                    // <<< Please handle try/catch blocks manually
                 }
             } else {
-                return Ok(true)
+                return Ok(true);
             }
         }
 
@@ -6,12 +6,11 @@
 
 #[openbrush::contract]
 pub mod flipper {
+    use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::traits::Storage;
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -49,14 +48,14 @@ pub mod flipper {
         ///to `false` and vice versa.
         #[ink(message)]
         pub fn flip(&mut self) -> Result<(), Error> {
-            self.data.value = !value;
+            self.data.value = !self.data.value;
             Ok(())
         }
 
         ///Simply returns the current value of our `bool`.
         #[ink(message)]
         pub fn get(&self) -> Result<bool, Error> {
-            return Ok(self.data.value)
+            return Ok(self.data.value);
         }
 
     }
@@ -6,12 +6,11 @@
 
 #[openbrush::contract]
 pub mod primitives {
+    use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::traits::Storage;
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -20,18 +19,43 @@ pub mod primitives {
     }
 
 
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum Oper {
-        Add,
-        Sub,
-        Mul,
-        Div,
-        Modulo,
-        Pow,
-        Shl,
-        Shr,
-        Or,
-        And,
-        Xor,
+        #[default]
+        Add = 0,
+        Sub = 1,
+        Mul = 2,
+        Div = 3,
+        Modulo = 4,
+        Pow = 5,
+        Shl = 6,
+        Shr = 7,
+        Or = 8,
+        And = 9,
+        Xor = 10,
+    }
+
+    impl Oper {
+        /// Converts the discriminant to the value of the enum
+        ///
+        /// returns None if the discriminant is out of the range of the enum
+        pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+            match index.try_into().ok()? {
+                0 => Some(Self::Add),
+                1 => Some(Self::Sub),
+                2 => Some(Self::Mul),
+                3 => Some(Self::Div),
+                4 => Some(Self::Modulo),
+                5 => Some(Self::Pow),
+                6 => Some(Self::Shl),
+                7 => Some(Self::Shr),
+                8 => Some(Self::Or),
+                9 => Some(Self::And),
+                10 => Some(Self::Xor),
+                _ => None,
+            }
+        }
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -55,158 +79,224 @@ pub mod primitives {
 
         #[ink(message)]
         pub fn is_mul(&self, op: oper) -> Result<bool, Error> {
-            return Ok(op == oper.mul)
+            return Ok(op == Oper::Mul);
         }
 
         #[ink(message)]
         pub fn return_div(&self) -> Result<oper, Error> {
-            return Ok(oper.div)
+            return Ok(Oper::Div);
         }
 
         #[ink(message)]
         pub fn op_i_64(&self, op: oper, a: i64, b: i64) -> Result<i64, Error> {
-            if op == oper.add {
-                return Ok(a + b)
-            } else if op == oper.sub {
-                return Ok(a - b)
-            } else if op == oper.mul {
-                return Ok(a * b)
-            } else if op == oper.div {
-                return Ok(a / b)
-            } else if op == oper.modulo {
-                return Ok(a % b)
-            } else if op == oper.shl {
-                return Ok(a << b)
-            } else if op == oper.shr {
-                return Ok(a >> b)
+            if op == Oper::Add {
+                return Ok(a
+                    .checked_add(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Sub {
+                return Ok(a
+                    .checked_sub(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Mul {
+                return Ok(a
+                    .checked_mul(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Div {
+                return Ok(a
+                    .checked_div(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Modulo {
+                return Ok(a
+                    .checked_rem(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Shl {
+                return Ok(a << b);
+            } else if op == Oper::Shr {
+                return Ok(a >> b);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn op_u_64(&self, op: oper, a: u64, b: u64) -> Result<u64, Error> {
-            if op == oper.add {
-                return Ok(a + b)
-            } else if op == oper.sub {
-                return Ok(a - b)
-            } else if op == oper.mul {
-                return Ok(a * b)
-            } else if op == oper.div {
-                return Ok(a / b)
-            } else if op == oper.modulo {
-                return Ok(a % b)
-            } else if op == oper.pow {
-                return Ok(a.pow(b as u32))
-            } else if op == oper.shl {
-                return Ok(a << b)
-            } else if op == oper.shr {
-                return Ok(a >> b)
+            if op == Oper::Add {
+                return Ok(a
+                    .checked_add(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Sub {
+                return Ok(a
+                    .checked_sub(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Mul {
+                return Ok(a
+                    .checked_mul(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Div {
+                return Ok(a
+                    .checked_div(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Modulo {
+                return Ok(a
+                    .checked_rem(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Pow {
+                return Ok(a
+                    .checked_pow(
+                        u32::try_from(b)
+                            .map_err(|_| Error::Custom(String::from("Arithmetic overflow")))?,
+                    )
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Shl {
+                return Ok(a << b);
+            } else if op == Oper::Shr {
+                return Ok(a >> b);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn op_u_256(&self, op: oper, a: u128, b: u128) -> Result<u128, Error> {
-            if op == oper.add {
-                return Ok(a + b)
-            } else if op == oper.sub {
-                return Ok(a - b)
-            } else if op == oper.mul {
-                return Ok(a * b)
-            } else if op == oper.div {
-                return Ok(a / b)
-            } else if op == oper.modulo {
-                return Ok(a % b)
-            } else if op == oper.pow {
-                return Ok(a.pow((b as u128) as u32))
-            } else if op == oper.shl {
-                return Ok(a << b)
-            } else if op == oper.shr {
-                return Ok(a >> b)
+            if op == Oper::Add {
+                return Ok(a
+                    .checked_add(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Sub {
+                return Ok(a
+                    .checked_sub(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Mul {
+                return Ok(a
+                    .checked_mul(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Div {
+                return Ok(a
+                    .checked_div(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Modulo {
+                return Ok(a
+                    .checked_rem(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Pow {
+                return Ok(a
+                    .checked_pow(
+                        u32::try_from((b as u128))
+                            .map_err(|_| Error::Custom(String::from("Arithmetic overflow")))?,
+                    )
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Shl {
+                return Ok(a << b);
+            } else if op == Oper::Shr {
+                return Ok(a >> b);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn op_i_256(&self, op: oper, a: i128, b: i128) -> Result<i128, Error> {
-            if op == oper.add {
-                return Ok(a + b)
-            } else if op == oper.sub {
-                return Ok(a - b)
-            } else if op == oper.mul {
-                return Ok(a * b)
-            } else if op == oper.div {
-                return Ok(a / b)
-            } else if op == oper.modulo {
-                return Ok(a % b)
-            } else if op == oper.shl {
-                return Ok(a << b)
-            } else if op == oper.shr {
-                return Ok(a >> b)
+            if op == Oper::Add {
+                return Ok(a
+                    .checked_add(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Sub {
+                return Ok(a
+                    .checked_sub(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Mul {
+                return Ok(a
+                    .checked_mul(b)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+            } else if op == Oper::Div {
+                return Ok(a
+                    .checked_div(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Modulo {
+                return Ok(a
+                    .checked_rem(b)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?);
+            } else if op == Oper::Shl {
+                return Ok(a << b);
+            } else if op == Oper::Shr {
+                return Ok(a >> b);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn return_u_8_6(&self) -> Result<[u8; 6], Error> {
-            return Ok("ABCDEF")
+            return Ok("ABCDEF");
         }
 
         #[ink(message)]
         pub fn op_u_8_5_shift(&self, op: oper, a: [u8; 5], r: u64) -> Result<[u8; 5], Error> {
-            if op == oper.shl {
-                return Ok(a << r)
-            } else if op == oper.shr {
-                return Ok(a >> r)
+            if op == Oper::Shl {
+                return Ok(a << r);
+            } else if op == Oper::Shr {
+                return Ok(a >> r);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn op_u_8_5(&self, op: oper, a: [u8; 5], b: [u8; 5]) -> Result<[u8; 5], Error> {
-            if op == oper.or {
-                return Ok(a | b)
-            } else if op == oper.and {
-                return Ok(a & b)
-            } else if op == oper.xor {
-                return Ok(a ^ b)
+            if op == Oper::Or {
+                return Ok(a | b);
+            } else if op == Oper::And {
+                return Ok(a & b);
+            } else if op == Oper::Xor {
+                return Ok(a ^ b);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn op_u_8_14_shift(&self, op: oper, a: [u8; 14], r: u64) -> Result<[u8; 14], Error> {
-            if op == oper.shl {
-                return Ok(a << r)
-            } else if op == oper.shr {
-                return Ok(a >> r)
+            if op == Oper::Shl {
+                return Ok(a << r);
+            } else if op == Oper::Shr {
+                return Ok(a >> r);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn op_u_8_14(&self, op: oper, a: [u8; 14], b: [u8; 14]) -> Result<[u8; 14], Error> {
-            if op == oper.or {
-                return Ok(a | b)
-            } else if op == oper.and {
-                return Ok(a & b)
-            } else if op == oper.xor {
-                return Ok(a ^ b)
+            if op == Oper::Or {
+                return Ok(a | b);
+            } else if op == Oper::And {
+                return Ok(a & b);
+            } else if op == Oper::Xor {
+                return Ok(a ^ b);
             } else {
-                revert()?;
+                return Err(Error::Custom(String::from(
+                    "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP",
+                )));
             }
         }
 
         #[ink(message)]
         pub fn address_passthrough(&self, a: AccountId) -> Result<AccountId, Error> {
-            return Ok(a)
+            return Ok(a);
         }
 
     }
@@ -7,16 +7,15 @@
 ///example.sol
 #[openbrush::contract]
 pub mod example {
+    use ink_prelude::string::String;
+    use ink_storage::traits::PackedLayout;
     use ink_storage::traits::SpreadAllocate;
-    use openbrush::traits::{
-        AccountIdExt,
-        Storage,
-        ZERO_ADDRESS,
-    };
-    use scale::{
-        Decode,
-        Encode,
-    };
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::traits::AccountIdExt;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
 
     #[derive(Debug, Encode, Decode, PartialEq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -29,44 +28,129 @@ pub mod example {
     pub const FIRST_PID: i32 = 1;
 
     ///Process state
+    #[derive(
+        Default, Debug, Clone, Copy, PartialEq, Encode, Decode, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
     pub enum State {
-        Running,
-        Sleeping,
-        Waiting,
-        Stopped,
-        Zombie,
-        StateCount,
+        #[default]
+        Running = 0,
+        Sleeping = 1,
+        Waiting = 2,
+        Stopped = 3,
+        Zombie = 4,
+        StateCount = 5,
+    }
+
+    impl State {
+        /// Converts the discriminant to the value of the enum
+        ///
+        /// returns None if the discriminant is out of the range of the enum
+        pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+            match index.try_into().ok()? {
+                0 => Some(Self::Running),
+                1 => Some(Self::Sleeping),
+                2 => Some(Self::Waiting),
+                3 => Some(Self::Stopped),
+                4 => Some(Self::Zombie),
+                5 => Some(Self::StateCount),
+                _ => None,
+            }
+        }
     }
 
     ///cards
+    #[derive(
+        Default, Debug, Clone, Copy, PartialEq, Encode, Decode, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
     pub enum Suit {
-        Club,
-        Diamonds,
-        Hearts,
-        Spades,
+        #[default]
+        Club = 0,
+        Diamonds = 1,
+        Hearts = 2,
+        Spades = 3,
     }
 
+    impl Suit {
+        /// Converts the discriminant to the value of the enum
+        ///
+        /// returns None if the discriminant is out of the range of the enum
+        pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+            match index.try_into().ok()? {
+                0 => Some(Self::Club),
+                1 => Some(Self::Diamonds),
+                2 => Some(Self::Hearts),
+                3 => Some(Self::Spades),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(
+        Default, Debug, Clone, Copy, PartialEq, Encode, Decode, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
     pub enum Value {
-        Two,
-        Three,
-        Four,
-        Five,
-        Six,
-        Seven,
-        Eight,
-        Nine,
-        Ten,
-        Jack,
-        Queen,
-        King,
-        Ace,
+        #[default]
+        Two = 0,
+        Three = 1,
+        Four = 2,
+        Five = 3,
+        Six = 4,
+        Seven = 5,
+        Eight = 6,
+        Nine = 7,
+        Ten = 8,
+        Jack = 9,
+        Queen = 10,
+        King = 11,
+        Ace = 12,
     }
 
-    #[derive(Default, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    impl Value {
+        /// Converts the discriminant to the value of the enum
+        ///
+        /// returns None if the discriminant is out of the range of the enum
+        pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+            match index.try_into().ok()? {
+                0 => Some(Self::Two),
+                1 => Some(Self::Three),
+                2 => Some(Self::Four),
+                3 => Some(Self::Five),
+                4 => Some(Self::Six),
+                5 => Some(Self::Seven),
+                6 => Some(Self::Eight),
+                7 => Some(Self::Nine),
+                8 => Some(Self::Ten),
+                9 => Some(Self::Jack),
+                10 => Some(Self::Queen),
+                11 => Some(Self::King),
+                12 => Some(Self::Ace),
+                _ => None,
+            }
+        }
+    }
+
+    #[derive(
+        Default, Clone, Encode, Decode, Debug, PartialEq, SpreadLayout, PackedLayout, SpreadAllocate,
+    )]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
     pub struct card {
-        v: value,
-        s: suit,
+        pub v: value,
+        pub s: suit,
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -94,14 +178,14 @@ pub mod example {
         #[ink(constructor)]
         pub fn new(pid: i32) -> Self {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
-                // Set contract storage
-                instance.data.pid = pid;
-                self.reaped = 3;
-                self.card_1 = card(value.two, suit.club)?;
-                self.card_2 = Card {
+                instance.data.reaped = 3;
+                instance.data.card_1 = card(value.two, suit.club)?;
+                instance.data.card_2 = Card {
                     s: suit.club,
                     v: value.two,
                 };
+                // Set contract storage
+                instance.data.pid = pid;
             })
         }
 
@@ -110,7 +194,7 @@ pub mod example {
         #[ink(message)]
         pub fn is_zombie_reaper(&self) -> Result<bool, Error> {
             // must be pid 1 and not zombie ourselves *
-            return Ok((self.data.pid == FIRST_PID && self.data.state != state.zombie))
+            return Ok((self.data.pid == FIRST_PID && self.data.state != State::Zombie));
         }
 
         ///Returning a constant does not access storage at all, so
@@ -119,26 +203,42 @@ pub mod example {
         pub fn systemd_pid(&self) -> Result<u32, Error> {
             // Note that cast is required to change sign from
             // int32 to uint32
-            return Ok((FIRST_PID as u32))
+            return Ok((FIRST_PID as u32));
         }
 
         ///Convert celcius to fahrenheit
         #[ink(message)]
         pub fn celcius_2_fahrenheit(&self, celcius: i32) -> Result<i32, Error> {
-            let fahrenheit: i32 = celcius * 9 / 5 + 32;
-            return Ok(fahrenheit)
+            let fahrenheit: i32 = celcius
+                .checked_mul(9)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                .checked_div(5)
+                .ok_or(Error::Custom(String::from("Division by zero")))?
+                .checked_add(32)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            return Ok(fahrenheit);
         }
 
         ///Convert fahrenheit to celcius
         #[ink(message)]
         pub fn fahrenheit_2_celcius(&self, fahrenheit: i32) -> Result<i32, Error> {
-            return Ok((fahrenheit - 32) * 5 / 9)
+            return Ok((fahrenheit
+                .checked_sub(32)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?)
+            .checked_mul(5)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+            .checked_div(9)
+            .ok_or(Error::Custom(String::from("Division by zero")))?);
         }
 
         ///is this number a power-of-two
         #[ink(message)]
         pub fn is_power_of_2(&self, n: u128) -> Result<bool, Error> {
-            return Ok(n != 0 && (n & (n - 1)) == 0)
+            return Ok(n != 0
+                && (n
+                    & (n.checked_sub(1)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?))
+                    == 0);
         }
 
         ///calculate the population count (number of set bits) using Brian Kerningham's way
@@ -147,8 +247,12 @@ pub mod example {
             let mut count = Default::default();
             count = 0;
             while n != 0 {
-                n &= (n - 1);
-                count += 1;
+                n &= (n
+                    .checked_sub(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+                count = count
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             Ok(count)
         }
@@ -156,13 +260,18 @@ pub mod example {
         ///calculate the power of base to exp
         #[ink(message)]
         pub fn power(&self, base: u128, exp: u128) -> Result<u128, Error> {
-            return Ok(base.pow(exp as u32))
+            return Ok(base
+                .checked_pow(
+                    u32::try_from(exp)
+                        .map_err(|_| Error::Custom(String::from("Arithmetic overflow")))?,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
         }
 
         ///returns true if the address is 0
         #[ink(message)]
         pub fn is_address_zero(&self, a: AccountId) -> Result<bool, Error> {
-            return Ok(a.is_zero())
+            return Ok(a.is_zero());
         }
 
         ///reverse the bytes in an array of 8 (endian swap)
@@ -181,34 +290,57 @@ pub mod example {
         }
 
         ///This mocks a pid state
-        fn _get_pid_state(&self, pid: u64) -> Result<State, Error> {
+        fn _get_pid_state_uint_64(&self, pid: u64) -> Result<State, Error> {
             let n: u64 = 8;
             let i: u16 = 1;
             while i < 10 {
-                if (i % 3) == 0 {
-                    n *= pid / (i as u64);
+                if (i
+                    .checked_rem(3)
+                    .ok_or(Error::Custom(String::from("Division by zero")))?)
+                    == 0
+                {
+                    n = n
+                        .checked_mul(
+                            pid.checked_div((i as u64))
+                                .ok_or(Error::Custom(String::from("Division by zero")))?,
+                        )
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
                 } else {
-                    n /= 3;
+                    n = n
+                        .checked_div(3)
+                        .ok_or(Error::Custom(String::from("Division by zero")))?;
                 }
-                i += 1;
+                i = i
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
-            return Ok(state(n % (state.state_count as u64))?)
+            return Ok(State::from_index(
+                n.checked_rem((State::StateCount as u64))
+                    .ok_or(Error::Custom(String::from("Division by zero")))?,
+            )
+            .ok_or(Error::Custom(String::from("Enum value out of range")))?);
         }
 
         ///Overloaded function with different return value!
         fn _get_pid_state(&self) -> Result<u32, Error> {
-            return Ok(self.data.reaped)
+            return Ok(self.data.reaped);
         }
 
         #[ink(message)]
         pub fn reap_processes(&mut self) -> Result<(), Error> {
             let n: u32 = 0;
             while n < 100 {
-                if self._get_pid_state(n)? == state.zombie {
+                if self._get_pid_state_uint_64(n)? == State::Zombie {
                     // reap!
-                    self.data.reaped += 1;
+                    self.data.reaped = self
+                        .data
+                        .reaped
+                        .checked_add(1)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
                 }
-                n += 1;
+                n = n
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
             }
             Ok(())
         }
@@ -219,14 +351,19 @@ pub mod example {
             // no initializer means its 0.
             let n: u32 = 0;
             loop {
-                if self._get_pid_state(n)? == state.waiting {
-                    count += 1;
+                if self._get_pid_state_uint_64(n)? == State::Waiting {
+                    count = count
+                        .checked_add(1)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
                 }
-                if n < 1000 {
-                    break
+                n = n
+                    .checked_add(1)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+                if n >= 1000 {
+                    break;
                 }
             }
-            return Ok(count)
+            return Ok(count);
         }
 
         ///This function does a lot of copying
@@ -242,26 +379,26 @@ pub mod example {
         #[ink(message)]
         pub fn ace_of_spaces(&self) -> Result<card, Error> {
             return Ok(Card {
-                s: suit.spades,
-                v: value.ace,
-            })
+                s: Suit::Spades,
+                v: Value::Ace,
+            });
         }
 
         ///score card
         #[ink(message)]
         pub fn score_card(&self, c: card) -> Result<u32, Error> {
             let mut score = Default::default();
-            if c.s == suit.hearts {
-                if c.v == value.ace {
+            if c.s == Suit::Hearts {
+                if c.v == Value::Ace {
                     score = 14;
                 }
-                if c.v == value.king {
+                if c.v == Value::King {
                     score = 13;
                 }
-                if c.v == value.queen {
+                if c.v == Value::Queen {
                     score = 12;
                 }
-                if c.v == value.jack {
+                if c.v == Value::Jack {
                     score = 11;
                 }
             }
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+// The errors of this contract are reported with their position,
+// Sol2Ink reports all of them instead of stopping at the first one
+contract SyntaxErrors {
+    uint256 public value;
+
+    event Stored(uint256 value);
+
+    function store(uint256 newValue) public {
+        uint256 previous = ;
+        value = newValue +;
+        emit Stored(newValue);
+    }
+
+    function reset() public {
+        value = 0 # 1;
+    }
+
+    function get() public view returns (uint256) {
+        return value;
+    }
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+/// @dev Provides a basic access control mechanism with a single owner
+abstract contract Ownable {
+    address private _owner;
+
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    constructor() {
+        _transferOwnership(msg.sender);
+    }
+
+    /// @dev Throws if called by any account other than the owner.
+    modifier onlyOwner() {
+        require(owner() == msg.sender, "Ownable: caller is not the owner");
+        _;
+    }
+
+    /// @dev Returns the address of the current owner.
+    function owner() public view virtual returns (address) {
+        return _owner;
+    }
+
+    /// @dev Transfers ownership of the contract to a new account.
+    function transferOwnership(address newOwner) public virtual onlyOwner {
+        require(newOwner != address(0), "Ownable: new owner is the zero address");
+        _transferOwnership(newOwner);
+    }
+
+    function _transferOwnership(address newOwner) internal virtual {
+        address oldOwner = _owner;
+        _owner = newOwner;
+        emit OwnershipTransferred(oldOwner, newOwner);
+    }
+}
+
+/// @dev Allows the owner to stop the contract
+abstract contract Pausable is Ownable {
+    bool private _paused;
+
+    event Paused(address account);
+
+    constructor(bool paused_) {
+        _paused = paused_;
+    }
+
+    modifier whenNotPaused() {
+        require(!_paused, "Pausable: paused");
+        _;
+    }
+
+    function paused() public view virtual returns (bool) {
+        return _paused;
+    }
+
+    function pause() public virtual onlyOwner {
+        _paused = true;
+        emit Paused(msg.sender);
+    }
+
+    function _beforeTransfer(address from, address to, uint256 amount) internal virtual;
+}
+
+/// @dev Records the number of transfers
+abstract contract Counting is Ownable {
+    uint256 private _transfers;
+
+    function transfers() public view returns (uint256) {
+        return _transfers;
+    }
+
+    function _beforeTransfer(address from, address to, uint256 amount) internal virtual {
+        _transfers += 1;
+    }
+}
+
+/// @dev Pausable token whose balances are managed by the owner
+contract Token is Pausable(false), Counting {
+    mapping(address => uint256) private _balances;
+    uint256 private _supply;
+
+    constructor(uint256 supply) {
+        _supply = supply;
+        _balances[msg.sender] = supply;
+    }
+
+    function balanceOf(address account) public view returns (uint256) {
+        return _balances[account];
+    }
+
+    function transfer(address to, uint256 amount) public whenNotPaused returns (bool) {
+        _beforeTransfer(msg.sender, to, amount);
+        _balances[msg.sender] -= amount;
+        _balances[to] += amount;
+        return true;
+    }
+
+    function pause() public override onlyOwner {
+        require(_supply > 0, "Token: nothing to pause");
+        super.pause();
+    }
+
+    function _beforeTransfer(address from, address to, uint256 amount) internal override(Pausable, Counting) {
+        require(!paused(), "Token: token transfer while paused");
+        super._beforeTransfer(from, to, amount);
+    }
+}
@@ -0,0 +1,234 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///@dev Pausable token whose balances are managed by the owner
+#[openbrush::contract]
+pub mod token {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::modifier_definition;
+    use openbrush::modifiers;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::AccountIdExt;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Token`, accessed by its modifiers
+    pub trait TokenStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Token;
+    }
+
+    impl TokenStorage for Token {
+        fn contract(&mut self) -> &mut Token {
+            self
+        }
+    }
+
+    ///@dev Throws if called by any account other than the owner.
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: TokenStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().owner()? != instance.contract().env().caller() {
+            return Err(Error::Custom(String::from(
+                "Ownable: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[modifier_definition]
+    pub fn when_not_paused<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: TokenStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().data.paused {
+            return Err(Error::Custom(String::from("Pausable: paused")));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Paused {
+        account: AccountId,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub paused: bool,
+        pub transfers: u128,
+        pub balances: Mapping<AccountId, u128>,
+        pub supply: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Token {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Token {
+        #[ink(constructor)]
+        pub fn new(supply: u128) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._transfer_ownership(instance.env().caller())?;
+                let paused: bool = false;
+                instance.data.paused = paused;
+                instance.data.supply = supply;
+                instance
+                    .data
+                    .balances
+                    .insert(&instance.env().caller(), &(supply));
+            })
+        }
+
+        ///@dev Returns the address of the current owner.
+        #[ink(message)]
+        pub fn owner(&self) -> Result<AccountId, Error> {
+            return Ok(self.data.owner);
+        }
+
+        ///@dev Transfers ownership of the contract to a new account.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            if new_owner.is_zero() {
+                return Err(Error::Custom(String::from(
+                    "Ownable: new owner is the zero address",
+                )));
+            }
+            self._transfer_ownership(new_owner)?;
+            Ok(())
+        }
+
+        fn _transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            let old_owner: AccountId = self.data.owner;
+            self.data.owner = new_owner;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner: old_owner,
+                new_owner,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn paused(&self) -> Result<bool, Error> {
+            return Ok(self.data.paused);
+        }
+
+        #[modifiers(only_owner)]
+        fn _pausable_pause(&mut self) -> Result<(), Error> {
+            self.data.paused = true;
+            self.env().emit_event(Paused {
+                account: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfers(&self) -> Result<u128, Error> {
+            return Ok(self.data.transfers);
+        }
+
+        fn _counting_before_transfer(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            self.data.transfers = self
+                .data
+                .transfers
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, account: AccountId) -> Result<u128, Error> {
+            return Ok(self.data.balances.get(&account).unwrap_or_default());
+        }
+
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error> {
+            self._before_transfer(self.env().caller(), to, amount)?;
+            self.data.balances.insert(
+                &self.env().caller(),
+                &(self
+                    .data
+                    .balances
+                    .get(&self.env().caller())
+                    .unwrap_or_default()
+                    .checked_sub(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
+            self.data.balances.insert(
+                &to,
+                &(self
+                    .data
+                    .balances
+                    .get(&to)
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
+            return Ok(true);
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            if self.data.supply <= 0 {
+                return Err(Error::Custom(String::from("Token: nothing to pause")));
+            }
+            self._pausable_pause()?;
+            Ok(())
+        }
+
+        fn _before_transfer(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            if self.paused()? {
+                return Err(Error::Custom(String::from(
+                    "Token: token transfer while paused",
+                )));
+            }
+            self._counting_before_transfer(from, to, amount)?;
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+/// @dev Math helpers used by the vault
+library Math {
+    /// @dev Returns the largest of two numbers.
+    function max(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a >= b ? a : b;
+    }
+
+    /// @dev Returns the smallest of two numbers.
+    function min(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a < b ? a : b;
+    }
+
+    /// @dev Returns the sum of two numbers, reverting on overflow.
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        uint256 c = a + b;
+        require(c >= a, "Math: addition overflow");
+        return c;
+    }
+}
+
+/// @dev Simple vault which tracks deposits of its users
+contract Vault {
+    using Math for uint256;
+
+    mapping(address => uint256) private _balances;
+    uint256 private _largestDeposit;
+
+    /// @dev Records a deposit of `amount` for the caller
+    function deposit(uint256 amount) external {
+        _balances[msg.sender] = _balances[msg.sender].add(amount);
+        _largestDeposit = Math.max(_largestDeposit, amount);
+    }
+
+    /// @dev Returns the balance of `account` capped by `cap`
+    function cappedBalance(address account, uint256 cap) external view returns (uint256) {
+        return _balances[account].min(cap);
+    }
+}
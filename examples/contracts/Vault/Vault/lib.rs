@@ -0,0 +1,101 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///@dev Simple vault which tracks deposits of its users
+#[openbrush::contract]
+pub mod vault {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    ///SPDX-License-Identifier: MIT
+    ///@dev Math helpers used by the vault
+    pub mod math {
+        use super::*;
+
+        ///@dev Returns the largest of two numbers.
+        pub fn max(a: u128, b: u128) -> Result<u128, Error> {
+            return Ok(if a >= b { a } else { b });
+        }
+
+        ///@dev Returns the smallest of two numbers.
+        pub fn min(a: u128, b: u128) -> Result<u128, Error> {
+            return Ok(if a < b { a } else { b });
+        }
+
+        ///@dev Returns the sum of two numbers, reverting on overflow.
+        pub fn add(a: u128, b: u128) -> Result<u128, Error> {
+            let c: u128 = a
+                .checked_add(b)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            if c < a {
+                return Err(Error::Custom(String::from("Math: addition overflow")));
+            }
+            return Ok(c);
+        }
+
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub balances: Mapping<AccountId, u128>,
+        pub largest_deposit: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Vault {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Vault {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        ///@dev Records a deposit of `amount` for the caller
+        #[ink(message)]
+        pub fn deposit(&mut self, amount: u128) -> Result<(), Error> {
+            self.data.balances.insert(
+                &self.env().caller(),
+                &(math::add(
+                    self.data
+                        .balances
+                        .get(&self.env().caller())
+                        .unwrap_or_default(),
+                    amount,
+                )?),
+            );
+            self.data.largest_deposit = math::max(self.data.largest_deposit, amount)?;
+            Ok(())
+        }
+
+        ///@dev Returns the balance of `account` capped by `cap`
+        #[ink(message)]
+        pub fn capped_balance(&self, account: AccountId, cap: u128) -> Result<u128, Error> {
+            return Ok(math::min(
+                self.data.balances.get(&account).unwrap_or_default(),
+                cap,
+            )?);
+        }
+
+    }
+}
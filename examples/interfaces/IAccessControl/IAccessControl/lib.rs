@@ -1,6 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
 // Generated with Sol2Ink v1.0.0
 // https://github.com/Supercolony-net/sol2ink
 
+use ink_prelude::string::String;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+#[derive(Debug, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    Custom(String),
+}
+
 /// @dev Emitted when `newAdminRole` is set as ``role``'s admin role, replacing `previousAdminRole`
 /// `DEFAULT_ADMIN_ROLE` is the starting admin for all roles, despite
 /// {RoleAdminChanged} not being emitted signaling this.
@@ -1,10 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
 // Generated with Sol2Ink v1.0.0
 // https://github.com/Supercolony-net/sol2ink
 
-use ink_prelude::{
-    string::String,
-    vec::Vec,
-};
+use ink_prelude::string::String;
+use ink_prelude::vec::Vec;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+#[derive(Debug, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    Custom(String),
+}
+
 /// @dev Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
 #[ink(event)]
 pub struct TransferSingle {
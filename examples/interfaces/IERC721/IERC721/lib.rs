@@ -1,7 +1,21 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
 // Generated with Sol2Ink v1.0.0
 // https://github.com/Supercolony-net/sol2ink
 
+use ink_prelude::string::String;
 use ink_prelude::vec::Vec;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+#[derive(Debug, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    Custom(String),
+}
+
 /// @dev Emitted when `tokenId` token is transferred from `from` to `to`.
 #[ink(event)]
 pub struct Transfer {
@@ -58,7 +72,7 @@ pub trait ERC721 {
     /// - If `to` refers to a smart contract, it must implement {IERC721Receiver-onERC721Received}, which is called upon a safe transfer.
     /// Emits a {Transfer} event.
     #[ink(message)]
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256_bytes(
         &mut self,
         from: AccountId,
         to: AccountId,
@@ -76,7 +90,7 @@ pub trait ERC721 {
     /// - If `to` refers to a smart contract, it must implement {IERC721Receiver-onERC721Received}, which is called upon a safe transfer.
     /// Emits a {Transfer} event.
     #[ink(message)]
-    fn safe_transfer_from(
+    fn safe_transfer_from_address_address_uint_256(
         &mut self,
         from: AccountId,
         to: AccountId,
@@ -0,0 +1,79 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+use ink_prelude::string::String;
+use scale::Decode;
+use scale::Encode;
+
+#[derive(Debug, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    Custom(String),
+}
+
+///SPDX-License-Identifier: MIT
+///OpenZeppelin Contracts (last updated v4.6.0) (utils/math/SafeMath.sol)
+/// @dev Wrappers over Solidity's arithmetic operations.
+/// NOTE: `SafeMath` is generally not needed starting with Solidity 0.8, since the compiler
+/// now has built in overflow checking.
+pub mod safe_math {
+    use super::*;
+
+    /// @dev Returns the addition of two unsigned integers, with an overflow flag.
+    pub fn try_add(a: u128, b: u128) -> Result<(bool, u128), Error> {
+        let c: u128 = a
+            .checked_add(b)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+        if c < a {
+            return Ok((false, 0));
+        }
+        return Ok((true, c));
+    }
+
+    /// @dev Returns the addition of two unsigned integers, reverting on
+    /// overflow.
+    pub fn add(a: u128, b: u128) -> Result<u128, Error> {
+        return Ok(a
+            .checked_add(b)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+    }
+
+    /// @dev Returns the subtraction of two unsigned integers, reverting with custom message on
+    /// overflow (when the result is negative).
+    pub fn sub(a: u128, b: u128, error_message: String) -> Result<u128, Error> {
+        if b > a {
+            return Err(Error::Custom(error_message));
+        }
+        return Ok(a
+            .checked_sub(b)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+    }
+
+    /// @dev Returns the multiplication of two unsigned integers, reverting on
+    /// overflow.
+    pub fn mul(a: u128, b: u128) -> Result<u128, Error> {
+        return Ok(a
+            .checked_mul(b)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+    }
+
+    /// @dev Returns the integer division of two unsigned integers, reverting with custom message on
+    /// division by zero.
+    pub fn div(a: u128, b: u128) -> Result<u128, Error> {
+        if b <= 0 {
+            return Err(Error::Custom(String::from("SafeMath: division by zero")));
+        }
+        return Ok(a
+            .checked_div(b)
+            .ok_or(Error::Custom(String::from("Division by zero")))?);
+    }
+
+    /// @dev Returns the average of two numbers, rounded towards zero.
+    pub fn average(a: u128, b: u128) -> Result<u128, Error> {
+        return Ok(div(add(a, b)?, 2)?);
+    }
+
+}
+
@@ -0,0 +1,53 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+use ink_prelude::string::String;
+use ink_prelude::vec::Vec;
+use ink_storage::traits::PackedLayout;
+use ink_storage::traits::SpreadAllocate;
+use ink_storage::traits::SpreadLayout;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+///@dev State of an order
+#[derive(Default, Debug, Clone, Copy, PartialEq, Encode, Decode, SpreadLayout, PackedLayout)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+)]
+pub enum Status {
+    #[default]
+    Placed = 0,
+    Shipped = 1,
+    Delivered = 2,
+}
+
+impl Status {
+    /// Converts the discriminant to the value of the enum
+    ///
+    /// returns None if the discriminant is out of the range of the enum
+    pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+        match index.try_into().ok()? {
+            0 => Some(Self::Placed),
+            1 => Some(Self::Shipped),
+            2 => Some(Self::Delivered),
+            _ => None,
+        }
+    }
+}
+
+///SPDX-License-Identifier: MIT
+///@dev Order placed in a shop
+#[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+)]
+pub struct Order {
+    pub buyer: AccountId,
+    pub amount: u128,
+}
+
@@ -0,0 +1,58 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///@dev Confirms the delivery of the orders
+#[openbrush::contract]
+pub mod courier {
+    use common::*;
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub delivered: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Courier {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Courier {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn deliver(&mut self) -> Result<(), Error> {
+            self.data.delivered = self
+                .data
+                .delivered
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+import "@acme/access/Ownable.sol";
+import "./Types.sol";
+
+/// @dev Tracks the state of the orders
+contract Tracker is Ownable {
+    mapping(uint256 => Status) private _statuses;
+
+    function ship(uint256 orderId) public onlyOwner {
+        _statuses[orderId] = Status.Shipped;
+    }
+
+    function status(uint256 orderId) public view returns (Status) {
+        return _statuses[orderId];
+    }
+}
+
+/// @dev Confirms the delivery of the orders
+contract Courier {
+    uint256 private _delivered;
+
+    function deliver() public {
+        _delivered += 1;
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+import "@acme/access/Ownable.sol";
+import {Order, OrderPlaced} from "./Types.sol";
+
+/// @dev Shop which records the orders of its buyers
+contract Shop is Ownable {
+    mapping(uint256 => Order) private _orders;
+    uint256 private _orderCount;
+
+    function placeOrder(uint256 amount) public returns (uint256) {
+        _orderCount += 1;
+        _orders[_orderCount] = Order({buyer: msg.sender, amount: amount});
+        emit OrderPlaced(msg.sender, amount);
+        return _orderCount;
+    }
+
+    function orderCount() public view onlyOwner returns (uint256) {
+        return _orderCount;
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///SPDX-License-Identifier: MIT
+///@dev Shop which records the orders of its buyers
+#[openbrush::contract]
+pub mod shop {
+    use common::*;
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Shop`, accessed by its modifiers
+    pub trait ShopStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Shop;
+    }
+
+    impl ShopStorage for Shop {
+        fn contract(&mut self) -> &mut Shop {
+            self
+        }
+    }
+
+    ///@dev Throws if called by any account other than the owner.
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: ShopStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().owner()? != instance.contract().env().caller() {
+            return Err(Error::Custom(String::from(
+                "Ownable: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OrderPlaced {
+        buyer: AccountId,
+        amount: u128,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub orders: Mapping<u128, Order>,
+        pub order_count: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Shop {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Shop {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.owner = instance.env().caller();
+                self.env().emit_event(OwnershipTransferred {
+                    previous_owner: ZERO_ADDRESS.into(),
+                    new_owner: instance.env().caller(),
+                });
+            })
+        }
+
+        ///@dev Returns the address of the current owner.
+        #[ink(message)]
+        pub fn owner(&self) -> Result<AccountId, Error> {
+            return Ok(self.data.owner);
+        }
+
+        #[ink(message)]
+        pub fn place_order(&mut self, amount: u128) -> Result<u128, Error> {
+            self.data.order_count = self
+                .data
+                .order_count
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.orders.insert(
+                &self.data.order_count,
+                &(Order {
+                    buyer: self.env().caller(),
+                    amount,
+                }),
+            );
+            self.env().emit_event(OrderPlaced {
+                buyer: self.env().caller(),
+                amount,
+            });
+            return Ok(self.data.order_count);
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn order_count(&mut self) -> Result<u128, Error> {
+            return Ok(self.data.order_count);
+        }
+
+    }
+}
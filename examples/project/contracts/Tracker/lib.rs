@@ -0,0 +1,109 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///SPDX-License-Identifier: MIT
+///@dev Tracks the state of the orders
+#[openbrush::contract]
+pub mod tracker {
+    use common::*;
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Tracker`, accessed by its modifiers
+    pub trait TrackerStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Tracker;
+    }
+
+    impl TrackerStorage for Tracker {
+        fn contract(&mut self) -> &mut Tracker {
+            self
+        }
+    }
+
+    ///@dev Throws if called by any account other than the owner.
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: TrackerStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().owner()? != instance.contract().env().caller() {
+            return Err(Error::Custom(String::from(
+                "Ownable: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub statuses: Mapping<u128, Status>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Tracker {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Tracker {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.owner = instance.env().caller();
+                self.env().emit_event(OwnershipTransferred {
+                    previous_owner: ZERO_ADDRESS.into(),
+                    new_owner: instance.env().caller(),
+                });
+            })
+        }
+
+        ///@dev Returns the address of the current owner.
+        #[ink(message)]
+        pub fn owner(&self) -> Result<AccountId, Error> {
+            return Ok(self.data.owner);
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn ship(&mut self, order_id: u128) -> Result<(), Error> {
+            self.data.statuses.insert(&order_id, &(Status::Shipped));
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn status(&self, order_id: u128) -> Result<Status, Error> {
+            return Ok(self.data.statuses.get(&order_id).unwrap_or_default());
+        }
+
+    }
+}
@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.0;
+
+/// @dev Order placed in a shop
+struct Order {
+    address buyer;
+    uint256 amount;
+}
+
+/// @dev State of an order
+enum Status {
+    Placed,
+    Shipped,
+    Delivered
+}
+
+event OrderPlaced(address buyer, uint256 amount);
@@ -22,10 +22,14 @@
 
 use std::{
     collections::HashSet,
+    fmt,
     str::FromStr,
 };
 
-use crate::structures::*;
+use crate::{
+    structures::*,
+    toml_builder::dependency_name,
+};
 use convert_case::{
     Case::{
         Pascal,
@@ -35,26 +39,147 @@ use convert_case::{
     Casing,
 };
 use proc_macro2::{
+    Group,
     Ident,
+    Literal,
+    Punct,
+    Spacing,
+    Span,
     TokenStream,
+    TokenTree,
 };
 use quote::*;
 
+/// The keywords of Rust 2021 which can be raw identifiers, `self`, `Self`, `super` and `crate`
+/// can not
+const RUST_KEYWORDS: [&str; 47] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+    "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// The version of ink! targeted by the generated code
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Backend {
+    /// ink! 3 with OpenBrush 2
+    #[default]
+    Ink3,
+    /// ink! 4 with OpenBrush 3
+    Ink4,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Backend::Ink3 => write!(f, "ink3"),
+            Backend::Ink4 => write!(f, "ink4"),
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(backend: &str) -> Result<Self, Self::Err> {
+        match backend {
+            "ink3" => Ok(Backend::Ink3),
+            "ink4" => Ok(Backend::Ink4),
+            _ => Err(format!("unknown backend `{backend}`, expected `ink3` or `ink4`")),
+        }
+    }
+}
+
 /// Assembles ink! contract from the parsed contract struct and return it as a vec of Strings
-pub fn assemble_contract(contract: Contract) -> TokenStream {
-    let mod_name = format_ident!("{}", contract.name.to_case(Snake));
+pub fn assemble_contract(mut contract: Contract, backend: Backend) -> TokenStream {
+    let mod_name = format_ident!("{}", escape_keyword(contract.name.to_case(Snake)));
     let contract_name = format_ident!("{}", contract.name);
     let signature = signature();
-    let imports = assemble_imports(contract.imports);
+    // events can only be defined in the contract module
+    for library in contract.libraries.iter_mut() {
+        contract.events.append(&mut library.events);
+    }
+    // the errors of the libraries are reverted with the error of the contract
+    let mut errors = contract.errors;
+    for error in contract.libraries.iter().flat_map(|library| library.errors.iter()) {
+        if !errors.iter().any(|defined| defined.name == error.name) {
+            errors.push(error.clone());
+        }
+    }
+    let stored_structs = contract
+        .structs
+        .iter()
+        .chain(contract.libraries.iter().flat_map(|library| library.structs.iter()))
+        .any(|structure| structure.stored);
+    let stored_enums = contract
+        .enums
+        .iter()
+        .chain(contract.libraries.iter().flat_map(|library| library.enums.iter()))
+        .any(|enumeration| enumeration.stored);
+    if backend == Backend::Ink3 && (stored_structs || stored_enums) {
+        // `SpreadAllocate` is imported by every ink! 3 contract
+        contract.imports.extend([
+            String::from("use ink_storage::traits::PackedLayout;"),
+            String::from("use ink_storage::traits::SpreadLayout;"),
+        ]);
+    }
+    for interface in contract.interfaces.iter() {
+        contract
+            .imports
+            .insert(format!("use {}::*;", dependency_name(&interface.name)));
+    }
+    // the error of the contract reverts with `Custom(String)`
+    contract
+        .imports
+        .insert(String::from("use ink_prelude::string::String;"));
+    let imports = assemble_imports(contract.imports, backend);
+    let error = assemble_error(&errors, &contract.interfaces, backend);
     let events = assemble_events(contract.events);
-    let enums = assemble_enums(contract.enums);
-    let structs = assemble_structs(contract.structs);
-    let storage = assemble_storage(&contract.name, &contract.fields);
-    let constructor = assemble_constructor(contract.constructor, &contract.fields);
+    let enums = assemble_enums(contract.enums, backend);
+    let structs = assemble_structs(contract.structs, backend);
+    let storage = assemble_storage(&contract.name, &contract.fields, backend);
+    let constructor = assemble_constructor(contract.constructor, &contract.fields, backend);
     let constants = assemble_constants(contract.fields);
     let functions = assemble_functions(contract.functions);
     let comments = assemble_contract_doc(contract.contract_doc);
     let modifiers = assemble_modifiers(contract.modifiers, &contract_name);
+    let libraries = assemble_library_modules(contract.libraries, backend);
+
+    if backend == Backend::Ink4 {
+        // the environment is accessed the same way in constructors and messages
+        let implementation = static_env(quote! {
+            #constructor
+            #functions
+        });
+        return quote! {
+            #![cfg_attr(not(feature = "std"), no_std, no_main)]
+            #![feature(min_specialization)]
+            _blank_!();
+            #signature
+            #comments
+            #[ink::contract]
+            pub mod #mod_name {
+                #imports
+                use scale::Encode;
+                use scale::Decode;
+                use openbrush::traits::Storage;
+                _blank_!();
+                #error
+
+                #constants
+                #modifiers
+                #events
+                #enums
+                #structs
+                #libraries
+                #storage
+                impl #contract_name {
+                    #implementation
+                }
+            }
+        }
+    }
 
     let contract = quote! {
         #![cfg_attr(not(feature = "std"), no_std)]
@@ -70,18 +195,14 @@ pub fn assemble_contract(contract: Contract) -> TokenStream {
             use ink_storage::traits::SpreadAllocate;
             use openbrush::traits::Storage;
             _blank_!();
-            #[derive(Debug, Encode, Decode, PartialEq)]
-            #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-            pub enum Error {
-                Custom(String),
-            }
-            _blank_!();
+            #error
 
             #constants
             #modifiers
             #events
             #enums
             #structs
+            #libraries
             #storage
             impl #contract_name {
                 #constructor
@@ -94,19 +215,34 @@ pub fn assemble_contract(contract: Contract) -> TokenStream {
 }
 
 /// Assembles ink! interface(trait) from the parsed interface struct and return it as a vec of Strings
-pub fn assemble_interface(interface: Interface) -> TokenStream {
+pub fn assemble_interface(mut interface: Interface, backend: Backend) -> TokenStream {
     let interface_name = TokenStream::from_str(&interface.name).unwrap();
     let interface_name_ref = TokenStream::from_str(&format!("{}Ref", interface.name)).unwrap();
     let signature = signature();
-    let imports = assemble_imports(interface.imports);
+    // the crate of the interface is a dependency of the contracts calling the interface
+    interface
+        .imports
+        .insert(String::from("use ink_prelude::string::String;"));
+    interface
+        .imports
+        .insert(String::from("use openbrush::traits::AccountId;"));
+    let imports = assemble_imports(interface.imports, backend);
+    let error = assemble_error(&interface.errors, &[], backend);
     let events = assemble_events(interface.events);
-    let enums = assemble_enums(interface.enums);
-    let structs = assemble_structs(interface.structs);
+    let enums = assemble_enums(interface.enums, backend);
+    let structs = assemble_structs(interface.structs, backend);
     let function_headers = assemble_function_headers(interface.function_headers);
 
     let interface = quote! {
+        #![cfg_attr(not(feature = "std"), no_std)]
+        #![feature(min_specialization)]
+        _blank_!();
         #signature
         #imports
+        use scale::Encode;
+        use scale::Decode;
+        _blank_!();
+        #error
         #events
         #enums
         #structs
@@ -122,6 +258,110 @@ pub fn assemble_interface(interface: Interface) -> TokenStream {
     interface
 }
 
+/// Assembles Rust modules from the parsed libraries of a file which does not contain a contract
+pub fn assemble_library(libraries: Vec<Library>, backend: Backend) -> TokenStream {
+    let signature = signature();
+    let mut imports = HashSet::new();
+    for library in libraries.iter() {
+        imports.extend(library.imports.iter().cloned());
+    }
+    imports.insert(String::from("use ink_prelude::string::String;"));
+    let imports = assemble_imports(imports, backend);
+    let errors = libraries
+        .iter()
+        .flat_map(|library| library.errors.iter().cloned())
+        .collect::<Vec<_>>();
+    let error = assemble_error(&errors, &[], backend);
+    let modules = assemble_library_modules(libraries, backend);
+
+    quote! {
+        #![cfg_attr(not(feature = "std"), no_std)]
+        _blank_!();
+        #signature
+        #imports
+        use scale::Encode;
+        use scale::Decode;
+        _blank_!();
+        #error
+        #modules
+    }
+}
+
+/// Assembles the crate with the structs and enums shared by the contracts of a project
+pub fn assemble_common(structs: Vec<Struct>, enums: Vec<Enum>, backend: Backend) -> TokenStream {
+    let signature = signature();
+    let mut imports = HashSet::from([
+        String::from("use ink_prelude::string::String;"),
+        String::from("use ink_prelude::vec::Vec;"),
+        String::from("use openbrush::traits::AccountId;"),
+    ]);
+    if structs
+        .iter()
+        .flat_map(|structure| structure.fields.iter())
+        .any(|field| field.field_type.contains("U256"))
+    {
+        imports.insert(String::from("use primitive_types::U256;"));
+    }
+    if backend == Backend::Ink3 && structs.iter().any(|structure| structure.stored) {
+        imports.extend([
+            String::from("use ink_storage::traits::PackedLayout;"),
+            String::from("use ink_storage::traits::SpreadAllocate;"),
+            String::from("use ink_storage::traits::SpreadLayout;"),
+        ]);
+    }
+    if backend == Backend::Ink3 && enums.iter().any(|enumeration| enumeration.stored) {
+        imports.extend([
+            String::from("use ink_storage::traits::PackedLayout;"),
+            String::from("use ink_storage::traits::SpreadLayout;"),
+        ]);
+    }
+    let imports = assemble_imports(imports, backend);
+    let enums = assemble_enums(enums, backend);
+    let structs = assemble_structs(structs, backend);
+
+    quote! {
+        #![cfg_attr(not(feature = "std"), no_std)]
+        _blank_!();
+        #signature
+        #imports
+        use scale::Encode;
+        use scale::Decode;
+        _blank_!();
+        #enums
+        #structs
+    }
+}
+
+/// Assembles a Rust module of free functions for each of the parsed libraries
+///
+/// The modules use the `Error` and the imports of their parent module
+fn assemble_library_modules(libraries: Vec<Library>, backend: Backend) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    for library in libraries {
+        let mod_name = format_ident!("{}", escape_keyword(library.name.to_case(Snake)));
+        let comments = assemble_contract_doc(library.comments);
+        let constants = assemble_constants(library.fields);
+        let enums = assemble_enums(library.enums, backend);
+        let structs = assemble_structs(library.structs, backend);
+        let functions = assemble_library_functions(library.functions);
+
+        output.extend(quote! {
+            #comments
+            pub mod #mod_name {
+                use super::*;
+                #constants
+                #enums
+                #structs
+                #functions
+            }
+            _blank_!();
+        });
+    }
+
+    output
+}
+
 fn assemble_contract_doc(comments: Vec<String>) -> TokenStream {
     let mut output = TokenStream::new();
 
@@ -136,9 +376,29 @@ fn assemble_contract_doc(comments: Vec<String>) -> TokenStream {
 }
 
 /// Sorts the imports inside the HashSet and return it as a Vec of Strings
-fn assemble_imports(imports: HashSet<String>) -> TokenStream {
+///
+/// The ink! 3 crates are replaced by the modules of the `ink` crate for ink! 4
+fn assemble_imports(imports: HashSet<String>, backend: Backend) -> TokenStream {
     let mut output = TokenStream::new();
-    let output_vec = Vec::from_iter(imports);
+    // the same import may be inserted with and without a trailing new line
+    let output_vec = Vec::from_iter(
+        imports
+            .iter()
+            .map(|import| {
+                match backend {
+                    Backend::Ink3 => import.trim().to_owned(),
+                    Backend::Ink4 => {
+                        import
+                            .trim()
+                            .replace("openbrush::storage::Mapping", "ink::storage::Mapping")
+                            .replace("ink_prelude::", "ink::prelude::")
+                            .replace("ink_storage::", "ink::storage::")
+                            .replace("ink_env::", "ink::env::")
+                    }
+                }
+            })
+            .collect::<HashSet<String>>(),
+    );
 
     for import in output_vec {
         output.extend(TokenStream::from_str(&import).unwrap());
@@ -147,14 +407,80 @@ fn assemble_imports(imports: HashSet<String>) -> TokenStream {
     output
 }
 
+/// Assembles the `Error` of the contract, the custom errors are its variants with their fields
+///
+/// The errors of the called interfaces are wrapped in a variant named after the interface
+fn assemble_error(
+    errors: &[CustomError],
+    interfaces: &[Interface],
+    backend: Backend,
+) -> TokenStream {
+    let derive = match backend {
+        Backend::Ink3 => quote!(#[derive(Debug, Encode, Decode, PartialEq)]),
+        Backend::Ink4 => quote!(#[derive(Debug, Encode, Decode, PartialEq, Eq)]),
+    };
+    let mut variants = TokenStream::new();
+
+    for error in errors.iter() {
+        let error_name = format_ident!("{}", error.name);
+        for comment in error.comments.iter() {
+            variants.extend(quote! {
+                #[doc = #comment]
+            });
+        }
+        if error.fields.is_empty() {
+            variants.extend(quote!(#error_name,));
+            continue
+        }
+        let mut fields = TokenStream::new();
+        for field in error.fields.iter() {
+            let field_name = format_ident!("{}", escape_keyword(field.name.to_case(Snake)));
+            let field_type = TokenStream::from_str(&field.param_type).unwrap();
+            fields.extend(quote!(#field_name: #field_type,));
+        }
+        variants.extend(quote! {
+            #error_name {
+                #fields
+            },
+        });
+    }
+
+    let mut conversions = TokenStream::new();
+    for interface in interfaces.iter() {
+        let variant = format_ident!("{}Error", interface.name);
+        let dependency = format_ident!("{}", dependency_name(&interface.name));
+        variants.extend(quote!(#variant(#dependency::Error),));
+        conversions.extend(quote! {
+            impl From<#dependency::Error> for Error {
+                fn from(error: #dependency::Error) -> Self {
+                    Error::#variant(error)
+                }
+            }
+            _blank_!();
+        });
+    }
+
+    quote! {
+        #derive
+        #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+        pub enum Error {
+            Custom(String),
+            #variants
+        }
+        _blank_!();
+        #conversions
+    }
+}
+
 /// Assembles ink! enums from the vec of parsed Enum structs and return them as a vec of Strings
-fn assemble_enums(enums: Vec<Enum>) -> TokenStream {
+fn assemble_enums(enums: Vec<Enum>, backend: Backend) -> TokenStream {
     let mut output = TokenStream::new();
 
     for enumeration in enums.iter() {
         let enum_name = TokenStream::from_str(&enumeration.name.to_case(Pascal)).unwrap();
         let mut enum_comments = TokenStream::new();
         let mut values = TokenStream::new();
+        let mut from_index = TokenStream::new();
 
         // assemble comments
         for comment in enumeration.comments.iter() {
@@ -163,21 +489,56 @@ fn assemble_enums(enums: Vec<Enum>) -> TokenStream {
             });
         }
 
-        // assemble enum values
-        for value in enumeration.values.iter() {
+        // assemble enum values, the first value is the default one as in Solidity
+        for (index, value) in enumeration.values.iter().enumerate() {
             let value_name = TokenStream::from_str(&value.to_case(Pascal)).unwrap();
+            let discriminant = Literal::u8_unsuffixed(index as u8);
+            let default = match index {
+                0 => quote!(#[default]),
+                _ => quote!(),
+            };
 
             values.extend(quote! {
-                #value_name,
+                #default
+                #value_name = #discriminant,
+            });
+            from_index.extend(quote! {
+                #discriminant => Some(Self::#value_name),
             });
         }
 
+        let mut derive = quote!(Default, Debug, Clone, Copy, PartialEq, Encode, Decode);
+        let mut std_derive = quote!(scale_info::TypeInfo);
+        if enumeration.stored {
+            match backend {
+                Backend::Ink3 => {
+                    derive.extend(quote!(, SpreadLayout, PackedLayout));
+                    std_derive.extend(quote!(, ink_storage::traits::StorageLayout));
+                }
+                Backend::Ink4 => std_derive.extend(quote!(, ink::storage::traits::StorageLayout)),
+            }
+        }
+
         output.extend(quote! {
             #enum_comments
+            #[derive(#derive)]
+            #[cfg_attr(feature = "std", derive(#std_derive))]
             pub enum #enum_name {
                 #values
             }
             _blank_!();
+            impl #enum_name {
+                /// Converts the discriminant to the value of the enum
+                ///
+                /// returns None if the discriminant is out of the range of the enum
+                pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+                    match index.try_into().ok()? {
+                        #from_index
+                        _ => None,
+                    }
+                }
+            }
+            _blank_!();
         });
     }
 
@@ -208,7 +569,8 @@ fn assemble_events(events: Vec<Event>) -> TokenStream {
                 });
             }
 
-            let event_field_name = format_ident!("{}", event_field.name.to_case(Snake));
+            let event_field_name =
+                format_ident!("{}", escape_keyword(event_field.name.to_case(Snake)));
             let event_field_type = TokenStream::from_str(&event_field.field_type).unwrap();
 
             event_fields.extend(quote! {
@@ -231,14 +593,18 @@ fn assemble_events(events: Vec<Event>) -> TokenStream {
 }
 
 /// Assembles ink! storage struct from the vec of parsed ContractField structs and return it as a vec of Strings
-fn assemble_storage(contract_name: &String, fields: &[ContractField]) -> TokenStream {
+fn assemble_storage(
+    contract_name: &String,
+    fields: &[ContractField],
+    backend: Backend,
+) -> TokenStream {
     let mut output = TokenStream::new();
     let contract_name = format_ident!("{}", contract_name);
     let mut storage_fields = TokenStream::new();
 
     // assemble storage fields
     for field in fields.iter().filter(|field| !field.constant) {
-        let field_name = format_ident!("{}", field.name.to_case(Snake));
+        let field_name = format_ident!("{}", escape_keyword(field.name.to_case(Snake)));
         let field_type = TokenStream::from_str(&field.field_type).unwrap();
 
         for comment in field.comments.iter() {
@@ -251,6 +617,27 @@ fn assemble_storage(contract_name: &String, fields: &[ContractField]) -> TokenSt
         });
     }
 
+    if backend == Backend::Ink4 {
+        output.extend(quote! {
+            pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+            _blank_!();
+            #[derive(Default, Debug)]
+            #[openbrush::upgradeable_storage(STORAGE_KEY)]
+            pub struct Data {
+                #storage_fields
+            }
+            _blank_!();
+            #[ink(storage)]
+            #[derive(Default, Storage)]
+            pub struct #contract_name {
+                #[storage_field]
+                data: Data,
+            }
+            _blank_!();
+        });
+        return output
+    }
+
     output.extend(quote! {
         pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
         _blank_!();
@@ -279,7 +666,13 @@ fn assemble_constants(fields: Vec<ContractField>) -> TokenStream {
     // assemble storage fields
     for field in fields.iter().filter(|field| field.constant) {
         let field_name = format_ident!("{}", field.name.to_case(UpperSnake));
-        let field_type = TokenStream::from_str(&field.field_type).unwrap();
+        // the constructors of `String` and `AccountId` are not `const`
+        let field_type = match field.field_type.as_str() {
+            "String" => "&'static str",
+            "AccountId" => "[u8; 32]",
+            field_type => field_type,
+        };
+        let field_type = TokenStream::from_str(field_type).unwrap();
         let initial_value = field.initial_value.clone().unwrap();
 
         for comment in field.comments.iter() {
@@ -300,7 +693,7 @@ fn assemble_constants(fields: Vec<ContractField>) -> TokenStream {
 }
 
 /// Assembles ink! structs from the vec of parsed Struct structs and return them as a vec of Strings
-fn assemble_structs(structs: Vec<Struct>) -> TokenStream {
+fn assemble_structs(structs: Vec<Struct>, backend: Backend) -> TokenStream {
     let mut output = TokenStream::new();
 
     for structure in structs.iter() {
@@ -315,20 +708,45 @@ fn assemble_structs(structs: Vec<Struct>) -> TokenStream {
             });
         }
 
-        // assemble struct fields
-        for struct_field in structure.fields.iter() {
-            let struct_field_name = format_ident!("{}", struct_field.name.to_case(Snake));
+        // the mappings of the stored structs are stored in the fields of the contract
+        for struct_field in structure
+            .fields
+            .iter()
+            .filter(|struct_field| !struct_field.field_type.starts_with("Mapping<"))
+        {
+            let struct_field_name =
+                format_ident!("{}", escape_keyword(struct_field.name.to_case(Snake)));
             let struct_field_type = TokenStream::from_str(&struct_field.field_type).unwrap();
 
             struct_fields.extend(quote! {
-                #struct_field_name: #struct_field_type,
+                pub #struct_field_name: #struct_field_type,
             });
         }
 
+        // the encoding is needed by the storage, the messages and the events alike,
+        // the exposed structs can be compared and the stored structs describe their layout
+        let mut derive = quote!(Default, Clone, Encode, Decode);
+        let mut std_derive = quote!(scale_info::TypeInfo);
+        if structure.stored || structure.exposed {
+            derive.extend(quote!(, Debug));
+        }
+        if structure.exposed {
+            derive.extend(quote!(, PartialEq));
+        }
+        if structure.stored {
+            match backend {
+                Backend::Ink3 => {
+                    derive.extend(quote!(, SpreadLayout, PackedLayout, SpreadAllocate));
+                    std_derive.extend(quote!(, ink_storage::traits::StorageLayout));
+                }
+                Backend::Ink4 => std_derive.extend(quote!(, ink::storage::traits::StorageLayout)),
+            }
+        }
+
         output.extend(quote! {
             #struct_comments
-            #[derive(Default, Encode, Decode)]
-            #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+            #[derive(#derive)]
+            #[cfg_attr(feature = "std", derive(#std_derive))]
             pub struct #struct_name {
                 #struct_fields
             }
@@ -343,7 +761,11 @@ fn assemble_structs(structs: Vec<Struct>) -> TokenStream {
 }
 
 /// Assembles ink! cosntructor from the parsed Function struct and return it as a vec of Strings
-fn assemble_constructor(constructor: Function, fields: &[ContractField]) -> TokenStream {
+fn assemble_constructor(
+    constructor: Function,
+    fields: &[ContractField],
+    backend: Backend,
+) -> TokenStream {
     let mut output = TokenStream::new();
     let mut params = TokenStream::new();
     let mut comments = TokenStream::new();
@@ -358,7 +780,7 @@ fn assemble_constructor(constructor: Function, fields: &[ContractField]) -> Toke
 
     // assemble params
     for param in constructor.header.params.iter() {
-        let param_name = format_ident!("{}", param.name.to_case(Snake));
+        let param_name = format_ident!("{}", escape_keyword(param.name.to_case(Snake)));
         let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
         params.extend(quote! {
@@ -368,32 +790,50 @@ fn assemble_constructor(constructor: Function, fields: &[ContractField]) -> Toke
 
     let mut body = TokenStream::new();
 
-    // assemble body
-    body.extend(quote! {
-        #(#constructor_functions)*
-    });
-
+    // the state variables, including the immutables, are initialized before the body
+    // of the constructor, as in Solidity
     for field in fields
         .iter()
         .filter(|field| field.initial_value.is_some() && !field.constant)
     {
-        let field_name = format_ident!("{}", field.name.to_case(Snake));
+        let field_name = format_ident!("{}", escape_keyword(field.name.to_case(Snake)));
         let intial_value = field.initial_value.clone();
 
         body.extend(quote! {
-            self.#field_name = #intial_value;
+            instance.data.#field_name = #intial_value;
         });
     }
 
-    output.extend(quote! {
-        #comments
-        #[ink(constructor)]
-        pub fn new(#params) -> Self{
-            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
-                #body
-            })
+    // assemble body
+    body.extend(quote! {
+        #(#constructor_functions)*
+    });
+
+    output.extend(match backend {
+        Backend::Ink3 => {
+            quote! {
+                #comments
+                #[ink(constructor)]
+                pub fn new(#params) -> Self{
+                    ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                        #body
+                    })
+                }
+                _blank_!();
+            }
+        }
+        Backend::Ink4 => {
+            quote! {
+                #comments
+                #[ink(constructor)]
+                pub fn new(#params) -> Self{
+                    let mut instance = Self::default();
+                    #body
+                    instance
+                }
+                _blank_!();
+            }
         }
-        _blank_!();
     });
 
     output
@@ -408,11 +848,8 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
         let mut function_name = TokenStream::new();
         let mut view = TokenStream::new();
         let mut params = TokenStream::new();
-        let mut return_params = TokenStream::new();
-        let mut body = TokenStream::new();
         let mut comments = TokenStream::new();
         let mut function_modifiers = TokenStream::new();
-        let statements = &function.body;
 
         // assemble comments
         for comment in function.header.comments.iter() {
@@ -421,36 +858,36 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
             });
         }
 
+        // the modifiers are invoked by their paths, the arguments are passed without the
+        // parentheses if there are none, as OpenBrush expects
         for function_modifier in function.header.modifiers.iter() {
+            let function_modifier = match function_modifier {
+                Expression::FunctionCall(name, args, ..) => {
+                    let name = format_ident!("{}", escape_keyword(name.to_case(Snake)));
+                    if args.is_empty() {
+                        quote!(#name)
+                    } else {
+                        quote!(#name(#(#args),*))
+                    }
+                }
+                function_modifier => quote!(#function_modifier),
+            };
             function_modifiers.extend(quote! {
                 #[modifiers(#function_modifier)]
             });
         }
 
         // assemble message
-        if function.header.external {
-            if function.header.payable {
-                message.extend(quote! {
-                    #[ink(message, payable)]
-                });
-            } else {
-                message.extend(quote! {
-                    #[ink(message)]
-                });
-            }
+        if function.header.visibility.is_message() {
+            message.extend(message_attribute(&function.header));
         }
 
         // assemble function name
         function_name.extend(
-            TokenStream::from_str(&format!(
-                "{}{}",
-                if function.header.external {
-                    String::from("pub fn ")
-                } else {
-                    String::from("fn _")
-                },
-                function.header.name.to_case(Snake)
-            ))
+            TokenStream::from_str(&match function.header.visibility.is_message() {
+                true => format!("pub fn {}", escape_keyword(function.header.name.to_case(Snake))),
+                false => format!("fn _{}", function.header.name.to_case(Snake)),
+            })
             .unwrap(),
         );
 
@@ -465,7 +902,7 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
 
         // assemble params
         for param in function.header.params.iter() {
-            let param_name = format_ident!("{}", param.name.to_case(Snake));
+            let param_name = format_ident!("{}", escape_keyword(param.name.to_case(Snake)));
             let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
             params.extend(quote! {
@@ -473,90 +910,67 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
             });
         }
 
-        // assemble return params
-        if !function.header.return_params.is_empty() {
-            let mut params = TokenStream::new();
+        let (return_params, body) = assemble_function_body(function);
 
-            for i in 0..function.header.return_params.len() {
-                let param = &function.header.return_params[i];
-                let param_type = TokenStream::from_str(&param.param_type).unwrap();
+        output.extend(quote! {
+            #comments
+            #message
+            #function_modifiers
+            #function_name(#view #params) -> Result<#return_params, Error> {
+                #body
+            }
+        });
 
-                if i > 0 {
-                    params.extend(quote! {,});
-                }
+        output.extend(quote! {
+            _blank_!();
+        });
+    }
 
-                params.extend(quote! {
-                    #param_type
-                });
+    output
+}
 
-                if param.name != "_" {
-                    let param_name = TokenStream::from_str(&param.name.to_case(Snake)).unwrap();
-                    body.extend(quote! {
-                        let mut #param_name = Default::default();
-                    })
-                }
-            }
+/// Assembles the free functions of a library from the vec of parsed Function structs
+fn assemble_library_functions(functions: Vec<Function>) -> TokenStream {
+    let mut output = TokenStream::new();
 
-            if function.header.return_params.len() > 1 {
-                return_params.extend(quote! {
-                    (#params)
-                });
-            } else {
-                return_params.extend(quote! {
-                    #params
-                });
-            }
-        } else {
-            return_params.extend(quote! {
-                ()
+    for function in functions.iter() {
+        let function_name =
+            format_ident!("{}", escape_keyword(function.header.name.to_case(Snake)));
+        let mut params = TokenStream::new();
+        let mut comments = TokenStream::new();
+
+        // assemble comments
+        for comment in function.header.comments.iter() {
+            comments.extend(quote! {
+                #[doc = #comment]
             });
         }
 
-        // body
-        body.extend(quote! {
-            #(#statements)*
-        });
-
-        if function.header.return_params.is_empty() {
-            body.extend(quote! {
-                Ok(())
-            });
-        } else if function.header.return_params[0].name != "_" {
-            let out = TokenStream::from_str(
-                &function
-                    .header
-                    .return_params
-                    .iter()
-                    .map(|param| param.name.clone())
-                    .collect::<Vec<String>>()
-                    .join(","),
-            )
-            .unwrap();
-            if !statements.iter().any(|s| matches!(s, Statement::Return(_))) {
-                body.extend(
-                    if function.header.return_params.len() > 1 {
-                        quote! {
-                            Ok((#out))
-                        }
-                    } else {
-                        quote! {
-                            Ok(#out)
-                        }
-                    },
-                );
+        // assemble params, the storage parameters are references to the storage of the contract
+        for (i, param) in function.header.params.iter().enumerate() {
+            let param_name = format_ident!("{}", escape_keyword(param.name.to_case(Snake)));
+            let param_type = TokenStream::from_str(&param.param_type).unwrap();
+            let reference = match (param.storage, function.header.view) {
+                (false, _) => quote!(),
+                (true, true) => quote!(&),
+                (true, false) => quote!(&mut),
+            };
+
+            if i > 0 {
+                params.extend(quote! {,});
             }
+            params.extend(quote! {
+                #param_name: #reference #param_type
+            });
         }
 
+        let (return_params, body) = assemble_function_body(function);
+
         output.extend(quote! {
             #comments
-            #message
-            #function_modifiers
-            #function_name(#view #params) -> Result<#return_params, Error> {
+            pub fn #function_name(#params) -> Result<#return_params, Error> {
                 #body
             }
-        });
-
-        output.extend(quote! {
             _blank_!();
         });
     }
@@ -564,12 +978,122 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
     output
 }
 
+/// Assembles the return type and the body of a function
+///
+/// Named return parameters are declared at the beginning of the body and returned at its end
+///
+/// returns 0. the return type 1. the body
+fn assemble_function_body(function: &Function) -> (TokenStream, TokenStream) {
+    let mut return_params = TokenStream::new();
+    let mut body = TokenStream::new();
+    let statements = &function.body;
+
+    // assemble return params
+    if !function.header.return_params.is_empty() {
+        let mut params = TokenStream::new();
+
+        for i in 0..function.header.return_params.len() {
+            let param = &function.header.return_params[i];
+            let param_type = TokenStream::from_str(&param.param_type).unwrap();
+
+            if i > 0 {
+                params.extend(quote! {,});
+            }
+
+            params.extend(quote! {
+                #param_type
+            });
+
+            if param.name != "_" {
+                let param_name =
+                    TokenStream::from_str(&escape_keyword(param.name.to_case(Snake))).unwrap();
+                body.extend(quote! {
+                    let mut #param_name = Default::default();
+                })
+            }
+        }
+
+        if function.header.return_params.len() > 1 {
+            return_params.extend(quote! {
+                (#params)
+            });
+        } else {
+            return_params.extend(quote! {
+                #params
+            });
+        }
+    } else {
+        return_params.extend(quote! {
+            ()
+        });
+    }
+
+    // body
+    body.extend(quote! {
+        #(#statements)*
+    });
+
+    if function.header.return_params.is_empty() {
+        body.extend(quote! {
+            Ok(())
+        });
+    } else if function.header.return_params[0].name != "_" {
+        let out = TokenStream::from_str(
+            &function
+                .header
+                .return_params
+                .iter()
+                .map(|param| escape_keyword(param.name.clone()))
+                .collect::<Vec<String>>()
+                .join(","),
+        )
+        .unwrap();
+        if !statements.iter().any(|s| matches!(s, Statement::Return(_))) {
+            body.extend(
+                if function.header.return_params.len() > 1 {
+                    quote! {
+                        Ok((#out))
+                    }
+                } else {
+                    quote! {
+                        Ok(#out)
+                    }
+                },
+            );
+        }
+    }
+
+    (return_params, body)
+}
+
 /// Assembles ink! functions from the vec of parsed Function structs and return them as a vec of Strings
 fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenStream {
     let mut output = TokenStream::new();
+    if modifiers.is_empty() {
+        return output
+    }
+
+    // the modifiers are generic over the storage, as OpenBrush requires
+    let storage = format_ident!("{}Storage", contract_name);
+    let storage_doc = format!(" The storage of `{contract_name}`, accessed by its modifiers");
+    output.extend(quote! {
+        #[doc = #storage_doc]
+        pub trait #storage {
+            #[doc = " returns the contract whose function is modified"]
+            fn contract(&mut self) -> &mut #contract_name;
+        }
+        _blank_!();
+        impl #storage for #contract_name {
+            fn contract(&mut self) -> &mut #contract_name {
+                self
+            }
+        }
+        _blank_!();
+    });
 
     for modifier in modifiers.iter() {
-        let modifier_name = format_ident!("{}", modifier.header.name.to_case(Snake));
+        let modifier_name =
+            format_ident!("{}", escape_keyword(modifier.header.name.to_case(Snake)));
         let mut body = TokenStream::new();
         let mut comments = TokenStream::new();
         let mut params = TokenStream::new();
@@ -584,7 +1108,7 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
 
         // assemble params
         for param in modifier.header.params.iter() {
-            let param_name = format_ident!("{}", param.name.to_case(Snake));
+            let param_name = format_ident!("{}", escape_keyword(param.name.to_case(Snake)));
             let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
             params.extend(quote! {
@@ -592,20 +1116,61 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
             });
         }
 
-        // body
-        body.extend(quote! {
-            #(#statements)*
+        // the function is executed by `_`, the modifier returns its result
+        let placeholders = count_statements(statements, &|statement| {
+            matches!(statement, Statement::ModifierBody)
+        });
+        let returns = count_statements(statements, &|statement| {
+            matches!(statement, Statement::Return(_))
         });
+        let position = statements
+            .iter()
+            .position(|statement| matches!(statement, Statement::ModifierBody));
+        // the function executed repeatedly is borrowed mutably
+        let (body_param, mut bounds) = if placeholders > 1 {
+            (quote!(mut body), quote!(F: FnMut(&mut T) -> Result<R, Error>))
+        } else {
+            (quote!(body), quote!(F: FnOnce(&mut T) -> Result<R, Error>))
+        };
+        match position {
+            Some(position) if placeholders == 1 && returns == 0 => {
+                let before = &statements[..position];
+                let after = &statements[position + 1..];
+                if after.is_empty() {
+                    body.extend(quote! {
+                        #(#before)*
+                        body(instance)
+                    });
+                } else {
+                    body.extend(quote! {
+                        #(#before)*
+                        let result = body(instance)?;
+                        #(#after)*
+                        Ok(result)
+                    });
+                }
+            }
+            // the function may not be executed, then the default values are returned
+            _ => {
+                bounds.extend(quote!(, R: Default));
+                body.extend(quote! {
+                    let mut result = None;
+                    #(#statements)*
+                    Ok(result.unwrap_or_default())
+                });
+            }
+        }
 
         output.extend(quote! {
             #comments
-            #[doc = "The type of `T` should be the trait which implements the storage"]
-            #[doc = "This will be implemented in Sol2Ink in upcoming version"]
             #[modifier_definition]
-            pub fn #modifier_name<T, F, R>(instance: &mut T, body: F #params) -> Result<R, Error>
+            pub fn #modifier_name<T, F, R>(
+                instance: &mut T,
+                #body_param: F #params
+            ) -> Result<R, Error>
             where
-                T: #contract_name,
-                F: FnOnce(&mut T) -> Result<R, Error>
+                T: #storage,
+                #bounds
             {
                 #body
             }
@@ -619,6 +1184,51 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
     output
 }
 
+/// returns the number of the statements, including the nested statements, which match
+/// `predicate`, the statements in loops are counted twice as they may be executed repeatedly
+fn count_statements(statements: &[Statement], predicate: &dyn Fn(&Statement) -> bool) -> usize {
+    statements
+        .iter()
+        .map(|statement| {
+            let nested = match statement {
+                Statement::Catch(statements)
+                | Statement::Else(statements)
+                | Statement::ElseIf(_, statements)
+                | Statement::Group(statements)
+                | Statement::If(_, statements)
+                | Statement::Try(statements) => count_statements(statements, predicate),
+                Statement::Loop(.., statements) | Statement::While(.., statements) => {
+                    2 * count_statements(statements, predicate)
+                }
+                Statement::Match(_, arms) => {
+                    arms.iter()
+                        .map(|(_, statements)| count_statements(statements, predicate))
+                        .sum()
+                }
+                Statement::Ternary(_, if_true, if_false) => {
+                    count_statements(std::slice::from_ref(if_true.as_ref()), predicate)
+                        + count_statements(std::slice::from_ref(if_false.as_ref()), predicate)
+                }
+                _ => 0,
+            };
+            nested + usize::from(predicate(statement))
+        })
+        .sum()
+}
+
+/// returns the `#[ink(message)]` attribute of the function, with its payability
+/// and its explicit selector
+fn message_attribute(header: &FunctionHeader) -> TokenStream {
+    let payable = header.payable.then(|| quote!(, payable));
+    let selector = header.selector.map(|selector| {
+        let selector = TokenStream::from_str(&format!("0x{selector:08x}")).unwrap();
+        quote!(, selector = #selector)
+    });
+    quote! {
+        #[ink(message #payable #selector)]
+    }
+}
+
 /// Assembles ink! trait function headers from the vec of parsed FunctionHeader structs and return them as a vec of Strings
 fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStream {
     let mut output = TokenStream::new();
@@ -639,21 +1249,13 @@ fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStre
         }
 
         // assemble message
-        if header.external {
-            if header.payable {
-                message.extend(quote! {
-                    #[ink(message, payable)]
-                });
-            } else {
-                message.extend(quote! {
-                    #[ink(message)]
-                });
-            }
+        if header.visibility.is_message() {
+            message.extend(message_attribute(header));
         }
 
         // assemble function name
-        function_name
-            .extend(TokenStream::from_str(&format!("fn {}", header.name.to_case(Snake))).unwrap());
+        let name = escape_keyword(header.name.to_case(Snake));
+        function_name.extend(TokenStream::from_str(&format!("fn {name}")).unwrap());
 
         // assemble view
         view.extend(
@@ -666,7 +1268,7 @@ fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStre
 
         // assemble params
         for param in header.params.iter() {
-            let param_name = format_ident!("{}", param.name.to_case(Snake));
+            let param_name = format_ident!("{}", escape_keyword(param.name.to_case(Snake)));
             let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
             params.extend(quote! {
@@ -718,6 +1320,47 @@ fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStre
     output
 }
 
+/// Replaces the access to the environment through the contract, eg. `self.env()`
+/// or `instance.env()`, with `Self::env()`
+fn static_env(tokens: TokenStream) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut output = TokenStream::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let is_env_access = matches!(
+            &tokens[i..],
+            [TokenTree::Ident(receiver), TokenTree::Punct(dot), TokenTree::Ident(env), TokenTree::Group(args), ..]
+                if (receiver == "self" || receiver == "instance")
+                    && dot.as_char() == '.'
+                    && env == "env"
+                    && args.stream().is_empty()
+        );
+        if is_env_access {
+            output.extend([
+                TokenTree::Ident(Ident::new("Self", Span::call_site())),
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                tokens[i + 2].clone(),
+                tokens[i + 3].clone(),
+            ]);
+            i += 4;
+            continue
+        }
+        match &tokens[i] {
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), static_env(group.stream()));
+                replaced.set_span(group.span());
+                output.extend([TokenTree::Group(replaced)]);
+            }
+            token => output.extend([token.clone()]),
+        }
+        i += 1;
+    }
+
+    output
+}
+
 /// Adds a signature to the beginning of the file :)
 fn signature() -> TokenStream {
     const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -768,7 +1411,6 @@ impl ToTokens for Operation {
 impl ToTokens for Statement {
     fn to_tokens(&self, stream: &mut TokenStream) {
         match self {
-            Statement::AssemblyEnd => {}
             Statement::Assign(left, right, operation) => {
                 stream.extend(quote! {
                     #left #operation #right;
@@ -782,14 +1424,13 @@ impl ToTokens for Statement {
                     }
                 })
             }
-            Statement::CatchEnd => {}
             Statement::Comment(content) => {
                 stream.extend(quote! {
                     _comment_!(#content);
                 })
             }
             Statement::Declaration(var_name_raw, var_type_raw, initial_value_maybe) => {
-                let var_name = format_ident!("{}", var_name_raw.to_case(Snake));
+                let var_name = format_ident!("{}", escape_keyword(var_name_raw.to_case(Snake)));
                 let var_type = TokenStream::from_str(var_type_raw).unwrap();
                 if let Some(initial_value) = &initial_value_maybe {
                     stream.extend(quote!(let #var_name : #var_type = #initial_value;));
@@ -862,10 +1503,23 @@ impl ToTokens for Statement {
                     }
                 })
             }
-            Statement::IfEnd => {}
+            Statement::Match(expression, arms) => {
+                let arms = arms.iter().map(|(pattern, statements)| {
+                    quote! {
+                        #pattern => {
+                            #(#statements)*
+                        }
+                    }
+                });
+                stream.extend(quote! {
+                    match #expression {
+                        #(#arms)*
+                    }
+                })
+            }
             Statement::ModifierBody => {
                 stream.extend(quote! {
-                    body(instance);
+                    result = Some(body(instance)?);
                 })
             }
             Statement::Raw(_) => {}
@@ -889,6 +1543,13 @@ impl ToTokens for Statement {
                     return Ok(#output)
                 })
             }
+            Statement::Revert(error, constructor) => {
+                stream.extend(if *constructor {
+                    quote!(panic!("{:?}", #error);)
+                } else {
+                    quote!(return Err(#error);)
+                })
+            }
             Statement::Ternary(condition_raw, if_true, if_false) => {
                 let left = &condition_raw.left;
                 let operation = condition_raw.operation;
@@ -920,7 +1581,6 @@ impl ToTokens for Statement {
                     }
                 })
             }
-            Statement::TryEnd => {}
             Statement::While(assign, condition, modification, statements) => {
                 stream.extend(quote! {
                     #assign
@@ -930,7 +1590,6 @@ impl ToTokens for Statement {
                     }
                 })
             }
-            Statement::WhileEnd => {}
         }
     }
 }
@@ -940,11 +1599,37 @@ impl ToTokens for Expression {
         stream.extend(match self {
             Expression::Arithmetic(left, right, operation) => {
                 if operation == &Operation::Pow {
-                    quote!(#left.pow(#right as u32))
+                    let right = exponent(right, operation, false);
+                    quote!(#left.pow(#right))
                 } else {
                     quote!(#left #operation #right)
                 }
             }
+            Expression::Builtin(code_raw, args) => {
+                let all_args = quote!(#(#args),*).to_string();
+                let mut code = code_raw.replace("$args", &all_args);
+                // the later arguments first, so `$1` does not replace the beginning of `$10`
+                for (i, arg) in args.iter().enumerate().rev() {
+                    code = code.replace(&format!("${i}"), &quote!(#arg).to_string());
+                }
+                TokenStream::from_str(&code).unwrap_or_else(|_| {
+                    TokenStream::from_str(format!("\"S2I_ERR:{code}\"").as_str()).unwrap()
+                })
+            }
+            Expression::Checked(left, right, operation, constructor) => {
+                let left = enclose_operand(left);
+                let method = format_ident!("checked_{}", arithmetic_method(operation));
+                let right = exponent(right, operation, !*constructor);
+                let message = match operation {
+                    Operation::Div | Operation::Modulo => "Division by zero",
+                    _ => "Arithmetic overflow",
+                };
+                if *constructor {
+                    quote!(#left.#method(#right).expect(#message))
+                } else {
+                    quote!(#left.#method(#right).ok_or(Error::Custom(String::from(#message)))?)
+                }
+            }
             Expression::Cast(unique_cast, cast_type_raw, expression) => {
                 let cast_type = TokenStream::from_str(cast_type_raw).unwrap();
                 if *unique_cast {
@@ -968,6 +1653,14 @@ impl ToTokens for Expression {
                     TokenStream::from_str(&expression_raw.to_case(UpperSnake)).unwrap();
                 quote!(#expression)
             }
+            Expression::CustomError(error_name_raw, fields) => {
+                let error_name = format_ident!("{}", error_name_raw);
+                if fields.is_empty() {
+                    quote!(Error::#error_name)
+                } else {
+                    quote!(Error::#error_name { #(#fields),* })
+                }
+            }
             Expression::Enclosed(expression) => {
                 quote!((#expression))
             }
@@ -976,17 +1669,29 @@ impl ToTokens for Expression {
                     TokenStream::from_str(&selector_raw.clone().unwrap_or_default()).unwrap();
                 quote!(#selector.env().caller())
             }
+            Expression::ExternalCall(wrapper_raw, function_name_raw, address, args, builder) => {
+                let wrapper = format_ident!("{}", wrapper_raw);
+                if *builder {
+                    let function_name =
+                        format_ident!("{}_builder", function_name_raw.to_case(Snake));
+                    quote!(#wrapper::#function_name(&#address, #(#args),*))
+                } else {
+                    let function_name =
+                        format_ident!("{}", escape_keyword(function_name_raw.to_case(Snake)));
+                    quote!(#wrapper::#function_name(&#address, #(#args),*)?)
+                }
+            }
             Expression::FunctionCall(function_name_raw, args_raw, selector_maybe, external) => {
                 let mut function_call = TokenStream::new();
                 if let Some(selector_raw) = selector_maybe {
                     let selector = TokenStream::from_str(selector_raw).unwrap();
                     function_call.extend(quote!(#selector.))
                 }
-                let function_name = format_ident!(
-                    "{}{}",
-                    if *external { "" } else { "_" },
-                    function_name_raw.to_case(Snake)
-                );
+                let function_name = if *external {
+                    format_ident!("{}", escape_keyword(function_name_raw.to_case(Snake)))
+                } else {
+                    format_ident!("_{}", function_name_raw.to_case(Snake))
+                };
                 let mut args = TokenStream::new();
                 for (i, arg) in args_raw.iter().enumerate() {
                     if i > 0 {
@@ -999,9 +1704,18 @@ impl ToTokens for Expression {
                     #function_call #function_name(#args)?
                 }
             }
+            Expression::Index(array, index) => {
+                quote!(#array[#index])
+            }
             Expression::IsZero(expression) => {
                 quote!(#expression.is_zero())
             }
+            Expression::LibraryCall(library_raw, function_name_raw, args) => {
+                let library = format_ident!("{}", escape_keyword(library_raw.to_case(Snake)));
+                let function_name =
+                    format_ident!("{}", escape_keyword(function_name_raw.to_case(Snake)));
+                quote!(#library::#function_name(#(#args),*)?)
+            }
             Expression::Literal(content) => {
                 TokenStream::from_str(content).unwrap_or_else(|_| {
                     TokenStream::from_str(format!("\"S2I_ERR:{content}\"").as_str()).unwrap()
@@ -1011,10 +1725,11 @@ impl ToTokens for Expression {
                 quote!(#left #operation #right)
             }
             Expression::Member(expression_raw, selector_raw) => {
-                let expression_maybe = TokenStream::from_str(&expression_raw.to_case(Snake));
+                let expression_maybe =
+                    TokenStream::from_str(&escape_keyword(expression_raw.to_case(Snake)));
                 if let Ok(expression) = expression_maybe {
                     if let Some(selector_raw) = selector_raw {
-                        let selector = format_ident!("{}", selector_raw);
+                        let selector = TokenStream::from_str(selector_raw).unwrap();
                         quote!(#selector.data.#expression)
                     } else {
                         quote!(#expression)
@@ -1035,7 +1750,7 @@ impl ToTokens for Expression {
                     }
                     quote!((#inner))
                 } else {
-                    let expression = indices_raw.get(0).unwrap();
+                    let expression = indices_raw.first().unwrap();
                     quote!(#expression)
                 };
                 if let Some(insert) = insert_maybe {
@@ -1044,16 +1759,19 @@ impl ToTokens for Expression {
                     quote!(#expression.get(&#indices).unwrap_or_default())
                 }
             }
-            Expression::Modifier(modifier_raw) => {
-                let modifier = TokenStream::from_str(modifier_raw).unwrap();
-                quote!(#modifier)
+            Expression::Raw(expression) => {
+                let content = expression.to_string();
+                TokenStream::from_str(&content).unwrap_or_else(|_| {
+                    TokenStream::from_str(format!("\"S2I_ERR:{content}\"").as_str()).unwrap()
+                })
             }
             Expression::NewArray(array_type_raw, array_size) => {
                 let array_type = TokenStream::from_str(array_type_raw).unwrap();
                 quote!(vec![#array_type::default(); #array_size])
             }
             Expression::StructArg(field_name_raw, value) => {
-                let field_name = TokenStream::from_str(&field_name_raw.to_case(Snake)).unwrap();
+                let field_name =
+                    TokenStream::from_str(&escape_keyword(field_name_raw.to_case(Snake))).unwrap();
                 quote!(#field_name : #value)
             }
             Expression::StructInit(struct_name_raw, struct_args_raw) => {
@@ -1097,7 +1815,80 @@ impl ToTokens for Expression {
             Expression::WithSelector(left, right) => {
                 quote!(#left.#right)
             }
+            Expression::Wrapping(left, right, operation, wide) => {
+                let left = enclose_operand(left);
+                let right = exponent(right, operation, false);
+                // `U256` only has the overflowing operations
+                if *wide {
+                    let method = format_ident!("overflowing_{}", arithmetic_method(operation));
+                    quote!(#left.#method(#right).0)
+                } else {
+                    let method = format_ident!("wrapping_{}", arithmetic_method(operation));
+                    quote!(#left.#method(#right))
+                }
+            }
             Expression::ZeroAddressInto => quote!(ZERO_ADDRESS.into()),
         })
     }
 }
+
+/// returns the name as a Rust identifier, the Rust keywords are escaped as raw identifiers,
+/// eg. `r#match` of a Solidity variable `match`
+fn escape_keyword(name: String) -> String {
+    match RUST_KEYWORDS.contains(&name.as_str()) {
+        true => format!("r#{name}"),
+        false => name,
+    }
+}
+
+/// returns the name of the arithmetic operation used in the names of the checked
+/// and wrapping methods, eg. `add` of `checked_add`
+fn arithmetic_method(operation: &Operation) -> &'static str {
+    match operation {
+        Operation::Add => "add",
+        Operation::Subtract => "sub",
+        Operation::Mul => "mul",
+        Operation::Div => "div",
+        Operation::Modulo => "rem",
+        Operation::Neg => "neg",
+        _ => "pow",
+    }
+}
+
+/// Encloses the operand in parentheses if a method can not be called on it directly
+fn enclose_operand(operand: &Expression) -> TokenStream {
+    match operand {
+        Expression::Arithmetic(..)
+        | Expression::Condition(..)
+        | Expression::Logical(..)
+        | Expression::Ternary(..) => quote!((#operand)),
+        _ => quote!(#operand),
+    }
+}
+
+/// The exponent of a primitive integer is `u32`, the exponent of `U256` is `U256`
+///
+/// The exponent which does not fit into `u32` fails with the overflow error,
+/// or panics with it where the error can not be returned
+///
+/// `fallible` if the error can be returned with `?`
+fn exponent(right: &Expression, operation: &Operation, fallible: bool) -> TokenStream {
+    match (operation, right) {
+        (Operation::Pow, Expression::Cast(true, cast_type, _)) if cast_type == "U256::from" => {
+            quote!(#right)
+        }
+        (Operation::Pow, Expression::Literal(number)) if number.parse::<u32>().is_ok() => {
+            quote!(#right)
+        }
+        (Operation::Pow, _) if fallible => {
+            quote! {
+                u32::try_from(#right)
+                    .map_err(|_| Error::Custom(String::from("Arithmetic overflow")))?
+            }
+        }
+        (Operation::Pow, _) => quote!(u32::try_from(#right).expect("Arithmetic overflow")),
+        // the negation has no second operand
+        (Operation::Neg, _) => quote!(),
+        _ => quote!(#right),
+    }
+}
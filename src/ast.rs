@@ -0,0 +1,621 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+pub use crate::lexer::Span;
+use std::fmt::{
+    Display,
+    Formatter,
+    Result,
+};
+
+/// The syntax tree of a Solidity file
+///
+/// Every node keeps the span of its source code, the `Parser` then converts the nodes
+/// to the structures of the ink! contract
+///
+/// `solidity` the version requirement of `pragma solidity`, eg. `^0.8.0`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SourceUnit {
+    pub parts: Vec<SourcePart>,
+    pub solidity: Option<String>,
+}
+
+/// A definition on the file level
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SourcePart {
+    Contract(ContractDefinition),
+    Enum(EnumDefinition),
+    Error(ErrorDefinition),
+    Event(EventDefinition),
+    Function(FunctionDefinition),
+    Import(ImportDirective),
+    Struct(StructDefinition),
+    Type(TypeDefinition),
+    Using(UsingDirective),
+    Variable(VariableDefinition),
+}
+
+/// An `import` of another file, `path` is the imported path as written in the source
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportDirective {
+    pub path: String,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContractKind {
+    Contract,
+    Interface,
+    Library,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractDefinition {
+    pub kind: ContractKind,
+    pub is_abstract: bool,
+    pub name: String,
+    pub base: Vec<InheritanceSpecifier>,
+    pub parts: Vec<ContractPart>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+/// A base contract in the `is` list, with the arguments of its constructor
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InheritanceSpecifier {
+    pub name: String,
+    pub args: Vec<Expression>,
+    pub span: Span,
+}
+
+/// A definition inside of a contract, interface or library
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContractPart {
+    Enum(EnumDefinition),
+    Error(ErrorDefinition),
+    Event(EventDefinition),
+    Function(FunctionDefinition),
+    Struct(StructDefinition),
+    Using(UsingDirective),
+    Variable(VariableDefinition),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnumDefinition {
+    pub name: String,
+    pub values: Vec<String>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorDefinition {
+    pub name: String,
+    pub params: Vec<Parameter>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventDefinition {
+    pub name: String,
+    pub params: Vec<EventParameter>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventParameter {
+    pub type_name: TypeName,
+    pub indexed: bool,
+    pub name: Option<String>,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructDefinition {
+    pub name: String,
+    pub fields: Vec<Parameter>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+/// A user defined value type, eg. `type Price is uint128;`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypeDefinition {
+    pub name: String,
+    pub type_name: TypeName,
+    pub span: Span,
+}
+
+/// The `using A for B;` directive, `library` is None for the `using {f, g} for B;` form
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UsingDirective {
+    pub library: Option<String>,
+    pub span: Span,
+}
+
+/// A state variable or a constant
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VariableDefinition {
+    pub type_name: TypeName,
+    pub name: String,
+    pub constant: bool,
+    pub immutable: bool,
+    pub visibility: Option<Visibility>,
+    pub value: Option<Expression>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FunctionKind {
+    Constructor,
+    Fallback,
+    Function,
+    Modifier,
+    Receive,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Visibility {
+    External,
+    Internal,
+    Private,
+    Public,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mutability {
+    Payable,
+    Pure,
+    View,
+}
+
+/// A function, a constructor or a modifier
+///
+/// `body` is None for functions without implementation
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FunctionDefinition {
+    pub kind: FunctionKind,
+    pub name: String,
+    pub params: Vec<Parameter>,
+    pub returns: Vec<Parameter>,
+    pub visibility: Option<Visibility>,
+    pub mutability: Option<Mutability>,
+    pub is_virtual: bool,
+    pub is_override: bool,
+    pub modifiers: Vec<ModifierInvocation>,
+    pub body: Option<Vec<Statement>>,
+    pub comments: Vec<String>,
+    pub span: Span,
+}
+
+/// A modifier applied to a function, or a call of a base constructor in a constructor header
+///
+/// `args` is None if the modifier is used without parentheses
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModifierInvocation {
+    pub name: String,
+    pub args: Option<Vec<Expression>>,
+    pub span: Span,
+}
+
+/// A parameter of a function, an error or a field of a struct
+///
+/// `storage` if the parameter is a reference to storage, eg. `Counter storage counter`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameter {
+    pub type_name: TypeName,
+    pub name: Option<String>,
+    pub storage: bool,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeName {
+    /// `address`, `uint256`, `bytes32`, `string`, ...
+    Elementary(String),
+    /// a struct, enum or contract, possibly defined in another contract or library
+    UserDefined(Vec<String>),
+    Array(Box<TypeName>, Option<Box<Expression>>),
+    Mapping(Box<TypeName>, Box<TypeName>),
+    /// `function(uint256) internal returns (uint256)`, the types of the parameters
+    /// and of the returned values
+    Function(Vec<TypeName>, Vec<TypeName>),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Statement {
+    /// the trimmed non-empty lines of the assembly block
+    Assembly(Vec<String>, Span),
+    Block(Vec<Statement>, Span),
+    Break(Span),
+    Comment(String, Span),
+    Continue(Span),
+    DoWhile(Vec<Statement>, Expression, Span),
+    Emit(Expression, Span),
+    Expression(Expression, Span),
+    For(
+        Option<Box<Statement>>,
+        Option<Expression>,
+        Option<Expression>,
+        Vec<Statement>,
+        Span,
+    ),
+    /// `else if` is represented as an else block with a single if statement
+    If(Expression, Vec<Statement>, Option<Vec<Statement>>, Span),
+    /// the `_;` statement of a modifier
+    ModifierBody(Span),
+    Return(Option<Expression>, Span),
+    /// the called expression, the header of the try statement as it is written in the source
+    /// and the parameters of its `returns`
+    Try(Expression, String, Vec<Parameter>, Vec<Statement>, Vec<CatchClause>, Span),
+    Unchecked(Vec<Statement>, Span),
+    /// more than one declaration is a tuple declaration, missing components are None
+    VariableDeclaration(Vec<Option<VariableDeclaration>>, Option<Expression>, Span),
+    While(Expression, Vec<Statement>, Span),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatchClause {
+    /// the header of the catch clause as it is written in the source
+    pub header: String,
+    /// `Error` or `Panic`, None if the clause catches any failure
+    pub kind: Option<String>,
+    pub parameters: Vec<Parameter>,
+    pub body: Vec<Statement>,
+    pub span: Span,
+}
+
+/// `storage` if the variable is a reference to storage, eg. `Proposal storage proposal`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VariableDeclaration {
+    pub type_name: TypeName,
+    pub name: String,
+    pub storage: bool,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expression {
+    ArrayLiteral(Vec<Expression>, Span),
+    /// `a = b`, `a += b`, ...
+    Assign(Box<Expression>, String, Box<Expression>, Span),
+    Binary(Box<Expression>, String, Box<Expression>, Span),
+    BoolLiteral(bool, Span),
+    Call(Box<Expression>, Vec<Expression>, Span),
+    /// `a.call{value: 1}`
+    CallOptions(Box<Expression>, Vec<NamedArgument>, Span),
+    Conditional(Box<Expression>, Box<Expression>, Box<Expression>, Span),
+    /// the content of `hex"..."`
+    HexLiteral(String, Span),
+    Identifier(String, Span),
+    Index(Box<Expression>, Option<Box<Expression>>, Span),
+    Member(Box<Expression>, String, Span),
+    /// `f({a: 1, b: 2})`
+    NamedCall(Box<Expression>, Vec<NamedArgument>, Span),
+    New(TypeName, Span),
+    /// the number and its unit, eg. `1 ether`
+    NumberLiteral(String, Option<String>, Span),
+    Parenthesis(Box<Expression>, Span),
+    Postfix(Box<Expression>, String, Span),
+    Prefix(String, Box<Expression>, Span),
+    /// the string literal including the quotes
+    StringLiteral(String, Span),
+    Tuple(Vec<Option<Expression>>, Span),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NamedArgument {
+    pub name: String,
+    pub value: Expression,
+    pub span: Span,
+}
+
+impl Statement {
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Assembly(.., span)
+            | Statement::Block(.., span)
+            | Statement::Break(span)
+            | Statement::Comment(.., span)
+            | Statement::Continue(span)
+            | Statement::DoWhile(.., span)
+            | Statement::Emit(.., span)
+            | Statement::Expression(.., span)
+            | Statement::For(.., span)
+            | Statement::If(.., span)
+            | Statement::ModifierBody(span)
+            | Statement::Return(.., span)
+            | Statement::Try(.., span)
+            | Statement::Unchecked(.., span)
+            | Statement::VariableDeclaration(.., span)
+            | Statement::While(.., span) => *span,
+        }
+    }
+
+    /// Calls `visit` with this statement and with all statements nested in it
+    pub fn walk(&self, visit: &mut dyn FnMut(&Statement)) {
+        visit(self);
+        let mut walk_all = |statements: &[Statement]| {
+            for statement in statements.iter() {
+                statement.walk(visit);
+            }
+        };
+        match self {
+            Statement::Block(statements, _)
+            | Statement::DoWhile(statements, ..)
+            | Statement::Unchecked(statements, _)
+            | Statement::While(_, statements, _) => walk_all(statements),
+            Statement::For(init, _, _, statements, _) => {
+                if let Some(init) = init {
+                    walk_all(std::slice::from_ref(init));
+                }
+                walk_all(statements);
+            }
+            Statement::If(_, statements, else_statements, _) => {
+                walk_all(statements);
+                walk_all(else_statements.as_deref().unwrap_or_default());
+            }
+            Statement::Try(_, _, _, statements, clauses, _) => {
+                walk_all(statements);
+                for clause in clauses.iter() {
+                    walk_all(&clause.body);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Calls `visit` with all expressions of this statement and of the nested statements,
+    /// including the subexpressions
+    pub fn walk_expressions(&self, visit: &mut dyn FnMut(&Expression)) {
+        self.walk(&mut |statement| {
+            let expressions = match statement {
+                Statement::DoWhile(_, condition, _)
+                | Statement::Emit(condition, _)
+                | Statement::Expression(condition, _)
+                | Statement::If(condition, ..)
+                | Statement::Try(condition, ..)
+                | Statement::While(condition, ..) => vec![condition],
+                Statement::For(_, condition, next, ..) => {
+                    condition.iter().chain(next.iter()).collect()
+                }
+                Statement::Return(value, _) | Statement::VariableDeclaration(_, value, _) => {
+                    value.iter().collect()
+                }
+                _ => Vec::new(),
+            };
+            for expression in expressions {
+                expression.walk(visit);
+            }
+        });
+    }
+}
+
+impl Expression {
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::ArrayLiteral(.., span)
+            | Expression::Assign(.., span)
+            | Expression::Binary(.., span)
+            | Expression::BoolLiteral(.., span)
+            | Expression::Call(.., span)
+            | Expression::CallOptions(.., span)
+            | Expression::Conditional(.., span)
+            | Expression::HexLiteral(.., span)
+            | Expression::Identifier(.., span)
+            | Expression::Index(.., span)
+            | Expression::Member(.., span)
+            | Expression::NamedCall(.., span)
+            | Expression::New(.., span)
+            | Expression::NumberLiteral(.., span)
+            | Expression::Parenthesis(.., span)
+            | Expression::Postfix(.., span)
+            | Expression::Prefix(.., span)
+            | Expression::StringLiteral(.., span)
+            | Expression::Tuple(.., span) => *span,
+        }
+    }
+
+    /// Calls `visit` with this expression and with all its subexpressions
+    pub fn walk(&self, visit: &mut dyn FnMut(&Expression)) {
+        visit(self);
+        match self {
+            Expression::ArrayLiteral(expressions, _) => {
+                for expression in expressions.iter() {
+                    expression.walk(visit);
+                }
+            }
+            Expression::Assign(left, _, right, _) | Expression::Binary(left, _, right, _) => {
+                left.walk(visit);
+                right.walk(visit);
+            }
+            Expression::Call(callee, args, _) => {
+                callee.walk(visit);
+                for arg in args.iter() {
+                    arg.walk(visit);
+                }
+            }
+            Expression::CallOptions(callee, args, _) | Expression::NamedCall(callee, args, _) => {
+                callee.walk(visit);
+                for arg in args.iter() {
+                    arg.value.walk(visit);
+                }
+            }
+            Expression::Conditional(condition, if_true, if_false, _) => {
+                condition.walk(visit);
+                if_true.walk(visit);
+                if_false.walk(visit);
+            }
+            Expression::Index(expression, index, _) => {
+                expression.walk(visit);
+                if let Some(index) = index {
+                    index.walk(visit);
+                }
+            }
+            Expression::Member(expression, ..)
+            | Expression::Parenthesis(expression, _)
+            | Expression::Postfix(expression, ..)
+            | Expression::Prefix(_, expression, _) => expression.walk(visit),
+            Expression::Tuple(expressions, _) => {
+                for expression in expressions.iter().flatten() {
+                    expression.walk(visit);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Display for TypeName {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            TypeName::Elementary(name) => write!(f, "{name}"),
+            TypeName::UserDefined(path) => write!(f, "{}", path.join(".")),
+            TypeName::Array(type_name, Some(size)) => write!(f, "{type_name}[{size}]"),
+            TypeName::Array(type_name, None) => write!(f, "{type_name}[]"),
+            TypeName::Mapping(from, to) => write!(f, "mapping({from}=>{to})"),
+            TypeName::Function(params, returns) => {
+                let join = |types: &[TypeName]| {
+                    types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(",")
+                };
+                write!(f, "function({})", join(params))?;
+                if !returns.is_empty() {
+                    write!(f, " returns ({})", join(returns))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Expression::ArrayLiteral(expressions, _) => write!(f, "[{}]", join(expressions)),
+            Expression::Assign(left, operation, right, _)
+            | Expression::Binary(left, operation, right, _) => {
+                write!(f, "{left} {operation} {right}")
+            }
+            Expression::BoolLiteral(value, _) => write!(f, "{value}"),
+            Expression::Call(callee, args, _) => write!(f, "{callee}({})", join(args)),
+            Expression::CallOptions(callee, args, _) => write!(f, "{callee}{{{}}}", join(args)),
+            Expression::Conditional(condition, if_true, if_false, _) => {
+                write!(f, "{condition} ? {if_true} : {if_false}")
+            }
+            Expression::HexLiteral(value, _) => write!(f, "hex\"{value}\""),
+            Expression::Identifier(name, _) => write!(f, "{name}"),
+            Expression::Index(expression, Some(index), _) => write!(f, "{expression}[{index}]"),
+            Expression::Index(expression, None, _) => write!(f, "{expression}[]"),
+            Expression::Member(expression, member, _) => write!(f, "{expression}.{member}"),
+            Expression::NamedCall(callee, args, _) => write!(f, "{callee}({{{}}})", join(args)),
+            Expression::New(type_name, _) => write!(f, "new {type_name}"),
+            Expression::NumberLiteral(value, Some(unit), _) => write!(f, "{value} {unit}"),
+            Expression::NumberLiteral(value, None, _) => write!(f, "{value}"),
+            Expression::Parenthesis(expression, _) => write!(f, "({expression})"),
+            Expression::Postfix(expression, operation, _) => write!(f, "{expression}{operation}"),
+            Expression::Prefix(operation, expression, _) if operation == "delete" => {
+                write!(f, "delete {expression}")
+            }
+            Expression::Prefix(operation, expression, _) => write!(f, "{operation}{expression}"),
+            Expression::StringLiteral(value, _) => write!(f, "{value}"),
+            Expression::Tuple(expressions, _) => {
+                let components = expressions
+                    .iter()
+                    .map(|expression| {
+                        expression
+                            .as_ref()
+                            .map(|expression| expression.to_string())
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<String>>();
+                write!(f, "({})", components.join(", "))
+            }
+        }
+    }
+}
+
+impl Display for NamedArgument {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}: {}", self.name, self.value)
+    }
+}
+
+impl Display for VariableDeclaration {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{} {}", self.type_name, self.name)
+    }
+}
+
+/// Displays the statements which fit on one line, blocks are displayed by their keyword
+impl Display for Statement {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Statement::Assembly(..) => write!(f, "assembly {{ }}"),
+            Statement::Block(..) => write!(f, "{{ }}"),
+            Statement::Break(_) => write!(f, "break;"),
+            Statement::Comment(comment, _) => write!(f, "// {comment}"),
+            Statement::Continue(_) => write!(f, "continue;"),
+            Statement::DoWhile(_, condition, _) => write!(f, "do {{ }} while ({condition});"),
+            Statement::Emit(event, _) => write!(f, "emit {event};"),
+            Statement::Expression(expression, _) => write!(f, "{expression};"),
+            Statement::For(..) => write!(f, "for (;;) {{ }}"),
+            Statement::If(condition, ..) => write!(f, "if ({condition}) {{ }}"),
+            Statement::ModifierBody(_) => write!(f, "_;"),
+            Statement::Return(Some(value), _) => write!(f, "return {value};"),
+            Statement::Return(None, _) => write!(f, "return;"),
+            Statement::Try(_, header, ..) => write!(f, "{header} }}"),
+            Statement::Unchecked(..) => write!(f, "unchecked {{ }}"),
+            Statement::VariableDeclaration(declarations, value, _) => {
+                let declarations = declarations
+                    .iter()
+                    .map(|declaration| {
+                        declaration
+                            .as_ref()
+                            .map(|declaration| declaration.to_string())
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<String>>();
+                if declarations.len() == 1 {
+                    write!(f, "{}", declarations[0])?;
+                } else {
+                    write!(f, "({})", declarations.join(", "))?;
+                }
+                match value {
+                    Some(value) => write!(f, " = {value};"),
+                    None => write!(f, ";"),
+                }
+            }
+            Statement::While(condition, ..) => write!(f, "while ({condition}) {{ }}"),
+        }
+    }
+}
+
+fn join<T: Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
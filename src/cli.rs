@@ -0,0 +1,179 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use sol_to_ink::{
+    assembler::Backend,
+    parser::{
+        Mangling,
+        ParserError,
+        Selectors,
+    },
+    project::{
+        self,
+        Remapping,
+    },
+    toml_builder::Manifest,
+};
+use clap::{
+    error::ErrorKind,
+    CommandFactory,
+    Parser,
+};
+use std::path::PathBuf;
+
+/// Transpiles Solidity contracts, interfaces and libraries into ink! crates
+#[derive(Debug, Parser)]
+#[command(name = "sol2ink", version, about)]
+pub struct Args {
+    /// Solidity files, directories or glob patterns to transpile, and import remappings in the
+    /// `prefix=target` form used by solc
+    #[arg(required = true, value_name = "INPUT")]
+    pub inputs: Vec<String>,
+    /// Writes the transpiled crates into this directory instead of next to the Solidity files
+    #[arg(short, long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+    /// Overwrites the previously transpiled crates
+    #[arg(short, long)]
+    pub force: bool,
+    /// Transpiles the files without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Prints the errors only
+    #[arg(short, long, conflicts_with = "verbose")]
+    pub quiet: bool,
+    /// Also prints the transpiled files and every spot of the code needing manual review
+    #[arg(short, long)]
+    pub verbose: bool,
+    /// The version of ink! targeted by the generated code
+    #[arg(long, value_name = "BACKEND", default_value = "ink3", value_parser = ["ink3", "ink4"])]
+    pub target: String,
+    /// The ink! version required by the generated crates
+    #[arg(long, value_name = "VERSION")]
+    pub ink_version: Option<String>,
+    /// The OpenBrush version required by the generated crates
+    #[arg(long, value_name = "VERSION")]
+    pub openbrush_version: Option<String>,
+    /// The prefix of the names of the generated packages, which are derived from the names
+    /// of the contracts
+    #[arg(long, value_name = "PREFIX")]
+    pub package_prefix: Option<String>,
+    /// The authors of the generated packages
+    #[arg(long, value_name = "AUTHOR", value_delimiter = ',')]
+    pub authors: Option<Vec<String>>,
+    /// The Rust edition of the generated packages
+    #[arg(long, value_name = "EDITION")]
+    pub edition: Option<String>,
+    /// Transpiles the unsigned integers wider than 128 bits to `U256` instead of `u128`
+    #[arg(long)]
+    pub u256: bool,
+    /// The naming of the overloaded functions, after the types or the number of the parameters
+    #[arg(long, value_name = "SCHEME", default_value = "types", value_parser = ["types", "arity"])]
+    pub mangling: String,
+    /// The messages with explicit selectors, `overloads` gives the overloaded messages the
+    /// selectors of their Solidity signatures, `solidity` gives them to all messages
+    #[arg(
+        long,
+        value_name = "WHICH",
+        default_value = "ink",
+        value_parser = ["ink", "overloads", "solidity"]
+    )]
+    pub selectors: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+/// The options of a transpilation run
+///
+/// `inputs` the files and directories to transpile, with the glob patterns expanded
+/// `output_dir` the directory of the transpiled crates, None to write them next to the sources
+/// `force` if the previously transpiled crates may be overwritten
+/// `dry_run` if the transpiled crates are only reported, not written
+/// `manifest` the settings of the generated Cargo.toml files
+/// `mangling` the naming of the overloaded functions
+/// `selectors` the messages which get an explicit selector
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub inputs: Vec<String>,
+    pub remappings: Vec<Remapping>,
+    pub output_dir: Option<PathBuf>,
+    pub force: bool,
+    pub dry_run: bool,
+    pub verbosity: Verbosity,
+    pub manifest: Manifest,
+    pub mangling: Mangling,
+    pub selectors: Selectors,
+}
+
+impl Options {
+    /// Creates the options from the command line arguments
+    ///
+    /// Exits with a usage error if the settings of the generated Cargo.toml are not consistent
+    ///
+    /// returns `ParserError::FileError` if a glob pattern is invalid or matches no file
+    pub fn from_args(args: Args) -> Result<Options, ParserError> {
+        let backend = args.target.parse::<Backend>().unwrap_or_default();
+        let default = Manifest::new(backend);
+        let manifest = Manifest {
+            backend,
+            ink_version: args.ink_version.unwrap_or(default.ink_version),
+            openbrush_version: args.openbrush_version.unwrap_or(default.openbrush_version),
+            package_prefix: args.package_prefix,
+            authors: args.authors.unwrap_or(default.authors),
+            edition: args.edition.unwrap_or(default.edition),
+            u256: args.u256,
+        };
+        if let Err(error) = manifest.validate() {
+            Args::command().error(ErrorKind::InvalidValue, error).exit()
+        }
+
+        let (remappings, patterns): (Vec<String>, Vec<String>) =
+            args.inputs.into_iter().partition(|arg| arg.contains('='));
+        let verbosity = if args.quiet {
+            Verbosity::Quiet
+        } else if args.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        };
+
+        Ok(Options {
+            inputs: project::expand_globs(&patterns)?,
+            remappings: remappings
+                .iter()
+                .filter_map(|remapping| Remapping::parse(remapping))
+                .collect(),
+            output_dir: args.output_dir,
+            force: args.force,
+            dry_run: args.dry_run,
+            verbosity,
+            manifest,
+            mangling: args.mangling.parse().unwrap_or_default(),
+            selectors: args.selectors.parse().unwrap_or_default(),
+        })
+    }
+}
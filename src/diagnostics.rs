@@ -0,0 +1,178 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::lexer::Span;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// An error or a warning found while transpiling a file
+///
+/// `span` is the position of the offending code, or None if the diagnostic concerns the whole file
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    pub fn error(message: String, span: Option<Span>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            message,
+            span,
+        }
+    }
+
+    pub fn warning(message: String, span: Option<Span>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            message,
+            span,
+        }
+    }
+
+    /// Renders the diagnostic the same way as rustc does, with the offending line of the source
+    ///
+    /// `path` the path of the file, as shown to the user
+    /// `source` the content of the file
+    ///
+    /// returns the rendered diagnostic, ending with a new line
+    pub fn render(&self, path: &str, source: &str) -> String {
+        let mut output = format!("{}: {}\n", self.severity, self.message);
+        let span = match self.span {
+            Some(span) => span,
+            None => {
+                output.push_str(&format!(" --> {path}\n"));
+                return output
+            }
+        };
+
+        let line_start = source[..span.start].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[span.start..]
+            .find('\n')
+            .map_or(source.len(), |index| span.start + index);
+        let line = source[line_start..line_end].trim_end_matches('\r');
+        let line_number = span.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        // tabs are rendered as four spaces, so the marker has to be shifted as well
+        let width = |text: &str| {
+            text.chars()
+                .map(|ch| if ch == '\t' { 4 } else { 1 })
+                .sum::<usize>()
+        };
+        let offset = width(&source[line_start..span.start]);
+        let length = width(&source[span.start..span.end.clamp(span.start, line_end)]).max(1);
+
+        output.push_str(&format!("{gutter}--> {path}:{}:{}\n", span.line, span.column));
+        output.push_str(&format!("{gutter} |\n"));
+        output.push_str(&format!("{line_number} | {}\n", line.replace('\t', "    ")));
+        output.push_str(&format!(
+            "{gutter} | {}{}\n",
+            " ".repeat(offset),
+            "^".repeat(length)
+        ));
+        output
+    }
+}
+
+/// The diagnostics reported while transpiling a file
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Diagnostics {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    /// adds the diagnostic, unless the same diagnostic was already reported
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// reports an error located at `span`
+    pub fn error(&mut self, message: String, span: Option<Span>) {
+        self.push(Diagnostic::error(message, span));
+    }
+
+    /// reports a warning located at `span`
+    pub fn warning(&mut self, message: String, span: Option<Span>) {
+        self.push(Diagnostic::warning(message, span));
+    }
+
+    /// returns the number of reported errors, the file can not be transpiled if there are any
+    pub fn errors(&self) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Error)
+            .count()
+    }
+
+    /// returns the number of reported warnings
+    pub fn warnings(&self) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == Severity::Warning)
+            .count()
+    }
+
+    /// removes the warnings, so only the errors are rendered
+    pub fn retain_errors(&mut self) {
+        self.diagnostics
+            .retain(|diagnostic| diagnostic.severity == Severity::Error);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// Renders all diagnostics, sorted by their position in the file
+    ///
+    /// `path` the path of the file, as shown to the user
+    /// `source` the content of the file
+    pub fn render(&self, path: &str, source: &str) -> String {
+        let mut diagnostics = self.diagnostics.iter().collect::<Vec<_>>();
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.map(|span| span.start));
+        diagnostics
+            .iter()
+            .map(|diagnostic| format!("{}\n", diagnostic.render(path, source)))
+            .collect()
+    }
+}
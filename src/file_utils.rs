@@ -20,6 +20,7 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::report::Report;
 use proc_macro2::TokenStream;
 use std::{
     fs::{
@@ -34,46 +35,135 @@ use std::{
 
 use rust_format::{
     Config,
+    Edition,
     Formatter,
     PostProcess,
     RustFmt,
 };
 
-use crate::toml_builder;
-
 /// Reads the file to be transpiled and returns it as string
 ///
 /// `path` the path to the file
+///
+/// returns the error with the path of the file if it can not be read
 pub fn read_file(path: &String) -> std::io::Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
     let mut contents = String::new();
-    buf_reader.read_to_string(&mut contents)?;
+    File::open(path)
+        .and_then(|file| BufReader::new(file).read_to_string(&mut contents))
+        .map_err(|error| {
+            std::io::Error::new(error.kind(), format!("cannot read `{path}`: {error}"))
+        })?;
     Ok(contents)
 }
 
-/// writes the output to file
+/// writes the transpiled crate
 ///
-/// `lines` the transpiled file in the form of vec of strings
-/// each item in the vec represents a separate line in the output file
-pub fn write_file(
-    lines: TokenStream,
-    file_name: Option<String>,
-) -> std::io::Result<()> {
-    let path = file_name.unwrap_or_else(|| String::from("output"));
-    create_dir_all(&path)?;
+/// `path` the path to the output crate
+/// `lib_rs` the content of `lib.rs` of the crate
+/// `cargo_toml` the content of the Cargo.toml of the crate
+pub fn write_file(path: &str, lib_rs: &str, cargo_toml: &str) -> std::io::Result<()> {
+    create_dir_all(path)?;
 
     let mut file = File::create(format!("{path}/lib.rs"))?;
-    let config = Config::new_str().post_proc(PostProcess::ReplaceMarkersAndDocBlocks);
-    file.write_all(
-        RustFmt::from_config(config)
-            .format_tokens(lines)
-            .unwrap()
-            .as_bytes(),
-    )?;
+    file.write_all(lib_rs.as_bytes())?;
+
+    let mut cargo_toml_file = File::create(format!("{path}/Cargo.toml"))?;
+    cargo_toml_file.write_all(cargo_toml.as_bytes())?;
+
+    Ok(())
+}
 
-    let mut cargo_toml = File::create(format!("{path}/Cargo.toml"))?;
-    cargo_toml.write_all(toml_builder::generate_cargo_toml().as_bytes())?;
+/// The options of rustfmt formatting the transpiled files
+///
+/// Every option changing the output is passed, so the files do not depend on the `rustfmt.toml`
+/// of the working directory. The unstable options keep their default values, as stable rustfmt
+/// ignores them.
+const RUSTFMT_OPTIONS: &[(&str, &str)] = &[
+    ("max_width", "100"),
+    ("hard_tabs", "false"),
+    ("tab_spaces", "4"),
+    ("newline_style", "Unix"),
+    ("use_small_heuristics", "Default"),
+    ("fn_call_width", "60"),
+    ("attr_fn_like_width", "70"),
+    ("struct_lit_width", "18"),
+    ("struct_variant_width", "35"),
+    ("array_width", "60"),
+    ("chain_width", "60"),
+    ("single_line_if_else_max_width", "50"),
+    ("single_line_let_else_max_width", "50"),
+    ("short_array_element_width_threshold", "10"),
+    ("match_arm_leading_pipes", "Never"),
+    ("reorder_imports", "true"),
+    ("reorder_modules", "true"),
+    ("remove_nested_parens", "true"),
+    ("merge_derives", "true"),
+    ("use_try_shorthand", "true"),
+    ("use_field_init_shorthand", "true"),
+    ("force_explicit_abi", "true"),
+    ("match_block_trailing_comma", "false"),
+    ("indent_style", "Block"),
+    ("wrap_comments", "false"),
+    ("format_code_in_doc_comments", "false"),
+    ("normalize_comments", "false"),
+    ("normalize_doc_attributes", "false"),
+    ("format_strings", "false"),
+    ("format_macro_matchers", "false"),
+    ("format_macro_bodies", "true"),
+    ("empty_item_single_line", "true"),
+    ("struct_lit_single_line", "true"),
+    ("fn_single_line", "false"),
+    ("where_single_line", "false"),
+    ("imports_indent", "Block"),
+    ("imports_layout", "Mixed"),
+    ("imports_granularity", "Preserve"),
+    ("group_imports", "Preserve"),
+    ("reorder_impl_items", "false"),
+    ("type_punctuation_density", "Wide"),
+    ("space_before_colon", "false"),
+    ("space_after_colon", "true"),
+    ("spaces_around_ranges", "false"),
+    ("binop_separator", "Front"),
+    ("combine_control_expr", "true"),
+    ("overflow_delimited_expr", "false"),
+    ("struct_field_align_threshold", "0"),
+    ("enum_discrim_align_threshold", "0"),
+    ("match_arm_blocks", "true"),
+    ("force_multiline_blocks", "false"),
+    ("brace_style", "SameLineWhere"),
+    ("control_brace_style", "AlwaysSameLine"),
+    ("trailing_semicolon", "true"),
+    ("trailing_comma", "Vertical"),
+    ("blank_lines_upper_bound", "1"),
+    ("blank_lines_lower_bound", "0"),
+    ("condense_wildcard_suffixes", "false"),
+    ("hex_literal_case", "Preserve"),
+];
+
+/// Formats the transpiled file with rustfmt
+///
+/// `lines` the transpiled file
+///
+/// returns the content of the file as it is written to `lib.rs`
+pub fn format_tokens(lines: TokenStream) -> std::io::Result<String> {
+    let config = RUSTFMT_OPTIONS
+        .iter()
+        .fold(Config::new_str(), |config, (key, value)| {
+            config.option(key, value)
+        })
+        .edition(Edition::Rust2021)
+        .post_proc(PostProcess::ReplaceMarkersAndDocBlocks);
+    RustFmt::from_config(config)
+        .format_tokens(lines)
+        .map_err(|error| std::io::Error::other(error.to_string()))
+}
 
+/// writes the transpilation report next to the transpiled file
+///
+/// `report` the report of the output crate
+/// `path` the path to the output crate
+pub fn write_report(report: &Report, path: &str) -> std::io::Result<()> {
+    let mut file = File::create(format!("{path}/report.json"))?;
+    file.write_all(report.to_json().as_bytes())?;
     Ok(())
 }
@@ -0,0 +1,374 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::diagnostics::Diagnostics;
+
+/// Punctuation and operators of Solidity, longer ones first so we always match the longest one
+const PUNCTUATION: [&str; 47] = [
+    ">>>=", "<<=", ">>=", ">>>", "...", "==", "!=", "<=", ">=", "&&", "||", "++", "--", "+=",
+    "-=", "*=", "/=", "%=", "&=", "|=", "^=", "<<", ">>", "**", "=>", "->", ":=", "+", "-", "*",
+    "/", "%", "&", "|", "^", "~", "!", "<", ">", "=", "?", ":", ";", ",", ".", "(", ")",
+];
+
+/// Brackets are matched separately from the operators
+const BRACKETS: [char; 4] = ['{', '}', '[', ']'];
+
+/// The position of a token or of a node of the syntax tree in the source file
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Span {
+    /// byte offset of the first character
+    pub start: usize,
+    /// byte offset after the last character
+    pub end: usize,
+    /// line of the first character, starting with 1
+    pub line: usize,
+    /// column of the first character, starting with 1
+    pub column: usize,
+}
+
+impl Span {
+    /// returns the span starting at the beginning of `self` and ending at the end of `other`
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            end: other.end,
+            ..*self
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind {
+    Identifier,
+    Number,
+    /// string literal including the quotes
+    String,
+    /// content of a `hex"..."` literal
+    HexString,
+    /// one line of a comment, without the comment marks
+    Comment,
+    Punctuation,
+    Eof,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub span: Span,
+}
+
+/// Splits the Solidity source into tokens
+///
+/// Comments are kept as tokens, so they can be used as documentation or transpiled as comments.
+/// Multiline comments are split into one token per non-empty line.
+///
+/// Unexpected characters and unterminated strings are reported to `diagnostics` and skipped.
+///
+/// `source` the content of the Solidity file
+/// `diagnostics` the diagnostics of the file
+///
+/// returns the tokens of the file, the last token is always `TokenKind::Eof`
+pub fn tokenize(source: &str, diagnostics: &mut Diagnostics) -> Vec<Token> {
+    Lexer::new(source, diagnostics).tokenize()
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    chars: Vec<(usize, char)>,
+    position: usize,
+    line: usize,
+    column: usize,
+    tokens: Vec<Token>,
+    diagnostics: &'a mut Diagnostics,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str, diagnostics: &'a mut Diagnostics) -> Self {
+        Lexer {
+            source,
+            chars: source.char_indices().collect(),
+            position: 0,
+            line: 1,
+            column: 1,
+            tokens: Vec::new(),
+            diagnostics,
+        }
+    }
+
+    fn tokenize(mut self) -> Vec<Token> {
+        while let Some(ch) = self.peek(0) {
+            let start = self.span();
+            match ch {
+                _ if ch.is_whitespace() => {
+                    self.bump();
+                }
+                '/' if self.peek(1) == Some('/') => self.line_comment(),
+                '/' if self.peek(1) == Some('*') => self.block_comment(),
+                '"' | '\'' => {
+                    self.string(ch, start);
+                    let text = self.text(start);
+                    self.push(TokenKind::String, text, start);
+                }
+                _ if ch.is_ascii_digit() => self.number(start),
+                _ if is_identifier_start(ch) => self.identifier(start),
+                _ if BRACKETS.contains(&ch) => {
+                    self.bump();
+                    self.push(TokenKind::Punctuation, ch.to_string(), start);
+                }
+                _ => {
+                    let rest = &self.source[self.offset()..];
+                    let punctuation = match PUNCTUATION
+                        .iter()
+                        .find(|punctuation| rest.starts_with(*punctuation))
+                    {
+                        Some(punctuation) => punctuation,
+                        None => {
+                            self.bump();
+                            let span = Span {
+                                end: self.offset(),
+                                ..start
+                            };
+                            self.diagnostics
+                                .error(format!("unexpected character `{ch}`"), Some(span));
+                            continue
+                        }
+                    };
+                    for _ in 0..punctuation.len() {
+                        self.bump();
+                    }
+                    self.push(TokenKind::Punctuation, punctuation.to_string(), start);
+                }
+            }
+        }
+
+        let eof = self.span();
+        self.push(TokenKind::Eof, String::new(), eof);
+        self.tokens
+    }
+
+    /// Reads a `//` comment, a third slash of documentation comments is skipped
+    fn line_comment(&mut self) {
+        let start = self.span();
+        self.bump();
+        self.bump();
+        if self.peek(0) == Some('/') {
+            self.bump();
+        }
+        let content_start = self.offset();
+        while self.peek(0).is_some() && self.peek(0) != Some('\n') {
+            self.bump();
+        }
+        let text = self.source[content_start..self.offset()].trim().to_owned();
+        self.push(TokenKind::Comment, text, start);
+    }
+
+    /// Reads a `/* */` comment, the leading asterisks of the lines are skipped
+    fn block_comment(&mut self) {
+        let mut start = self.span();
+        let mut buffer = String::new();
+        let mut reading = false;
+        let mut new_line = false;
+        let mut asterisk = false;
+        self.bump();
+        self.bump();
+
+        while let Some(ch) = self.bump() {
+            if ch == '/' && asterisk {
+                if !buffer.trim().is_empty() {
+                    let text = format!(" {}", buffer.trim());
+                    self.push(TokenKind::Comment, text, start);
+                }
+                break
+            } else {
+                asterisk = false;
+            }
+            match ch {
+                '*' if !reading => reading = true,
+                '*' if new_line => new_line = false,
+                '\n' => {
+                    if !buffer.trim().is_empty() {
+                        let text = format!(" {}", buffer.trim());
+                        self.push(TokenKind::Comment, text, start);
+                        buffer.clear();
+                    }
+                    start = self.span();
+                    new_line = true;
+                }
+                _ if !reading => {
+                    buffer.push(ch);
+                    reading = true;
+                }
+                ' ' if new_line => {}
+                _ if new_line => {
+                    buffer.push(ch);
+                    new_line = false;
+                }
+                _ => buffer.push(ch),
+            }
+            if ch == '*' {
+                asterisk = true;
+            }
+        }
+    }
+
+    /// Reads a string literal enclosed in `quote`, escaped characters are kept as they are
+    ///
+    /// An unterminated string ends at the end of the line
+    fn string(&mut self, quote: char, start: Span) {
+        self.bump();
+        loop {
+            match self.bump() {
+                Some('\\') => {
+                    self.bump();
+                }
+                Some(ch) if ch == quote => return,
+                Some('\n') | None => {
+                    self.diagnostics
+                        .error(String::from("unterminated string literal"), Some(start));
+                    return
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Reads a decimal, scientific or hexadecimal number
+    fn number(&mut self, start: Span) {
+        if self.peek(0) == Some('0') && matches!(self.peek(1), Some('x') | Some('X')) {
+            self.bump();
+            self.bump();
+            while self
+                .peek(0)
+                .is_some_and(|ch| ch.is_ascii_hexdigit() || ch == '_')
+            {
+                self.bump();
+            }
+        } else {
+            self.digits();
+            if self.peek(0) == Some('.') && self.peek(1).is_some_and(|ch| ch.is_ascii_digit()) {
+                self.bump();
+                self.digits();
+            }
+            if matches!(self.peek(0), Some('e') | Some('E')) {
+                let exponent_digit = if self.peek(1) == Some('-') { 2 } else { 1 };
+                if self
+                    .peek(exponent_digit)
+                    .is_some_and(|ch| ch.is_ascii_digit())
+                {
+                    for _ in 0..exponent_digit {
+                        self.bump();
+                    }
+                    self.digits();
+                }
+            }
+        }
+        let text = self.text(start);
+        self.push(TokenKind::Number, text, start);
+    }
+
+    fn digits(&mut self) {
+        while self
+            .peek(0)
+            .is_some_and(|ch| ch.is_ascii_digit() || ch == '_')
+        {
+            self.bump();
+        }
+    }
+
+    /// Reads an identifier, `hex"..."` and `unicode"..."` literals start like identifiers
+    fn identifier(&mut self, start: Span) {
+        while self.peek(0).is_some_and(is_identifier_part) {
+            self.bump();
+        }
+        let text = self.text(start);
+        match self.peek(0) {
+            Some(quote @ ('"' | '\'')) if text == "hex" => {
+                let content_start = self.offset() + 1;
+                self.string(quote, start);
+                let content = self.source[content_start..self.offset() - 1].to_owned();
+                self.push(TokenKind::HexString, content, start);
+            }
+            Some(quote @ ('"' | '\'')) if text == "unicode" => {
+                let string_start = self.span();
+                self.string(quote, start);
+                let text = self.text(string_start);
+                self.push(TokenKind::String, text, start);
+            }
+            _ => self.push(TokenKind::Identifier, text, start),
+        }
+    }
+
+    fn peek(&self, n: usize) -> Option<char> {
+        self.chars.get(self.position + n).map(|(_, ch)| *ch)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek(0)?;
+        self.position += 1;
+        if ch == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(ch)
+    }
+
+    /// returns the byte offset of the current character
+    fn offset(&self) -> usize {
+        self.chars
+            .get(self.position)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(self.source.len())
+    }
+
+    /// returns an empty span at the current character
+    fn span(&self) -> Span {
+        Span {
+            start: self.offset(),
+            end: self.offset(),
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    /// returns the source from `start` to the current character
+    fn text(&self, start: Span) -> String {
+        self.source[start.start..self.offset()].to_owned()
+    }
+
+    fn push(&mut self, kind: TokenKind, text: String, start: Span) {
+        let span = Span {
+            end: self.offset(),
+            ..start
+        };
+        self.tokens.push(Token { kind, text, span });
+    }
+}
+
+fn is_identifier_start(ch: char) -> bool {
+    ch.is_ascii_alphabetic() || ch == '_' || ch == '$'
+}
+
+fn is_identifier_part(ch: char) -> bool {
+    ch.is_ascii_alphanumeric() || ch == '_' || ch == '$'
+}
@@ -0,0 +1,442 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+#![feature(string_remove_matches)]
+
+//! Transpiles Solidity contracts, interfaces and libraries into ink! crates
+//!
+//! `transpile` transpiles a single Solidity file without touching the file system,
+//! the `sol_to_ink` binary transpiles whole projects.
+
+pub mod assembler;
+pub mod ast;
+pub mod diagnostics;
+pub mod file_utils;
+pub mod formatter;
+pub mod lexer;
+pub mod parser;
+pub mod project;
+pub mod report;
+pub mod structures;
+pub mod syntax;
+pub mod toml_builder;
+
+use crate::{
+    diagnostics::Diagnostics,
+    parser::{
+        Definitions,
+        Mangling,
+        Parser,
+        ParserError,
+        ParserOutput,
+        Selectors,
+    },
+    report::ReportEntry,
+    structures::Interface,
+    toml_builder::{
+        Dependency,
+        Manifest,
+    },
+};
+use proc_macro2::TokenStream;
+use std::collections::HashMap;
+
+/// The options of `transpile`
+///
+/// `name` the name of the file without the extension, names the packages of interfaces
+/// and libraries
+/// `common_path` the path from the crate of a contract to the crate with the shared types
+/// `manifest` the settings of the generated Cargo.toml files
+/// `mangling` the naming of the overloaded functions
+/// `selectors` the messages which get an explicit selector
+/// `interfaces` the crates of the interfaces transpiled from other files by the names of the
+/// interfaces, the contracts calling these interfaces depend on the crates, the crates of the
+/// other called interfaces are part of the output
+#[derive(Clone, Debug)]
+pub struct TranspileOptions {
+    pub name: String,
+    pub common_path: String,
+    pub manifest: Manifest,
+    pub mangling: Mangling,
+    pub selectors: Selectors,
+    pub interfaces: HashMap<String, Dependency>,
+}
+
+impl Default for TranspileOptions {
+    fn default() -> Self {
+        TranspileOptions {
+            name: String::from("sol_2_ink_generated"),
+            common_path: String::from("../common"),
+            manifest: Manifest::default(),
+            mangling: Mangling::default(),
+            selectors: Selectors::default(),
+            interfaces: HashMap::new(),
+        }
+    }
+}
+
+/// The kind of a transpiled crate
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CrateKind {
+    Contract,
+    /// the interface of a file without contracts
+    Interface,
+    /// the libraries of a file without contracts and interfaces
+    Libraries,
+    /// an interface called by the contracts, the crate is expected next to their crates
+    CalledInterface,
+    /// the structs and enums defined outside of contracts
+    Common,
+}
+
+/// A transpiled crate
+///
+/// `name` the name of the contract or the interface, `common` for the crate with the shared types
+/// `kind` what the crate is transpiled from
+/// `lib_rs` the content of `lib.rs` of the crate
+/// `cargo_toml` the content of `Cargo.toml` of the crate
+/// `report` the code which was not faithfully translated and needs a manual review
+#[derive(Clone, Debug)]
+pub struct Crate {
+    pub name: String,
+    pub kind: CrateKind,
+    pub lib_rs: String,
+    pub cargo_toml: String,
+    pub report: Vec<ReportEntry>,
+}
+
+/// The output of `transpile`
+///
+/// `crates` the transpiled contracts, interface or libraries, followed by the crates of the
+/// interfaces called by the contracts and by the crate with the structs and enums defined
+/// outside of contracts, if there are any. The crates of the called interfaces are expected
+/// next to the crates of the contracts
+/// `diagnostics` the warnings reported while transpiling the source
+#[derive(Clone, Debug)]
+pub struct Output {
+    pub crates: Vec<Crate>,
+    pub diagnostics: Diagnostics,
+}
+
+/// Transpiles the source code of a Solidity file
+///
+/// Imports of the file are not resolved, so the file can only use the definitions it contains
+///
+/// `source` the Solidity source code
+/// `options` the options of the transpilation
+///
+/// returns the transpiled crates, or the diagnostics with the errors found in the source
+pub fn transpile(source: &str, options: &TranspileOptions) -> Result<Output, Diagnostics> {
+    let mut transpiler = Transpiler::default();
+    let mut output = transpiler.transpile(source, options)?;
+    if let Some(common) = transpiler.common(&options.manifest)? {
+        output.crates.push(common);
+    }
+    if output.crates.is_empty() {
+        let mut diagnostics = output.diagnostics;
+        diagnostics.error(ParserError::FileCorrupted.to_string(), None);
+        return Err(diagnostics)
+    }
+    Ok(output)
+}
+
+/// Transpiles the files of a project one after another, the files can use the contracts,
+/// interfaces, libraries and types of the previously transpiled files, eg. of the files
+/// they import
+#[derive(Default)]
+pub struct Transpiler {
+    definitions: Definitions,
+}
+
+impl Transpiler {
+    /// returns the definitions of the transpiled files
+    pub fn definitions(&self) -> &Definitions {
+        &self.definitions
+    }
+
+    /// Transpiles the source code of a Solidity file
+    ///
+    /// The structs and enums defined outside of contracts are shared by the files,
+    /// they are transpiled by `common` once all files are transpiled
+    ///
+    /// `source` the Solidity source code
+    /// `options` the options of the transpilation
+    ///
+    /// returns the transpiled crates, no crates if the file defines only shared types,
+    /// or the diagnostics with the errors found in the source
+    pub fn transpile(
+        &mut self,
+        source: &str,
+        options: &TranspileOptions,
+    ) -> Result<Output, Diagnostics> {
+        if let Err(error) = options.manifest.validate() {
+            let mut diagnostics = Diagnostics::default();
+            diagnostics.error(error, None);
+            return Err(diagnostics)
+        }
+
+        let mut parser = Parser::new(source);
+        parser.with_definitions(self.definitions.clone());
+        parser.with_u256(options.manifest.u256);
+        parser.with_backend(options.manifest.backend);
+        parser.with_mangling(options.mangling);
+        parser.with_selectors(options.selectors);
+        let parsed = parser.parse_file();
+        let mut diagnostics = parser.diagnostics().clone();
+        let output = match parsed {
+            Ok(output) => {
+                self.definitions = parser.definitions().clone();
+                output
+            }
+            Err(error) => {
+                diagnostics.error(error.to_string(), None);
+                return Err(diagnostics)
+            }
+        };
+        if diagnostics.errors() > 0 {
+            return Err(diagnostics)
+        }
+
+        let manifest = &options.manifest;
+        // name, kind, report, Cargo.toml and the tokens of lib.rs of the crates
+        let mut assembled =
+            Vec::<(String, CrateKind, Vec<ReportEntry>, String, TokenStream)>::new();
+        match output {
+            ParserOutput::None => {}
+            ParserOutput::Contracts(contracts) => {
+                let mut interfaces = Vec::new();
+                for contract in contracts {
+                    let common = contract
+                        .imports
+                        .contains("use common::*;")
+                        .then(|| options.common_path.clone());
+                    let dependencies = contract
+                        .interfaces
+                        .iter()
+                        .map(|interface| {
+                            options
+                                .interfaces
+                                .get(&interface.name)
+                                .cloned()
+                                .unwrap_or_else(|| {
+                                    Dependency {
+                                        name: toml_builder::dependency_name(&interface.name),
+                                        package: manifest.package_name(&interface.name),
+                                        path: format!("../{}", interface.name),
+                                    }
+                                })
+                        })
+                        .collect::<Vec<_>>();
+                    for interface in contract.interfaces.iter() {
+                        let known = options.interfaces.contains_key(&interface.name)
+                            || interfaces
+                                .iter()
+                                .any(|called: &Interface| called.name == interface.name);
+                        if !known {
+                            interfaces.push(interface.clone());
+                        }
+                    }
+                    assembled.push((
+                        contract.name.clone(),
+                        CrateKind::Contract,
+                        contract.report.clone(),
+                        toml_builder::generate_cargo_toml(
+                            &contract.name,
+                            common,
+                            &dependencies,
+                            manifest,
+                        ),
+                        assembler::assemble_contract(contract, manifest.backend),
+                    ));
+                }
+                for interface in interfaces {
+                    assembled.push((
+                        interface.name.clone(),
+                        CrateKind::CalledInterface,
+                        Vec::new(),
+                        toml_builder::generate_cargo_toml(&interface.name, None, &[], manifest),
+                        assembler::assemble_interface(interface, manifest.backend),
+                    ));
+                }
+            }
+            ParserOutput::Interface(interface) => {
+                assembled.push((
+                    interface.name.clone(),
+                    CrateKind::Interface,
+                    interface.report.clone(),
+                    toml_builder::generate_cargo_toml(&options.name, None, &[], manifest),
+                    assembler::assemble_interface(*interface, manifest.backend),
+                ));
+            }
+            ParserOutput::Libraries(libraries) => {
+                let report = libraries
+                    .iter()
+                    .flat_map(|library| library.report.iter().cloned())
+                    .collect();
+                assembled.push((
+                    options.name.clone(),
+                    CrateKind::Libraries,
+                    report,
+                    toml_builder::generate_cargo_toml(&options.name, None, &[], manifest),
+                    assembler::assemble_library(libraries, manifest.backend),
+                ));
+            }
+        }
+
+        let mut crates = Vec::new();
+        for (name, kind, report, cargo_toml, tokens) in assembled {
+            match file_utils::format_tokens(tokens) {
+                Ok(lib_rs) => {
+                    crates.push(Crate {
+                        name,
+                        kind,
+                        lib_rs,
+                        cargo_toml,
+                        report,
+                    })
+                }
+                Err(error) => {
+                    diagnostics.error(format!("could not format `{name}`: {error}"), None)
+                }
+            }
+        }
+
+        if diagnostics.errors() > 0 {
+            return Err(diagnostics)
+        }
+        Ok(Output {
+            crates,
+            diagnostics,
+        })
+    }
+
+    /// Transpiles the structs and enums defined outside of contracts in the transpiled files
+    ///
+    /// `manifest` the settings of the generated Cargo.toml
+    ///
+    /// returns the `common` crate, None if the files define no shared types
+    pub fn common(&self, manifest: &Manifest) -> Result<Option<Crate>, Diagnostics> {
+        let definitions = &self.definitions;
+        if definitions.structs.is_empty() && definitions.enums.is_empty() {
+            return Ok(None)
+        }
+        let tokens = assembler::assemble_common(
+            definitions.structs.clone(),
+            definitions.enums.clone(),
+            manifest.backend,
+        );
+        match file_utils::format_tokens(tokens) {
+            Ok(lib_rs) => {
+                Ok(Some(Crate {
+                    name: String::from("common"),
+                    kind: CrateKind::Common,
+                    lib_rs,
+                    cargo_toml: toml_builder::generate_common_cargo_toml(manifest),
+                    report: Vec::new(),
+                }))
+            }
+            Err(error) => {
+                let mut diagnostics = Diagnostics::default();
+                diagnostics.error(format!("could not format `common`: {error}"), None);
+                Err(diagnostics)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        project,
+        transpile,
+        TranspileOptions,
+    };
+    use std::fs;
+
+    #[test]
+    fn transpile_source() {
+        let output = transpile(
+            include_str!("../examples/contracts/Flipper/flipper.sol"),
+            &TranspileOptions::default(),
+        )
+        .expect("flipper is transpiled");
+        assert_eq!(output.crates.len(), 1);
+        assert_eq!(output.crates[0].name, "flipper");
+        assert_eq!(
+            output.crates[0].lib_rs,
+            include_str!("../examples/contracts/Flipper/flipper/lib.rs")
+        );
+        assert_eq!(
+            output.crates[0].cargo_toml,
+            include_str!("../examples/contracts/Flipper/flipper/Cargo.toml")
+        );
+    }
+
+    #[test]
+    fn transpile_invalid_manifest() {
+        let mut options = TranspileOptions::default();
+        options.manifest.ink_version = String::from("2.1.0");
+        assert!(transpile("contract A {}", &options).is_err());
+    }
+
+    #[test]
+    fn order_imports() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path();
+        let write = |name: &str, content: &str| fs::write(root.join(name), content).unwrap();
+        write(
+            "Main.sol",
+            r#"
+            import "./A.sol";
+            import * as B from './B.sol';
+            import {C, D as E} from "./Missing.sol";
+            // import "./Commented.sol";
+            string constant PATH = "import './Literal.sol';";
+            contract Main {}
+            "#,
+        );
+        write("A.sol", "import {B} from \"./B.sol\";\ncontract A {}");
+        write("B.sol", "import \"./A.sol\" as A;\ncontract B {}");
+
+        let main = root.join("Main.sol");
+        let imports = project::parse_imports(&fs::read_to_string(&main).unwrap());
+        let paths = imports
+            .iter()
+            .map(|import| import.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["./A.sol", "./B.sol", "./Missing.sol"]);
+
+        let ordered = project::order_sources(std::slice::from_ref(&main), root, &[]).unwrap();
+        let files = ordered
+            .iter()
+            .map(|(file, _)| file.file_name().unwrap().to_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(files, ["B.sol", "A.sol", "Main.sol"]);
+        let warnings = ordered[2].1.iter().collect::<Vec<_>>();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].message,
+            "imported file `./Missing.sol` was not found, skipping"
+        );
+        assert_eq!(warnings[0].span.map(|span| span.line), Some(4));
+    }
+}
@@ -20,87 +20,403 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-#![feature(once_cell)]
-#![feature(string_remove_matches)]
-#![feature(exclusive_range_pattern)]
-
-pub mod assembler;
-pub mod file_utils;
-pub mod formatter;
-pub mod parser;
-pub mod structures;
-pub mod toml_builder;
+mod cli;
 
 use std::{
-    collections::{
-        HashMap,
-        HashSet,
+    collections::HashMap,
+    path::{
+        Path,
+        PathBuf,
     },
-    env,
 };
 
-use crate::parser::ParserError;
+use clap::Parser;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+use crate::cli::{
+    Args,
+    Options,
+    Verbosity,
+};
+use sol_to_ink::{
+    diagnostics::{
+        Diagnostic,
+        Diagnostics,
+    },
+    file_utils,
+    parser::{
+        Definitions,
+        ParserError,
+    },
+    project,
+    report::Report,
+    toml_builder::{
+        self,
+        Dependency,
+    },
+    Crate,
+    CrateKind,
+    Output,
+    TranspileOptions,
+    Transpiler,
+};
 
-    if args.len() <= 1 {
-        println!("Please pass name of the file as argument");
-        return
-    }
+// the files could not be transpiled, usage errors are reported by clap with the code 2
+const EXIT_INVALID_FILES: i32 = 1;
+// the files could not be read or the crates could not be written
+const EXIT_IO_ERROR: i32 = 3;
+
+fn main() {
+    let result = Options::from_args(Args::parse()).and_then(|options| run_project(&options));
 
-    std::process::exit(match run(&args[1]) {
+    std::process::exit(match result {
         Ok(_) => 0,
         Err(err) => {
-            eprintln!("error: {:?}", err);
-            1
+            eprintln!("error: {err}");
+            match err {
+                ParserError::FileError(_) | ParserError::UnwrittenCrates(_) => EXIT_IO_ERROR,
+                _ => EXIT_INVALID_FILES,
+            }
         }
     });
 }
 
-fn run(path: &String) -> Result<(), parser::ParserError> {
-    // read the file
-    let content = file_utils::read_file(path)?;
-    let mut chars = content.chars();
-    let mut imports = HashSet::new();
-    let mut storage = HashMap::new();
-    let mut functions = HashMap::new();
-    let mut events = HashMap::new();
-    let mut modifiers = HashMap::new();
-    let mut structs = HashMap::new();
-
-    let mut parser = parser::Parser::new(
-        &mut chars,
-        &mut imports,
-        &mut storage,
-        &mut functions,
-        &mut events,
-        &mut modifiers,
-        &mut structs,
-    );
-    let output = parser.parse_file()?;
-    match output {
-        (None, None) | (Some(_), Some(_)) => Err(ParserError::FileCorrupted),
-        (Some(contract), None) => {
-            let ink_contract = assembler::assemble_contract(contract);
-            let file_name = path.replace(".sol", "");
-            file_utils::write_file(ink_contract, Some(file_name))?;
-            println!("File saved!");
-            Ok(())
+/// Transpiles the file or the directory into a temporary directory, so the tests do not
+/// overwrite the transpiled examples
+#[cfg(test)]
+fn run(path: &String) -> Result<(), ParserError> {
+    let output_dir = tempfile::tempdir()?;
+    run_project(&Options {
+        inputs: std::slice::from_ref(path).to_vec(),
+        output_dir: Some(output_dir.path().to_path_buf()),
+        verbosity: Verbosity::Quiet,
+        ..Default::default()
+    })
+}
+
+/// Transpiles the passed files and the Solidity files in the passed directories
+///
+/// The imported files are parsed before the files importing them, so their contracts
+/// can be inherited and their structs, enums and events can be used.
+/// The structs and enums defined outside of contracts are written to the `common` crate
+/// in the root of the project.
+/// Files with errors are reported and skipped, the other files are still transpiled.
+///
+/// returns `ParserError::UnwrittenCrates` if some crates could not be written,
+/// or `ParserError::InvalidFiles` if some files could not be transpiled
+fn run_project(options: &Options) -> Result<(), ParserError> {
+    let root = project::project_root(&options.inputs);
+    let mut remappings = options.remappings.clone();
+    remappings.append(&mut project::read_remappings(&root)?);
+
+    let mut entries = Vec::new();
+    for input in options.inputs.iter() {
+        let path = Path::new(input);
+        if path.is_dir() {
+            entries.append(&mut project::collect_sources(path)?);
+        } else {
+            entries.push(project::normalize(path));
+        }
+    }
+    // the crates are written to the output directory with the same layout as the sources
+    let base = project::common_directory(&entries);
+    let output_path = |path: &Path| {
+        match &options.output_dir {
+            Some(output_dir) => {
+                match path.strip_prefix(&base) {
+                    Ok(relative) => output_dir.join(relative),
+                    Err(_) => output_dir.join(path.file_name().unwrap_or_default()),
+                }
+            }
+            None => path.to_path_buf(),
+        }
+    };
+    let common_path = output_path(&root.join("common"));
+
+    let mut transpiler = Transpiler::default();
+    // the transpiled crates with the files they are transpiled from and their paths
+    let mut crates = Vec::<(PathBuf, PathBuf, Crate)>::new();
+    // interface name -> the path to the crate of the interface and the name of its package
+    let mut interface_crates = HashMap::<String, (PathBuf, String)>::new();
+    let mut invalid_files = 0;
+    for (source, diagnostics) in project::order_sources(&entries, &root, &remappings)? {
+        if options.verbosity == Verbosity::Verbose {
+            println!("Transpiling `{}`", source.display());
+        }
+        let stem = source
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        // the crates of a file are written next to each other, so the relative paths
+        // from the crates to the other crates are the same
+        let crate_path = output_path(&source.with_extension(""));
+        let relative = |path: &Path| {
+            project::relative_path(&crate_path, path)
+                .display()
+                .to_string()
+        };
+        let transpile_options = TranspileOptions {
+            name: stem.clone(),
+            common_path: relative(&common_path),
+            manifest: options.manifest.clone(),
+            mangling: options.mangling,
+            selectors: options.selectors,
+            interfaces: interface_crates
+                .iter()
+                .map(|(name, (path, package))| {
+                    let dependency = Dependency {
+                        name: toml_builder::dependency_name(name),
+                        package: package.clone(),
+                        path: relative(path),
+                    };
+                    (name.clone(), dependency)
+                })
+                .collect(),
+        };
+
+        let types = shared_types(transpiler.definitions());
+        let output =
+            match transpile_file(&source, diagnostics, &mut transpiler, &transpile_options, options)
+            {
+                Ok(output) => output,
+                Err(ParserError::SourceErrors(errors)) => {
+                    eprintln!(
+                        "error: could not transpile `{}` due to {errors} previous error{}\n",
+                        source.display(),
+                        if errors == 1 { "" } else { "s" }
+                    );
+                    invalid_files += 1;
+                    continue
+                }
+                Err(error) => {
+                    eprintln!("error: could not transpile `{}`: {error}\n", source.display());
+                    invalid_files += 1;
+                    continue
+                }
+            };
+        if !entries.contains(&source) {
+            continue
         }
-        (None, Some(interface)) => {
-            let ink_trait = assembler::assemble_interface(interface);
-            let file_name = path.replace(".sol", "");
-            file_utils::write_file(ink_trait, Some(file_name))?;
-            println!("File saved!");
+        // files with shared types only are transpiled into the common crate
+        if output.crates.is_empty() {
+            if shared_types(transpiler.definitions()) == types {
+                let diagnostic = Diagnostic::error(ParserError::FileCorrupted.to_string(), None);
+                eprint!("{}", diagnostic.render(&source.display().to_string(), ""));
+                invalid_files += 1;
+            }
+            continue
+        }
+
+        let named = output
+            .crates
+            .iter()
+            .filter(|transpiled| transpiled.kind != CrateKind::CalledInterface)
+            .count()
+            > 1;
+        for transpiled in output.crates {
+            // a single contract, the interface or the libraries of the file are named
+            // after the file, the other crates after the contract or the interface
+            let path = if named || transpiled.kind == CrateKind::CalledInterface {
+                output_path(&source.with_file_name(&transpiled.name))
+            } else {
+                crate_path.clone()
+            };
+            // eg. the crate of `interface Vault` in one file and of `contract Vault` in another
+            if let Some((other_source, _, other)) =
+                crates.iter().find(|(_, written, _)| *written == path)
+            {
+                eprintln!(
+                    "error: the crate of `{}` of `{}` can not be written to `{}`, \
+                     the crate of `{}` of `{}` is written there\n",
+                    transpiled.name,
+                    source.display(),
+                    path.display(),
+                    other.name,
+                    other_source.display()
+                );
+                invalid_files += 1;
+                continue
+            }
+            let package = match transpiled.kind {
+                CrateKind::Interface => Some(options.manifest.package_name(&stem)),
+                CrateKind::CalledInterface => Some(options.manifest.package_name(&transpiled.name)),
+                _ => None,
+            };
+            if let Some(package) = package {
+                interface_crates.insert(transpiled.name.clone(), (path.clone(), package));
+            }
+            crates.push((source.clone(), path, transpiled));
+        }
+    }
+
+    let mut writer = Writer {
+        options,
+        written: 0,
+        unwritten: 0,
+        spots: 0,
+    };
+    match transpiler.common(&options.manifest) {
+        Ok(Some(common)) => {
+            writer.write(&common_path, None, || {
+                file_utils::write_file(
+                    &common_path.display().to_string(),
+                    &common.lib_rs,
+                    &common.cargo_toml,
+                )
+            })
+        }
+        Ok(None) => {}
+        Err(diagnostics) => {
+            eprint!("{}", diagnostics.render(&common_path.display().to_string(), ""));
+            writer.unwritten += 1;
+        }
+    }
+    for (source, path, transpiled) in crates {
+        let report = Report {
+            file: source.display().to_string(),
+            name: transpiled.name.clone(),
+            entries: transpiled.report.clone(),
+        };
+        writer.write(&path, Some(&report), || {
+            let path = path.display().to_string();
+            file_utils::write_file(&path, &transpiled.lib_rs, &transpiled.cargo_toml)?;
+            // the called interfaces are reported with the contracts calling them
+            if transpiled.kind != CrateKind::CalledInterface {
+                file_utils::write_report(&report, &path)?;
+            }
             Ok(())
+        });
+    }
+
+    if options.verbosity > Verbosity::Quiet {
+        println!(
+            "{} {} crate{}, {} spot{} to review, {invalid_files} file{} could not be transpiled",
+            if options.dry_run { "Would write" } else { "Wrote" },
+            writer.written,
+            if writer.written == 1 { "" } else { "s" },
+            writer.spots,
+            if writer.spots == 1 { "" } else { "s" },
+            if invalid_files == 1 { "" } else { "s" },
+        );
+    }
+
+    if writer.unwritten > 0 {
+        return Err(ParserError::UnwrittenCrates(writer.unwritten))
+    }
+    if invalid_files > 0 {
+        return Err(ParserError::InvalidFiles(invalid_files))
+    }
+    Ok(())
+}
+
+/// returns the number of the structs and enums defined outside of contracts
+fn shared_types(definitions: &Definitions) -> usize {
+    definitions.structs.len() + definitions.enums.len()
+}
+
+/// Writes the transpiled crates according to the options and summarizes them for the user
+struct Writer<'a> {
+    options: &'a Options,
+    // the number of written crates
+    written: usize,
+    // the number of crates which could not be written
+    unwritten: usize,
+    // the number of spots needing manual review in the written crates
+    spots: usize,
+}
+
+impl Writer<'_> {
+    /// Writes the crate, unless it is a dry run or the crate exists and may not be overwritten
+    ///
+    /// `path` the path to the crate
+    /// `report` the report of the crate, None for the common crate
+    /// `write` writes the files of the crate
+    fn write<F>(&mut self, path: &Path, report: Option<&Report>, write: F)
+    where
+        F: FnOnce() -> std::io::Result<()>,
+    {
+        if !self.options.dry_run {
+            if !self.options.force && path.join("lib.rs").exists() {
+                eprintln!(
+                    "error: `{}` already exists, pass `--force` to overwrite it\n",
+                    path.display()
+                );
+                self.unwritten += 1;
+                return
+            }
+            if let Err(error) = write() {
+                eprintln!("error: could not write `{}`: {error}\n", path.display());
+                self.unwritten += 1;
+                return
+            }
+        }
+        self.written += 1;
+
+        let spots = report.map_or(0, |report| report.entries.len());
+        self.spots += spots;
+        match (self.options.verbosity, report) {
+            (Verbosity::Quiet, _) => {}
+            (_, None) => println!("{}: shared types", path.display()),
+            (verbosity, Some(report)) => {
+                println!(
+                    "{}: {} ({}), {spots} spot{} to review",
+                    path.display(),
+                    report.name,
+                    report.file,
+                    if spots == 1 { "" } else { "s" }
+                );
+                if verbosity == Verbosity::Verbose {
+                    print!("{}", report.summary());
+                }
+            }
         }
     }
 }
 
+/// Transpiles the file, definitions of the previously transpiled files can be used by the file
+///
+/// The errors and warnings found in the file are printed with the offending code,
+/// the warnings are not printed in the quiet mode
+///
+/// `diagnostics` the diagnostics reported for the file before it is parsed, eg. its unresolved
+/// imports
+///
+/// returns `ParserError::SourceErrors` if the file contains errors
+fn transpile_file(
+    path: &Path,
+    mut diagnostics: Diagnostics,
+    transpiler: &mut Transpiler,
+    transpile_options: &TranspileOptions,
+    options: &Options,
+) -> Result<Output, ParserError> {
+    let content = file_utils::read_file(&path.display().to_string())?;
+
+    let (output, reported) = match transpiler.transpile(&content, transpile_options) {
+        Ok(output) => {
+            let reported = output.diagnostics.clone();
+            (Some(output), reported)
+        }
+        Err(reported) => (None, reported),
+    };
+    for diagnostic in reported.iter() {
+        diagnostics.push(diagnostic.clone());
+    }
+    if options.verbosity == Verbosity::Quiet {
+        diagnostics.retain_errors();
+    }
+    eprint!(
+        "{}",
+        diagnostics.render(&path.display().to_string(), &content)
+    );
+    match output {
+        Some(output) => Ok(output),
+        None => Err(ParserError::SourceErrors(diagnostics.errors())),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::run;
+    use sol_to_ink::parser::ParserError;
 
     #[test]
     fn erc20() {
@@ -150,6 +466,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn vault() {
+        assert_eq!(
+            run(&"examples/contracts/Vault/Vault.sol".to_string()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn token() {
+        assert_eq!(
+            run(&"examples/contracts/Token/Token.sol".to_string()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn project() {
+        assert_eq!(run(&"examples/project".to_string()), Ok(()));
+    }
+
     #[test]
     fn primitives() {
         assert_eq!(
@@ -158,6 +495,14 @@ mod test {
         );
     }
 
+    #[test]
+    fn safe_math() {
+        assert_eq!(
+            run(&"examples/libraries/SafeMath/SafeMath.sol".to_string()),
+            Ok(())
+        );
+    }
+
     #[test]
     fn ierc20() {
         assert_eq!(
@@ -189,4 +534,43 @@ mod test {
             Ok(())
         );
     }
+
+    #[test]
+    fn colliding_crates() {
+        // the called interface `Vault` and the contract `Vault` are written to `Vault`
+        let project = tempfile::tempdir().unwrap();
+        std::fs::write(
+            project.path().join("a.sol"),
+            "interface Vault { function f() external; }\n\
+             contract A { function g(address v) public { Vault(v).f(); } }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project.path().join("b.sol"),
+            "contract Vault { function f() public {} }\ncontract B { function h() public {} }\n",
+        )
+        .unwrap();
+        assert_eq!(
+            run(&project.path().display().to_string()),
+            Err(ParserError::InvalidFiles(1))
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        assert_eq!(
+            run(&"Missing.sol".to_string()),
+            Err(ParserError::FileError(String::from(
+                "cannot read `Missing.sol`: No such file or directory (os error 2)"
+            )))
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        assert_eq!(
+            run(&"examples/contracts/SyntaxErrors/SyntaxErrors.sol".to_string()),
+            Err(ParserError::InvalidFiles(1))
+        );
+    }
 }
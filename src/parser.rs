@@ -21,31 +21,52 @@
 // SOFTWARE.
 
 use crate::{
-    formatter::*,
+    assembler::Backend,
+    ast,
+    diagnostics::Diagnostics,
+    lexer::Span,
+    report::{
+        Category,
+        ReportEntry,
+    },
     structures::*,
+    syntax,
+    toml_builder::dependency_name,
+};
+use convert_case::{
+    Case::{
+        Pascal,
+        Snake,
+    },
+    Casing,
 };
 use lazy_static::lazy_static;
+use primitive_types::U256;
 use regex::Regex;
 use std::{
     collections::{
         HashMap,
         HashSet,
-        VecDeque,
     },
-    slice::Iter,
-    str::Chars,
+    fmt,
+    mem,
+    str::FromStr,
+};
+use sha2::{
+    Digest,
+    Sha256,
+};
+use tiny_keccak::{
+    Hasher,
+    Keccak,
 };
-use substring::Substring;
-
-#[derive(Debug, PartialEq, Eq)]
-enum ArgsReader {
-    ArgType,
-    ArgName,
-}
 
+// modifiers access the contract through the storage trait of the contract
 macro_rules! selector {
-    ($constructor:ident) => {
-        if $constructor {
+    ($self:ident, $constructor:ident) => {
+        if $self.modifier {
+            String::from("instance.contract()")
+        } else if $constructor {
             String::from("instance")
         } else {
             String::from("self")
@@ -60,6 +81,8 @@ macro_rules! bx {
 }
 
 const DEFAULT_ERROR: &str = "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP";
+const COMPARISONS: [&str; 6] = ["==", "!=", "<", ">", "<=", ">="];
+const U256_IMPORT: &str = "use primitive_types::U256;";
 
 lazy_static! {
     static ref TYPES: HashMap<&'static str, (&'static str, Option<&'static str>, Option<&'static str>)> = {
@@ -222,120 +245,201 @@ lazy_static! {
         map.insert(String::from("^"), Operation::Xor);
         map
     };
-    static ref SPECIFIC_EXPRESSION: HashMap<String, Expression> = {
+    static ref BUILTINS: HashMap<&'static str, (Option<&'static str>, Option<&'static str>)> = {
+        // solidity builtin -> (ink! code, note about the differing semantics)
+        // `$env` is the environment of the contract, `$0`, `$1`, ... are the arguments
+        // and `$args` are all arguments, the builtins without code have no ink! equivalent,
+        // they are transpiled to `todo!`
         let mut map = HashMap::new();
-        map.insert(String::from("address(0)"), Expression::ZeroAddressInto);
-        map.insert(String::from("address(0x0)"), Expression::ZeroAddressInto);
-        map.insert(String::from("msg.sender"), Expression::EnvCaller(None));
-        map.insert(String::from("msg.value"), Expression::TransferredValue(None));
+        let keccak256 = "{ let mut output = [0u8; 32]; \
+            ink_env::hash_bytes::<ink_env::hash::Keccak256>(&$0, &mut output); output }";
+        let sha256 = "{ let mut output = [0u8; 32]; \
+            ink_env::hash_bytes::<ink_env::hash::Sha2x256>(&$0, &mut output); output }";
+        let scale = "is encoded with SCALE instead of the ABI encoding";
+        // the signature of ink! is `r`, `s` and the recovery id, the recovered key is compressed
+        // and the account of the key is its hash, the failed recovery returns the zero account
+        let ecrecover = "{ let mut signature = [0u8; 65]; \
+            signature[..32].copy_from_slice(&$2); signature[32..64].copy_from_slice(&$3); \
+            signature[64] = $1; let mut key = [0u8; 33]; \
+            match ink_env::ecdsa_recover(&signature, &$0, &mut key) { \
+            Ok(()) => { let mut account = [0u8; 32]; \
+            ink_env::hash_bytes::<ink_env::hash::Blake2x256>(&key, &mut account); \
+            AccountId::from(account) } Err(_) => AccountId::from([0u8; 32]) } }";
+        let transfer = "$env.transfer($0, $1)\
+            .map_err(|_| Error::Custom(String::from(\"Transfer failed\")))?";
+
+        map.insert(
+            "abi.decode",
+            (Some("$0"), Some("is decoded with SCALE instead of the ABI encoding")),
+        );
+        map.insert("abi.encode", (Some("($args).encode()"), Some(scale)));
+        map.insert("abi.encodeCall", (None, None));
+        map.insert("abi.encodePacked", (Some("($args).encode()"), Some(scale)));
+        map.insert("abi.encodeWithSelector", (None, None));
+        map.insert("abi.encodeWithSignature", (None, None));
+        map.insert("address(this).balance", (Some("$env.balance()"), None));
+        map.insert("address.balance", (None, None));
+        map.insert("address.call", (Some("$env.transfer($0, $1).is_ok()"), None));
+        map.insert("address.send", (Some("$env.transfer($0, $1).is_ok()"), None));
+        map.insert("address.transfer", (Some(transfer), None));
+        map.insert("block.basefee", (None, None));
+        map.insert("block.chainid", (None, None));
+        map.insert("block.coinbase", (None, None));
+        map.insert("block.difficulty", (None, None));
+        map.insert("block.gaslimit", (None, None));
+        map.insert("block.number", (Some("($env.block_number() as u128)"), None));
+        map.insert("block.prevrandao", (None, None));
+        map.insert("block.timestamp", (Some("($env.block_timestamp() as u128 / 1000)"), None));
+        map.insert("blockhash", (None, None));
+        map.insert(
+            "ecrecover",
+            (
+                Some(ecrecover),
+                Some("returns the account of the recovered public key instead of its address"),
+            ),
+        );
+        map.insert("gasleft", (Some("($env.gas_left() as u128)"), None));
+        map.insert("keccak256", (Some(keccak256), None));
+        map.insert("msg.data", (None, None));
+        map.insert("msg.sig", (None, None));
+        map.insert("now", (Some("($env.block_timestamp() as u128 / 1000)"), None));
+        map.insert("ripemd160", (None, None));
+        map.insert("selfdestruct", (Some("$env.terminate_contract($0)"), None));
+        map.insert("sha256", (Some(sha256), None));
+        map.insert("string.concat", (Some("ink_prelude::format!"), None));
+        map.insert("this", (Some("$env.account_id()"), None));
+        map.insert("tx.gasprice", (None, None));
+        map.insert("tx.origin", (None, None));
         map
     };
-    static ref REGEX_RETURN: Regex =
-        Regex::new(r#"(?x)^\s*return\s+(?P<output>.+?);*\s*$"#).unwrap();
-    static ref REGEX_DECLARE: Regex = Regex::new(
-        r#"(?x)^\s*
-        (?P<field_type>[a-zA-Z0-9\[\]]+)\s+
-        (?P<field_name>[_a-zA-Z0-9]+)\s*
-        (=\s*(?P<value>.+))*;\s*$"#
-    )
-    .unwrap();
-    static ref REGEX_REQUIRE: Regex = Regex::new(
-        r#"(?x)
-        ^\s*require\s*\((?P<condition>.+?)\s*
-        (,\s*["|'](?P<error>.*)["|']\s*)*\);\s*$"#
-    )
-    .unwrap();
-    static ref REGEX_COMMENT: Regex = Regex::new(r#"(?x)^\s*///*\s*(?P<comment>.*)\s*$"#).unwrap();
-    static ref REGEX_CONDITION_ONE_LINE: Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<keyword>(else|if)(\s*if)*)\s*
-        (\(\s*(?P<condition>.+)\s*\))*
-        (?P<then>.+)\s*;\s*
-        $"#
-    ).unwrap();
-    static ref REGEX_DO: Regex = Regex::new(r#"(?x)^\s*do\s*\{\s*"#).unwrap();
-    static ref REGEX_IF: Regex =
-        Regex::new(r#"(?x)^\s*if\s*\((?P<condition>.+)\s*\)\s*\{\s*"#).unwrap();
-    static ref REGEX_ELSE: Regex = Regex::new(r#"^\s*else\s*\{\s*"#).unwrap();
-    static ref REGEX_ELSE_IF: Regex =
-        Regex::new(r#"(?x)^\s*else\s+if\s*\((?P<condition>.+)\s*\)\s*\{\s*"#).unwrap();
-    static ref REGEX_UNCHECKED: Regex = Regex::new(r#"(?x)^\s*unchecked\s*\{\s*"#).unwrap();
-    static ref REGEX_END_BLOCK: Regex = Regex::new(r#"^\s*\}\s*"#).unwrap();
-    static ref REGEX_TRY: Regex = Regex::new(r#"(?x)^\s*try\s*.*$"#).unwrap();
-    static ref REGEX_ASSEMBLY: Regex = Regex::new(r#"(?x)^\s*assembly\s*\{\s*"#).unwrap();
-    static ref REGEX_CATCH: Regex = Regex::new(r#"(?x)^\s*catch\s*.*$"#).unwrap();
-    static ref REGEX_EMIT: Regex = Regex::new(
-        r#"(?x)
-        ^\s*emit\s+(?P<event_name>.+?)\s*\(\s*
-        (?P<args>.+)+?\);\s*$"#
-    )
-    .unwrap();
-    static ref REGEX_ASSIGN: Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<left>[0-9a-zA-Z_\[\].]+?)\s*
-        (?P<operation>[+\-*/&|]*=)\s*
-        (?P<right>[^=][^;]*)+?;*\s*$"#
-    )
-    .unwrap();
-    static ref REGEX_FUNCTION_CALL: Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<function_name>[a-zA-Z0-9_]+?)\s*\(
-        \s*(?P<args>.*)\s*
-        \);*\s*$"#,
-    )
-    .unwrap();
-    static ref REGEX_BOOLEAN: Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<left>.+?)
-        \s*(?P<operation>[!=><^]+)\s*
-        (?P<right>.+)
-        \s*$"#,
-    )
-    .unwrap();
-    static ref REGEX_BINARY_SUFFIX: Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<value>.+?)
-        \s*(?P<operation>[+-]{2});*
-        \s*$"#,
-    )
-    .unwrap();
-    static ref REGEX_BINARY_PREFIX: Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<operation>[+-]{2})
-        \s*(?P<value>.+?);*
-        \s*$"#,
-    )
-    .unwrap();
-    static ref REGEX_FOR: Regex = Regex::new(
-        r#"(?x)
-        ^\s*for\s*\(\s*
-        (?P<assignment>.+?;)\s*
-        (?P<condition>.+?)\s*;
-        (?P<modification>.+)\s*
-        \)\s*\{$"#,
-    )
-    .unwrap();
-    static ref REGEX_WHILE: Regex = Regex::new(
-        r#"(?x)
-        ^\s*while\s*\(\s*
-        (?P<condition>.+?)\s*
-        \);*\s*\{*$"#,
-    )
-    .unwrap();
-    static ref REGEX_TERNARY:Regex = Regex::new(
-        r#"(?x)
-        ^\s*(?P<condition>.+?)\s*\?
-        \s*(?P<if_true>.+?)\s*:
-        \s*(?P<if_false>.+?)\s*$"#,
-    )
-    .unwrap();
 }
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ParserError {
     FileError(String),
     FileCorrupted,
-    LibraryParsingNotImplemented,
+    IncorrectInheritance(String),
+    // the number of errors in the file, the errors were already reported
+    SourceErrors(usize),
+    // the number of files which could not be transpiled
+    InvalidFiles(usize),
+    // the number of crates which could not be written
+    UnwrittenCrates(usize),
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParserError::FileError(error) => write!(f, "{error}"),
+            ParserError::FileCorrupted => write!(f, "no contract, interface or library found"),
+            ParserError::IncorrectInheritance(error) => write!(f, "{error}"),
+            ParserError::SourceErrors(1) => write!(f, "1 error"),
+            ParserError::SourceErrors(count) => write!(f, "{count} errors"),
+            ParserError::InvalidFiles(1) => write!(f, "1 file could not be transpiled"),
+            ParserError::InvalidFiles(count) => write!(f, "{count} files could not be transpiled"),
+            ParserError::UnwrittenCrates(1) => write!(f, "1 crate could not be written"),
+            ParserError::UnwrittenCrates(count) => write!(f, "{count} crates could not be written"),
+        }
+    }
+}
+
+/// The naming of the overloaded functions, Rust does not support overloading
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mangling {
+    /// the names are suffixed with the types of the parameters, eg. `mint_address_uint256`
+    #[default]
+    Types,
+    /// the names are suffixed with the number of the parameters, eg. `mint_2`, the overloads
+    /// with the same number of parameters are suffixed with the types of the parameters
+    Arity,
+}
+
+impl fmt::Display for Mangling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mangling::Types => write!(f, "types"),
+            Mangling::Arity => write!(f, "arity"),
+        }
+    }
+}
+
+impl FromStr for Mangling {
+    type Err = String;
+
+    fn from_str(mangling: &str) -> Result<Self, Self::Err> {
+        match mangling {
+            "types" => Ok(Mangling::Types),
+            "arity" => Ok(Mangling::Arity),
+            _ => Err(format!("unknown mangling `{mangling}`, expected `types` or `arity`")),
+        }
+    }
+}
+
+/// The messages which get an explicit selector instead of the selector derived by ink! from
+/// the name of the message
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Selectors {
+    /// no message gets an explicit selector
+    #[default]
+    Ink,
+    /// the overloaded messages get the selectors of their Solidity signatures, so the selectors
+    /// do not depend on the mangled names
+    Overloads,
+    /// all messages get the selectors of their Solidity signatures, so the encoders built
+    /// against the Solidity ABI keep working
+    Solidity,
+}
+
+impl fmt::Display for Selectors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Selectors::Ink => write!(f, "ink"),
+            Selectors::Overloads => write!(f, "overloads"),
+            Selectors::Solidity => write!(f, "solidity"),
+        }
+    }
+}
+
+impl FromStr for Selectors {
+    type Err = String;
+
+    fn from_str(selectors: &str) -> Result<Self, Self::Err> {
+        match selectors {
+            "ink" => Ok(Selectors::Ink),
+            "overloads" => Ok(Selectors::Overloads),
+            "solidity" => Ok(Selectors::Solidity),
+            _ => {
+                Err(format!(
+                    "unknown selectors `{selectors}`, expected `ink`, `overloads` or `solidity`"
+                ))
+            }
+        }
+    }
+}
+
+/// Raw definitions of the parsed contracts and interfaces
+///
+/// The definitions are used to resolve inheritance, also across multiple files
+#[derive(Default, Clone)]
+pub struct Definitions {
+    pub contracts: HashMap<String, Contract>,
+    pub interfaces: HashMap<String, Interface>,
+    pub libraries: Vec<Library>,
+    // types defined outside of contracts, shared by all contracts
+    pub structs: Vec<Struct>,
+    pub enums: Vec<Enum>,
+    pub events: Vec<Event>,
+    pub errors: Vec<CustomError>,
+    // the types of the user defined types in the ABI, structs are tuples of their members
+    pub abi_types: HashMap<String, String>,
+}
+
+/// The definitions found in a parsed file
+pub enum ParserOutput {
+    Contracts(Vec<Contract>),
+    Interface(Box<Interface>),
+    Libraries(Vec<Library>),
+    None,
 }
 
 impl From<std::io::Error> for ParserError {
@@ -344,310 +448,712 @@ impl From<std::io::Error> for ParserError {
     }
 }
 
-#[derive(Eq, PartialEq)]
-enum Action {
-    None,
-    AssemblyStart,
-    Assembly,
-    ContractName,
-    ContractNamed,
-    Contract,
-    Slash,
-}
-
-const AMPERSAND: char = '&';
-const ASTERISK: char = '*';
-const BRACKET_CLOSE: char = ']';
-const BRACKET_OPEN: char = '[';
-const COMMA: char = ',';
-const EXCLAMAITON: char = '!';
-const EQUALS: char = '=';
-const CURLY_CLOSE: char = '}';
-const CURLY_OPEN: char = '{';
-const MINUS: char = '-';
-const NEW_LINE: char = '\n';
-const PARENTHESIS_CLOSE: char = ')';
-const PARENTHESIS_OPEN: char = '(';
-const PERCENT: char = '%';
-const PIPE: char = '|';
-const PLUS: char = '+';
-const SEMICOLON: char = ';';
-const SLASH: char = '/';
-const SPACE: char = ' ';
-
 pub struct Parser<'a> {
-    chars: &'a mut Chars<'a>,
-    imports: &'a mut HashSet<String>,
-    storage: &'a mut HashMap<String, ContractField>,
-    functions: &'a mut HashMap<String, bool>,
-    events: &'a mut HashMap<String, Event>,
-    modifiers: &'a mut HashMap<String, ()>,
-    structs: &'a mut HashMap<String, Struct>,
+    source: &'a str,
+    imports: HashSet<String>,
+    storage: HashMap<String, ContractField>,
+    functions: HashMap<String, bool>,
+    events: HashMap<String, Event>,
+    errors: HashMap<String, CustomError>,
+    modifiers: HashMap<String, ()>,
+    // if the statements of a modifier are parsed
+    modifier: bool,
+    structs: HashMap<String, Struct>,
+    enums: HashMap<String, Enum>,
+    // library name -> names of the functions of the library
+    libraries: HashMap<String, HashSet<String>>,
+    // library function prefixed with the name of the library -> how the arguments are passed,
+    // `&mut ` or `&` for the storage parameters, eg. `Counters.increment` -> [`&mut `]
+    library_references: HashMap<String, Vec<&'static str>>,
+    // the storage parameters of the currently parsed library function, passed on as they are
+    references: HashSet<String>,
+    // function name -> name of the library attached by `using A for B`
+    using: HashMap<String, String>,
+    definitions: Definitions,
+    // the linearized inheritance of the parsed contract, starting with the most derived contract
+    linearization: Vec<String>,
+    // function name -> contracts of the linearization which implement the function
+    implementations: HashMap<String, Vec<String>>,
+    // the contract in which the currently parsed function is defined
+    origin: String,
+    // statements executed before and after the currently parsed statement, eg. `i += 1` of `i++`
+    preceding: Vec<Statement>,
+    following: Vec<Statement>,
+    // the contracts, interfaces and libraries defined in the parsed file, with their position
+    local: HashMap<String, Span>,
+    // the names of the structs, enums, contracts, interfaces and libraries known to the parser
+    known_types: HashSet<String>,
+    diagnostics: Diagnostics,
+    // the code which was not faithfully translated, since the last definition was parsed
+    report: Vec<ReportEntry>,
+    // if the unsigned integers wider than 128 bits are converted to `U256`
+    u256: bool,
+    // the truncated integer types reported by the definitions, eg. (`Token`, `uint256`)
+    truncated: HashSet<(String, String)>,
+    // the parameters and local variables of the currently parsed function with their types
+    locals: HashMap<String, String>,
+    // the storage pointers of the currently parsed function with the values they point to,
+    // eg. `p` of `Proposal storage p = proposals[id]`
+    storage_pointers: HashMap<String, ast::Expression>,
+    // if the currently parsed function returns a single `U256`
+    returns_wide: bool,
+    // if the arithmetic of the parsed file reverts on overflow, since Solidity 0.8
+    checked: bool,
+    // if the currently parsed statements are inside of an `unchecked` block
+    unchecked: bool,
+    // the ink! version of the generated code, cross-contract calls differ between the versions
+    backend: Backend,
+    // state variable name -> name of the interface which is the type of the variable
+    interface_fields: HashMap<String, String>,
+    // the dynamic array state variables stored lazily as a mapping of the indices and a length
+    storage_arrays: HashSet<String>,
+    // constant name -> value of the constant folded by the transpiler
+    constants: HashMap<String, Constant>,
+    // the interfaces called by the parsed contract, by their Solidity name
+    interfaces: HashMap<String, Interface>,
+    // the naming of the overloaded functions
+    mangling: Mangling,
+    // the messages which get an explicit selector
+    selectors: Selectors,
+    // the overloads of the overloaded functions by their Solidity name, the overloads of the
+    // library functions are prefixed with the name of the library, eg. `Math.max`
+    overloads: HashMap<String, Vec<FunctionHeader>>,
 }
 
 impl<'a> Parser<'a> {
-    /// creates a new parser from the given parameters
-    pub fn new(
-        chars: &'a mut Chars<'a>,
-        imports: &'a mut HashSet<String>,
-        storage: &'a mut HashMap<String, ContractField>,
-        functions: &'a mut HashMap<String, bool>,
-        events: &'a mut HashMap<String, Event>,
-        modifiers: &'a mut HashMap<String, ()>,
-        structs: &'a mut HashMap<String, Struct>,
-    ) -> Self {
+    /// creates a new parser of the source code
+    pub fn new(source: &'a str) -> Self {
         Parser {
-            chars,
-            imports,
-            storage,
-            functions,
-            events,
-            modifiers,
-            structs,
+            source,
+            imports: HashSet::new(),
+            storage: HashMap::new(),
+            functions: HashMap::new(),
+            events: HashMap::new(),
+            errors: HashMap::new(),
+            modifiers: HashMap::new(),
+            modifier: false,
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            libraries: HashMap::new(),
+            library_references: HashMap::new(),
+            references: HashSet::new(),
+            using: HashMap::new(),
+            definitions: Definitions::default(),
+            linearization: Vec::new(),
+            implementations: HashMap::new(),
+            origin: String::new(),
+            preceding: Vec::new(),
+            following: Vec::new(),
+            local: HashMap::new(),
+            known_types: HashSet::new(),
+            diagnostics: Diagnostics::default(),
+            report: Vec::new(),
+            u256: false,
+            truncated: HashSet::new(),
+            locals: HashMap::new(),
+            storage_pointers: HashMap::new(),
+            returns_wide: false,
+            checked: true,
+            unchecked: false,
+            backend: Backend::default(),
+            interface_fields: HashMap::new(),
+            storage_arrays: HashSet::new(),
+            constants: HashMap::new(),
+            interfaces: HashMap::new(),
+            mangling: Mangling::default(),
+            selectors: Selectors::default(),
+            overloads: HashMap::new(),
         }
     }
 
-    /// parses the file represented by the given chars iterator
-    ///
-    /// returns Some(contract) if a contract was successfully parsed
-    /// returns Some(interface) if an interface was successfully parsed
-    /// returns None if the file is not a valid contract or interface
-    pub fn parse_file(&mut self) -> Result<(Option<Contract>, Option<Interface>), ParserError> {
-        let mut comments = Vec::<String>::new();
-        let mut action = Action::None;
-        let mut buffer = String::new();
+    /// converts the unsigned integers wider than 128 bits to `U256` instead of `u128`
+    pub fn with_u256(&mut self, u256: bool) {
+        self.u256 = u256;
+    }
+
+    /// sets the ink! version of the generated code
+    pub fn with_backend(&mut self, backend: Backend) {
+        self.backend = backend;
+    }
+
+    /// sets the naming of the overloaded functions
+    pub fn with_mangling(&mut self, mangling: Mangling) {
+        self.mangling = mangling;
+    }
+
+    /// sets the messages which get an explicit selector
+    pub fn with_selectors(&mut self, selectors: Selectors) {
+        self.selectors = selectors;
+    }
+
+    /// adds definitions parsed from other files, so contracts of this file can inherit them
+    pub fn with_definitions(&mut self, definitions: Definitions) {
+        for library in definitions.libraries.iter() {
+            self.libraries
+                .insert(library.name.clone(), library_functions(library));
+            self.library_references.extend(library_references(
+                &library.name,
+                library.functions.iter().map(|function| &function.header),
+            ));
+        }
+        self.definitions = definitions;
+    }
+
+    /// returns the definitions parsed so far, including the ones added with `with_definitions`
+    pub fn definitions(&self) -> &Definitions {
+        &self.definitions
+    }
+
+    /// returns the errors and warnings reported while parsing the file
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.diagnostics
+    }
 
-        while let Some(ch) = self.chars.next() {
-            match ch {
-                SLASH if action == Action::None => action = Action::Slash,
-                SLASH if action == Action::Slash => {
-                    let comment = self.parse_comment();
-                    if !comment.is_empty() {
-                        comments.push(comment);
+    /// parses the source code of the file into its syntax tree and converts the tree
+    ///
+    /// libraries defined before a contract are parsed into the contract
+    /// structs, enums and events defined outside of a contract are shared with all contracts
+    ///
+    /// returns `ParserOutput::Contracts` with the contracts which are not abstract,
+    /// or with the last contract if all contracts of the file are abstract
+    /// returns `ParserOutput::Interface` if an interface was successfully parsed
+    /// returns `ParserOutput::Libraries` if the file contains only libraries
+    /// returns `ParserOutput::None` if the file is not a valid contract, interface or library
+    ///
+    /// syntax errors, contracts with impossible inheritance and the code which can not be
+    /// transpiled are reported to the diagnostics of the parser
+    pub fn parse_file(&mut self) -> Result<ParserOutput, ParserError> {
+        let source_unit = syntax::parse(self.source, &mut self.diagnostics);
+        self.checked = source_unit
+            .solidity
+            .as_deref()
+            .is_none_or(is_checked_version);
+        let mut libraries = Vec::<Library>::new();
+        let mut contracts = Vec::<Contract>::new();
+        let mut interface_maybe = None;
+        // the code outside of contracts is reported with every output of the file
+        let mut file_report = Vec::<ReportEntry>::new();
+        self.collect_known_types(&source_unit);
+
+        for part in source_unit.parts.iter() {
+            self.origin.clear();
+            match part {
+                ast::SourcePart::Contract(definition) => {
+                    match definition.kind {
+                        ast::ContractKind::Contract => {
+                            let known_libraries = self.definitions.libraries.clone();
+                            let contract = self.parse_contract(definition, &known_libraries);
+                            self.definitions
+                                .contracts
+                                .insert(contract.name.clone(), contract.clone());
+                            contracts.push(contract);
+                        }
+                        ast::ContractKind::Interface => {
+                            interface_maybe = Some(self.parse_interface(definition))
+                        }
+                        ast::ContractKind::Library => {
+                            let library = self.parse_library(definition);
+                            merge_by_name(
+                                &mut self.definitions.libraries,
+                                std::slice::from_ref(&library),
+                                |library| &library.name,
+                            );
+                            libraries.push(library);
+                        }
                     }
-                    action = Action::None;
                 }
-                ASTERISK if action == Action::Slash => {
-                    let mut new_comments = self.parse_multiline_comment();
-                    comments.append(&mut new_comments);
-                    action = Action::None;
+                ast::SourcePart::Struct(definition) => {
+                    let structure = self.parse_struct(definition);
+                    merge_by_name(&mut self.definitions.structs, &[structure], |structure| {
+                        &structure.name
+                    });
                 }
-                NEW_LINE | SPACE => {}
-                _ => {
-                    buffer.push(ch);
-                    if buffer == "pragma" || buffer == "import" {
-                        read_until(self.chars, vec![SEMICOLON]);
-                        buffer.clear();
-                    } else if buffer == "abstract" {
-                        buffer.clear();
-                    } else if buffer == "contract" {
-                        let contract = self.parse_contract(comments)?;
-                        return Ok((Some(contract), None))
-                    } else if buffer == "interface" {
-                        let interface = self.parse_interface(comments)?;
-                        return Ok((None, Some(interface)))
-                    } else if buffer == "library" {
-                        return Err(ParserError::LibraryParsingNotImplemented)
-                    }
+                ast::SourcePart::Enum(definition) => {
+                    let enumeration = parse_enum(definition);
+                    merge_by_name(&mut self.definitions.enums, &[enumeration], |enumeration| {
+                        &enumeration.name
+                    });
+                }
+                ast::SourcePart::Event(definition) => {
+                    let event = self.parse_event(definition);
+                    merge_by_name(&mut self.definitions.events, &[event], |event| &event.name);
+                }
+                ast::SourcePart::Function(definition) => {
+                    self.warn(
+                        Category::FreeFunction,
+                        format!(
+                            "free function `{}` is not supported, skipping",
+                            definition.name
+                        ),
+                        definition.span,
+                    );
+                }
+                ast::SourcePart::Error(definition) => {
+                    let error = self.parse_error(definition);
+                    merge_by_name(&mut self.definitions.errors, &[error], |error| &error.name);
+                }
+                // the imported files are parsed before the file, see `project::order_sources`
+                ast::SourcePart::Import(_) => {}
+                ast::SourcePart::Type(definition) => {
+                    self.warn(
+                        Category::UnsupportedDefinition,
+                        format!(
+                            "user defined value type `{}` is not supported, skipping",
+                            definition.name
+                        ),
+                        definition.span,
+                    );
+                }
+                ast::SourcePart::Using(directive) => {
+                    self.warn(
+                        Category::UnsupportedDefinition,
+                        String::from("`using` outside of a contract is not supported, skipping"),
+                        directive.span,
+                    );
+                }
+                ast::SourcePart::Variable(definition) => {
+                    self.warn(
+                        Category::UnsupportedDefinition,
+                        format!(
+                            "constant `{}` outside of a contract is not supported, skipping",
+                            definition.name
+                        ),
+                        definition.span,
+                    );
                 }
             }
+            file_report.append(&mut self.report);
         }
 
-        Ok((None, None))
-    }
+        // abstract contracts are only transpiled if the file contains no other contract
+        let last_contract = contracts.last().map(|contract| contract.name.clone());
+        contracts.retain(|contract| {
+            !contract.is_abstract || Some(&contract.name) == last_contract.as_ref()
+        });
+        if contracts.len() > 1 {
+            contracts.retain(|contract| !contract.is_abstract);
+        }
 
-    /// parses a line containing a comment and returns it as a string
-    fn parse_comment(&mut self) -> String {
-        let mut buffer = String::new();
-        let mut reading = false;
+        if !contracts.is_empty() {
+            let mut output = Vec::new();
+            for contract in contracts {
+                let span = self.local.get(&contract.name).copied();
+                match self.resolve_contract(contract) {
+                    Ok(mut contract) => {
+                        contract.report.extend(file_report.iter().cloned());
+                        output.push(contract)
+                    }
+                    Err(error) => self.diagnostics.error(error.to_string(), span),
+                }
+            }
+            Ok(ParserOutput::Contracts(output))
+        } else if let Some(mut interface) = interface_maybe {
+            interface.report.append(&mut file_report);
+            Ok(ParserOutput::Interface(Box::new(interface)))
+        } else if libraries.is_empty() {
+            Ok(ParserOutput::None)
+        } else {
+            if let Some(library) = libraries.last_mut() {
+                library.report.append(&mut file_report);
+            }
+            Ok(ParserOutput::Libraries(libraries))
+        }
+    }
 
-        for ch in self.chars.by_ref() {
-            match ch {
-                SLASH if !reading => {
-                    reading = true;
+    /// Collects the names of the types defined in the file and in the previously parsed files
+    ///
+    /// The contracts, interfaces and libraries of the file are marked as local.
+    /// The types of the file in the ABI are collected too, for the signatures of the functions
+    fn collect_known_types(&mut self, source_unit: &ast::SourceUnit) {
+        let abi_types = &mut self.definitions.abi_types;
+        let mut structs = Vec::<&ast::StructDefinition>::new();
+        for part in source_unit.parts.iter() {
+            match part {
+                ast::SourcePart::Contract(definition) => {
+                    self.local.insert(definition.name.clone(), definition.span);
+                    self.known_types.insert(definition.name.clone());
+                    abi_types.insert(definition.name.clone(), String::from("address"));
+                    for part in definition.parts.iter() {
+                        match part {
+                            ast::ContractPart::Enum(definition) => {
+                                self.known_types.insert(definition.name.clone());
+                                abi_types.insert(definition.name.clone(), String::from("uint8"));
+                            }
+                            ast::ContractPart::Struct(definition) => {
+                                self.known_types.insert(definition.name.clone());
+                                structs.push(definition);
+                            }
+                            _ => {}
+                        }
+                    }
                 }
-                NEW_LINE => return buffer.trim().to_owned(),
-                _ if !reading => {
-                    buffer.push(ch);
-                    reading = true;
+                ast::SourcePart::Enum(definition) => {
+                    self.known_types.insert(definition.name.clone());
+                    abi_types.insert(definition.name.clone(), String::from("uint8"));
                 }
-                _ => {
-                    buffer.push(ch);
+                ast::SourcePart::Struct(definition) => {
+                    self.known_types.insert(definition.name.clone());
+                    structs.push(definition);
                 }
+                _ => {}
+            }
+        }
+        // a struct can be a member of a struct defined before it, one pass resolves one level
+        for _ in 0..structs.len() {
+            for structure in structs.iter() {
+                let members = structure
+                    .fields
+                    .iter()
+                    .map(|field| abi_type(&field.type_name, abi_types))
+                    .collect::<Vec<_>>();
+                abi_types.insert(structure.name.clone(), format!("({})", members.join(",")));
             }
         }
 
-        buffer
+        let definitions = &self.definitions;
+        let names = definitions
+            .contracts
+            .iter()
+            .flat_map(|(name, contract)| {
+                std::iter::once(name)
+                    .chain(contract.structs.iter().map(|structure| &structure.name))
+                    .chain(contract.enums.iter().map(|enumeration| &enumeration.name))
+            })
+            .chain(definitions.interfaces.iter().flat_map(|(name, interface)| {
+                std::iter::once(name)
+                    .chain(interface.structs.iter().map(|structure| &structure.name))
+                    .chain(interface.enums.iter().map(|enumeration| &enumeration.name))
+            }))
+            .chain(definitions.libraries.iter().flat_map(|library| {
+                std::iter::once(&library.name)
+                    .chain(library.structs.iter().map(|structure| &structure.name))
+                    .chain(library.enums.iter().map(|enumeration| &enumeration.name))
+            }))
+            .chain(definitions.structs.iter().map(|structure| &structure.name))
+            .chain(definitions.enums.iter().map(|enumeration| &enumeration.name))
+            .cloned()
+            .collect::<Vec<_>>();
+        self.known_types.extend(names);
     }
 
-    /// parses mulitline comment starting with /* and ending with */
-    /// returns the comments as a vector of strings
-    fn parse_multiline_comment(&mut self) -> Vec<String> {
-        let mut comments = Vec::<String>::new();
-        let mut buffer = String::new();
-        let mut reading = false;
-        let mut new_line = false;
-        let mut asterisk = false;
+    /// Converts the syntax tree of a Solidity contract
+    ///
+    /// The statements of the contract stay raw until the inheritance of the contract is resolved
+    ///
+    /// `definition` the syntax tree of the contract
+    /// `libraries` the libraries defined in the file before the contract
+    ///
+    /// returns the representation of the contract as `Contract` struct
+    fn parse_contract(
+        &mut self,
+        definition: &ast::ContractDefinition,
+        libraries: &[Library],
+    ) -> Contract {
+        self.origin = definition.name.clone();
+        self.constants.clear();
+        let mut using = Vec::<String>::new();
+        let mut fields = Vec::<ContractField>::new();
+        let mut events = Vec::<Event>::new();
+        let mut errors = Vec::<CustomError>::new();
+        let mut enums = Vec::<Enum>::new();
+        let mut structs = Vec::<Struct>::new();
+        let mut functions = Vec::<Function>::new();
+        let mut getters = Vec::<Function>::new();
+        let mut constructor = Function::default();
+        let mut modifiers = Vec::<Modifier>::new();
 
-        for ch in self.chars.by_ref() {
-            if ch == SLASH && asterisk {
-                if !buffer.trim().is_empty() {
-                    comments.push(format!(" {}", buffer.trim()));
-                }
-                break
-            } else {
-                asterisk = false;
-            }
-            match ch {
-                ASTERISK if !reading => {
-                    reading = true;
-                }
-                ASTERISK if new_line => {
-                    new_line = false;
+        for part in definition.parts.iter() {
+            match part {
+                ast::ContractPart::Event(definition) => {
+                    let event = self.parse_event(definition);
+                    self.events.insert(event.name.clone(), event.clone());
+                    events.push(event);
                 }
-                NEW_LINE => {
-                    if !buffer.trim().is_empty() {
-                        comments.push(format!(" {}", buffer.trim()));
-                        buffer.clear();
+                ast::ContractPart::Enum(definition) => enums.push(parse_enum(definition)),
+                ast::ContractPart::Struct(definition) => structs.push(self.parse_struct(definition)),
+                ast::ContractPart::Function(definition) => {
+                    match definition.kind {
+                        ast::FunctionKind::Constructor => {
+                            constructor = self.parse_function(definition)
+                        }
+                        ast::FunctionKind::Modifier => {
+                            modifiers.push(self.parse_modifier(definition))
+                        }
+                        _ => functions.push(self.parse_function(definition)),
                     }
-                    new_line = true;
                 }
-                _ if !reading => {
-                    buffer.push(ch);
-                    reading = true;
-                }
-                SPACE if new_line => {}
-                _ if new_line => {
-                    buffer.push(ch);
-                    new_line = false;
+                ast::ContractPart::Using(directive) => using.extend(directive.library.clone()),
+                ast::ContractPart::Variable(definition)
+                    if matches!(definition.type_name, ast::TypeName::Function(..)) =>
+                {
+                    self.skip_function_field(definition)
                 }
-                _ => {
-                    buffer.push(ch);
+                ast::ContractPart::Variable(definition) => {
+                    fields.push(self.parse_contract_field(definition));
+                    if self.storage_arrays.contains(&definition.name) {
+                        fields.push(ContractField {
+                            field_type: String::from("u32"),
+                            name: format!("{}_length", definition.name),
+                            comments: vec![format!(" The length of the `{}`", definition.name)],
+                            initial_value: None,
+                            constant: false,
+                            immutable: false,
+                            visibility: Visibility::Private,
+                        });
+                    }
+                    if definition.visibility == Some(ast::Visibility::Public) {
+                        getters.push(self.parse_getter(definition));
+                    }
                 }
+                ast::ContractPart::Error(definition) => errors.push(self.parse_error(definition)),
             }
-            if ch == ASTERISK {
-                asterisk = true;
+        }
+        // the getters come before the functions, unless a function has the same signature
+        for getter in getters.into_iter().rev() {
+            if !functions
+                .iter()
+                .any(|function| function.header.signature == getter.header.signature)
+            {
+                functions.insert(0, getter);
             }
         }
 
-        comments
+        Contract {
+            name: definition.name.clone(),
+            base: definition
+                .base
+                .iter()
+                .map(|base| {
+                    BaseContract {
+                        name: base.name.clone(),
+                        args: base.args.clone(),
+                        span: base.span,
+                    }
+                })
+                .collect(),
+            is_abstract: definition.is_abstract,
+            fields,
+            constructor,
+            events,
+            errors,
+            enums,
+            structs,
+            functions,
+            imports: HashSet::default(),
+            contract_doc: definition.comments.clone(),
+            modifiers,
+            libraries: libraries.to_vec(),
+            using,
+            interfaces: Vec::new(),
+            report: mem::take(&mut self.report),
+        }
     }
 
-    /// Parses the code of a Solidity contract
+    /// Merges the members of the base contracts into the contract and parses its statements
     ///
-    /// `contract_doc` the documentation comments of the contract
+    /// Members of more derived contracts override the members of their bases.
+    /// Overridden functions which are called with `super` are kept, prefixed with the name
+    /// of the contract defining them, and the constructors of the base contracts
+    /// are inlined into the constructor of the contract.
     ///
-    /// returns the representation of the contract as `Contract` struct
-    fn parse_contract(&mut self, contract_doc: Vec<String>) -> Result<Contract, ParserError> {
-        let mut buffer = String::new();
-        let mut action = Action::None;
+    /// `contract` the raw contract as parsed by `parse_contract`
+    ///
+    /// returns the contract with parsed statements
+    fn resolve_contract(&mut self, contract: Contract) -> Result<Contract, ParserError> {
+        // members of the previously resolved contract of the file
+        self.storage.clear();
+        self.functions.clear();
+        self.modifiers.clear();
+        self.interfaces.clear();
+        self.overloads.clear();
+        self.linearization = self.linearize(&contract.name, &mut Vec::new())?;
 
-        let mut name = String::new();
-        let mut comments = Vec::<String>::new();
         let mut fields = Vec::<ContractField>::new();
         let mut events = Vec::<Event>::new();
+        let mut errors = Vec::<CustomError>::new();
         let mut enums = Vec::<Enum>::new();
         let mut structs = Vec::<Struct>::new();
-        let mut functions = Vec::<Function>::new();
-        let mut constructor = Function::default();
         let mut modifiers = Vec::<Modifier>::new();
+        let mut libraries = Vec::<Library>::new();
+        let mut using = Vec::<String>::new();
+        let mut inherited_functions = Vec::<(String, Function)>::new();
+
+        // from the most base contract to the most derived one
+        for name in self.linearization.iter().rev() {
+            if let Some(interface) = self.definitions.interfaces.get(name) {
+                merge_by_name(&mut events, &interface.events, |event| &event.name);
+                merge_by_name(&mut errors, &interface.errors, |error| &error.name);
+                merge_by_name(&mut enums, &interface.enums, |enumeration| &enumeration.name);
+                merge_by_name(&mut structs, &interface.structs, |structure| &structure.name);
+            }
+            if let Some(definition) = self.definitions.contracts.get(name) {
+                merge_by_name(&mut fields, &definition.fields, |field| &field.name);
+                merge_by_name(&mut events, &definition.events, |event| &event.name);
+                merge_by_name(&mut errors, &definition.errors, |error| &error.name);
+                merge_by_name(&mut enums, &definition.enums, |enumeration| &enumeration.name);
+                merge_by_name(&mut structs, &definition.structs, |structure| &structure.name);
+                merge_by_name(&mut modifiers, &definition.modifiers, |modifier| {
+                    &modifier.header.name
+                });
+                merge_by_name(&mut libraries, &definition.libraries, |library| &library.name);
+                for library in definition.using.iter() {
+                    if !using.contains(library) {
+                        using.push(library.clone());
+                    }
+                }
+                for function in definition.functions.iter() {
+                    inherited_functions.push((name.clone(), function.clone()));
+                }
+            }
+        }
+        mangle_overloads(
+            inherited_functions
+                .iter_mut()
+                .map(|(_, function)| &mut function.header),
+            self.mangling,
+            self.selectors,
+        );
+        self.overloads = collect_overloads(
+            inherited_functions
+                .iter()
+                .map(|(_, function)| &function.header),
+        );
 
-        while let Some(ch) = self.chars.next() {
-            match ch {
-                NEW_LINE if action == Action::None || action == Action::Contract => {}
-                SLASH if action == Action::Contract => action = Action::Slash,
-                SLASH if action == Action::Slash => {
-                    let comment = self.parse_comment();
-                    if !comment.is_empty() {
-                        comments.push(comment);
-                    }
-                    action = Action::Contract;
-                }
-                ASTERISK if action == Action::Slash => {
-                    let mut new_comments = self.parse_multiline_comment();
-                    comments.append(&mut new_comments);
-                    action = Action::Contract;
-                }
-                SPACE | CURLY_OPEN if action == Action::ContractName => {
-                    name = buffer.trim().to_string();
-                    buffer.clear();
-                    // we skip everything regarding generalization
-                    // TODO: cover generaliztaion
-                    if ch != CURLY_OPEN {
-                        read_until(self.chars, vec![CURLY_OPEN]);
-                    }
-                    action = Action::Contract;
-                }
-                _ if action == Action::None => {
-                    buffer.push(ch);
-                    action = Action::ContractName;
-                }
-                SEMICOLON if action == Action::Contract => {
-                    buffer.push(ch);
-                    fields.push(self.parse_contract_field(buffer.trim(), &comments));
-                    buffer.clear();
-                    comments.clear();
-                }
-                _ if action == Action::ContractName || action == Action::Contract => {
-                    buffer.push(ch);
-                    match buffer.trim() {
-                        "event" => {
-                            let event = self.parse_event(&comments);
-                            self.events.insert(event.name.clone(), event.clone());
-                            events.push(event);
-                            comments.clear();
-                            buffer.clear();
-                        }
-                        "enum" => {
-                            enums.push(self.parse_enum(&comments));
-                            comments.clear();
-                            buffer.clear();
-                        }
-                        "struct" => {
-                            structs.push(self.parse_struct(&comments));
-                            comments.clear();
-                            buffer.clear();
-                        }
-                        "function" => {
-                            functions.push(self.parse_function(&comments)?);
-                            comments.clear();
-                            buffer.clear();
-                        }
-                        "constructor" => {
-                            constructor = self.parse_function(&comments)?;
-                            comments.clear();
-                            buffer.clear();
-                        }
-                        "modifier" => {
-                            modifiers.push(self.parse_modifier(&comments)?);
-                            buffer.clear();
-                            comments.clear();
-                        }
-                        "using" => {
-                            read_until(self.chars, vec![SEMICOLON]);
-                            buffer.clear();
-                        }
-                        "receive" | "fallback" => {
-                            let mut function = self.parse_function(&comments)?;
-                            function.header.name = buffer.trim().to_owned();
-                            functions.push(function);
-                            comments.clear();
-                            buffer.clear();
+        // only the used libraries of the file and the imported files are part of the contract
+        libraries.retain(|library| {
+            let module = format!("{}::", library.name.to_case(Snake));
+            let mut used = false;
+            let statements = inherited_functions
+                .iter()
+                .map(|(_, function)| &function.body)
+                .chain(modifiers.iter().map(|modifier| &modifier.statements))
+                .chain(std::iter::once(&contract.constructor.body))
+                .flatten();
+            visit_raw_expressions(statements, &mut |expression| {
+                if let ast::Expression::Member(base, ..) = expression {
+                    used |= matches!(
+                        base.as_ref(),
+                        ast::Expression::Identifier(name, _) if name == &library.name
+                    );
+                }
+            });
+            used || using.contains(&library.name)
+                || fields
+                    .iter()
+                    .any(|field| field.field_type.contains(&module))
+        });
+
+        // the overloads are told apart by their mangled names
+        self.implementations.clear();
+        for name in self.linearization.iter() {
+            for (_, function) in inherited_functions.iter().filter(|(origin, _)| origin == name) {
+                let implementations = self
+                    .implementations
+                    .entry(function.header.name.clone())
+                    .or_default();
+                if !implementations.contains(name) {
+                    implementations.push(name.clone());
+                }
+            }
+        }
+
+        // overridden functions are only kept if they are called with `super`
+        let mut kept = HashSet::<(String, String)>::new();
+        let mut pending = self
+            .implementations
+            .iter()
+            .map(|(function_name, implementations)| {
+                (implementations[0].clone(), function_name.clone())
+            })
+            .collect::<Vec<(String, String)>>();
+        while let Some((origin, function_name)) = pending.pop() {
+            if !kept.insert((origin.clone(), function_name.clone())) {
+                continue
+            }
+            let function = inherited_functions.iter().find(|(name, function)| {
+                name == &origin && function.header.name == function_name
+            });
+            if let Some((_, function)) = function {
+                let mut called = Vec::<String>::new();
+                visit_raw_expressions(function.body.iter(), &mut |expression| {
+                    if let ast::Expression::Call(callee, args, _) = expression {
+                        if let ast::Expression::Member(base, member, _) = callee.as_ref() {
+                            if matches!(base.as_ref(), ast::Expression::Identifier(name, _) if name == "super")
+                            {
+                                called.push(
+                                    self.resolve_overload(member, args)
+                                        .unwrap_or_else(|| member.clone()),
+                                );
+                            }
                         }
-                        _ => {}
+                    }
+                });
+                self.origin = origin.clone();
+                for called_function in called {
+                    if let Some(implementation) = self.next_implementation(&called_function) {
+                        pending.push((implementation, called_function));
                     }
                 }
-                _ => {}
             }
         }
 
+        let mut functions = Vec::<(String, Function)>::new();
+        for (origin, mut function) in inherited_functions {
+            let function_name = function.header.name.clone();
+            if !kept.contains(&(origin.clone(), function_name.clone())) {
+                continue
+            }
+            if self.implementations[&function_name][0] != origin {
+                function.header.name = format!("{origin}_{function_name}");
+                function.header.visibility = Visibility::Internal;
+            }
+            functions.push((origin, function));
+        }
+
+        for library in libraries.iter() {
+            self.libraries
+                .insert(library.name.clone(), library_functions(library));
+            self.library_references.extend(library_references(
+                &library.name,
+                library.functions.iter().map(|function| &function.header),
+            ));
+            let overloads =
+                collect_overloads(library.functions.iter().map(|function| &function.header));
+            for (name, overloads) in overloads {
+                self.overloads
+                    .insert(format!("{}.{name}", library.name), overloads);
+            }
+        }
+        for library in using.iter() {
+            self.use_library(library);
+        }
+        let mapping_structs = structs
+            .iter()
+            .chain(self.definitions.structs.iter())
+            .filter(|structure| {
+                structure
+                    .fields
+                    .iter()
+                    .any(|field| field.field_type.starts_with("Mapping<"))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        fields = self.split_struct_mappings(fields, &mapping_structs);
         for contract_field in fields.iter() {
             self.storage
                 .insert(contract_field.name.clone(), contract_field.clone());
         }
-        for function in functions.iter() {
+        for (_, function) in functions.iter() {
             self.functions
-                .insert(function.header.name.clone(), function.header.external);
+                .insert(function.header.name.clone(), function.header.visibility.is_message());
+        }
+        // the overloaded functions are also known by their Solidity name
+        for (name, overloads) in self.overloads.iter() {
+            if !name.contains('.') {
+                self.functions
+                    .entry(name.clone())
+                    .or_insert(overloads[0].visibility.is_message());
+            }
         }
         for modifier in modifiers.iter() {
             self.modifiers.insert(modifier.header.name.clone(), ());
@@ -656,1988 +1162,5170 @@ impl<'a> Parser<'a> {
             self.structs
                 .insert(structure.name.clone(), structure.clone());
         }
+        for enumeration in enums.iter() {
+            self.enums
+                .insert(enumeration.name.clone(), enumeration.clone());
+        }
+        // ink! events can only be defined in the contract module
+        let mut emitted = HashSet::<String>::new();
+        let statements = functions
+            .iter()
+            .map(|(_, function)| &function.body)
+            .chain(modifiers.iter().map(|modifier| &modifier.statements))
+            .chain(std::iter::once(&contract.constructor.body))
+            .flatten();
+        for statement in statements {
+            if let Statement::Raw(statement) = statement {
+                statement.walk(&mut |statement| {
+                    if let ast::Statement::Emit(event, _) = statement {
+                        emitted.extend(event_name(event));
+                    }
+                });
+            }
+        }
+        for event in self.definitions.events.iter() {
+            if emitted.contains(&event.name)
+                && !events.iter().any(|defined| defined.name == event.name)
+            {
+                events.push(event.clone());
+            }
+        }
+        for event in events.iter() {
+            self.events.insert(event.name.clone(), event.clone());
+        }
+        let statements = functions
+            .iter()
+            .map(|(_, function)| &function.body)
+            .chain(modifiers.iter().map(|modifier| &modifier.statements))
+            .chain(std::iter::once(&contract.constructor.body))
+            .flatten();
+        add_used_errors(&mut errors, &self.definitions.errors, statements);
+        self.errors.clear();
+        for error in errors
+            .iter()
+            .chain(libraries.iter().flat_map(|library| library.errors.iter()))
+        {
+            self.errors.insert(error.name.clone(), error.clone());
+        }
+        // shared structs and enums are defined in the common module
+        for structure in self.definitions.structs.iter() {
+            self.structs
+                .insert(structure.name.clone(), structure.clone());
+        }
+        for enumeration in self.definitions.enums.iter() {
+            self.enums
+                .insert(enumeration.name.clone(), enumeration.clone());
+        }
+        if !self.definitions.structs.is_empty() || !self.definitions.enums.is_empty() {
+            self.imports.insert(String::from("use common::*;"));
+        }
 
         // now we know the contracts members and we can parse statements
-        for function in functions.iter_mut() {
+        let mut constructor = contract.constructor.clone();
+        constructor.body = self.parse_base_constructors(&mut constructor);
+        self.origin = contract.name.clone();
+        self.enter_function(&constructor.header);
+        constructor
+            .body
+            .append(&mut self.parse_statements(&contract.constructor.body, true));
+
+        let mut parsed_functions = Vec::<Function>::new();
+        for (origin, mut function) in functions {
+            self.origin = origin;
             function.header.modifiers = self.process_function_modifiers(&function.header.modifiers);
+            // the modifiers borrow the contract mutably, so the view function can not borrow
+            // it immutably
+            if !function.header.modifiers.is_empty() {
+                function.header.view = false;
+            }
+            self.enter_function(&function.header);
             function.body = self.parse_statements(&function.body, false);
+            parsed_functions.push(function);
         }
+        self.modifier = true;
         for modifier in modifiers.iter_mut() {
+            self.enter_function(&modifier.header);
             modifier.statements = self.parse_statements(&modifier.statements, false);
         }
-        constructor.body = self.parse_statements(&constructor.body, true);
+        self.modifier = false;
+
+        // the code of the contract and its bases parsed before, followed by the code parsed now
+        let mut report = Vec::<ReportEntry>::new();
+        for name in self.linearization.iter().rev() {
+            if let Some(interface) = self.definitions.interfaces.get(name) {
+                report.extend(interface.report.iter().cloned());
+            }
+            if let Some(definition) = self.definitions.contracts.get(name) {
+                report.extend(definition.report.iter().cloned());
+            }
+        }
+        for library in libraries.iter() {
+            report.extend(library.report.iter().cloned());
+        }
+        report.append(&mut self.report);
+        let mut interfaces = self
+            .interfaces
+            .drain()
+            .map(|(_, interface)| interface)
+            .collect::<Vec<_>>();
+        interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut stored = fields
+            .iter()
+            .filter(|field| !field.constant)
+            .map(|field| field.field_type.clone())
+            .collect();
+        let mut exposed = parsed_functions
+            .iter()
+            .filter(|function| function.header.visibility.is_message())
+            .flat_map(|function| {
+                let header = &function.header;
+                header.params.iter().chain(header.return_params.iter())
+            })
+            .chain(errors.iter().flat_map(|error| error.fields.iter()))
+            .map(|param| param.param_type.clone())
+            .chain(
+                events
+                    .iter()
+                    .flat_map(|event| event.fields.iter())
+                    .map(|field| field.field_type.clone()),
+            )
+            .collect();
+        let mut all_structs = structs
+            .iter_mut()
+            .chain(self.definitions.structs.iter_mut())
+            .chain(libraries.iter_mut().flat_map(|library| library.structs.iter_mut()))
+            .collect::<Vec<_>>();
+        mark_struct_usage(&mut all_structs, &mut stored, &mut exposed);
+        for enumeration in enums
+            .iter_mut()
+            .chain(self.definitions.enums.iter_mut())
+            .chain(libraries.iter_mut().flat_map(|library| library.enums.iter_mut()))
+        {
+            enumeration.stored |= names_type(&stored, &enumeration.name);
+        }
 
         Ok(Contract {
-            name,
+            name: contract.name,
+            base: contract.base,
+            is_abstract: contract.is_abstract,
             fields,
             constructor,
             events,
+            errors,
             enums,
             structs,
-            functions,
+            functions: parsed_functions,
             imports: self.imports.clone(),
-            contract_doc,
+            contract_doc: contract.contract_doc,
             modifiers,
+            libraries,
+            using,
+            interfaces,
+            report,
         })
     }
 
-    /// Parses the code of a Solidity interface
+    /// Stores the mappings of the structs stored in the contract in separate fields of the contract
     ///
-    /// `contract_doc` the documentation comments of the interface
+    /// Structs with mappings can not be encoded, the mapping `voted` of the struct stored
+    /// in the field `proposals: Mapping<u128, Proposal>` is stored in the field
+    /// `proposals_voted: Mapping<(u128, AccountId), bool>`, keyed by the key of the struct
+    /// followed by the keys of the mapping
     ///
-    /// returns the representation of the interface as `Interface` struct
-    pub fn parse_interface(
+    /// `fields` the fields of the contract
+    /// `structs` the structs containing mappings
+    ///
+    /// returns the fields of the contract with the fields of the mappings after their structs
+    fn split_struct_mappings(
         &mut self,
-        contract_comments: Vec<String>,
-    ) -> Result<Interface, ParserError> {
-        let mut buffer = String::new();
-        let mut action = Action::None;
+        fields: Vec<ContractField>,
+        structs: &[Struct],
+    ) -> Vec<ContractField> {
+        let mut output = Vec::new();
+        for field in fields {
+            let stored_struct = if field.constant {
+                None
+            } else if let Some((keys, value)) = mapping_types(&field.field_type) {
+                Some((keys, value))
+            } else if let Some(element) = field.field_type.strip_prefix("Vec<") {
+                // the index of the array is the key of the struct
+                let index = match self.u256 {
+                    true => self.wide_int(),
+                    false => String::from("u128"),
+                };
+                element
+                    .strip_suffix('>')
+                    .map(|element| (vec![index], element.to_owned()))
+            } else {
+                Some((Vec::new(), field.field_type.clone()))
+            };
+            let structure = stored_struct.and_then(|(keys, value)| {
+                structs
+                    .iter()
+                    .find(|structure| structure.name == value)
+                    .map(|structure| (keys, structure))
+            });
+            let name = field.name.clone();
+            output.push(field);
+            let Some((keys, structure)) = structure else {
+                continue
+            };
+            for struct_field in structure.fields.iter() {
+                let Some((mapping_keys, value)) = mapping_types(&struct_field.field_type) else {
+                    continue
+                };
+                let keys = keys.iter().chain(mapping_keys.iter()).cloned().collect::<Vec<_>>();
+                let key = match keys.as_slice() {
+                    [key] => key.clone(),
+                    keys => format!("({})", keys.join(", ")),
+                };
+                output.push(ContractField {
+                    field_type: format!("Mapping<{key}, {value}>"),
+                    name: format!("{name}_{}", struct_field.name),
+                    comments: vec![format!(" The `{}` of the `{name}`", struct_field.name)],
+                    initial_value: None,
+                    constant: false,
+                    immutable: false,
+                    visibility: Visibility::Private,
+                });
+            }
+        }
+        output
+    }
 
-        let mut name = String::new();
-        let mut comments = Vec::<String>::new();
-        let mut events = Vec::<Event>::new();
-        let mut enums = Vec::<Enum>::new();
-        let mut structs = Vec::<Struct>::new();
-        let mut function_headers = Vec::<FunctionHeader>::new();
-
-        while let Some(ch) = self.chars.next() {
-            match ch {
-                NEW_LINE if action == Action::None || action == Action::Contract => {}
-                SLASH if action == Action::Contract => action = Action::Slash,
-                SLASH if action == Action::Slash => {
-                    let comment = self.parse_comment();
-                    if !comment.is_empty() {
-                        comments.push(comment);
-                    }
-                    action = Action::Contract;
-                }
-                ASTERISK if action == Action::Slash => {
-                    let mut new_comments = self.parse_multiline_comment();
-                    comments.append(&mut new_comments);
-                    action = Action::Contract;
-                }
-                CURLY_OPEN => {
-                    action = Action::Contract;
-                }
-                SPACE if action == Action::ContractName => {
-                    name = buffer.trim().substring(1, buffer.len()).to_owned();
-                    buffer.clear();
-                    action = Action::ContractNamed;
-                }
-                _ if action == Action::None => {
-                    buffer.push(ch);
-                    action = Action::ContractName;
-                }
-                _ if action == Action::ContractName || action == Action::Contract => {
-                    buffer.push(ch);
-                    if buffer.trim() == "event" {
-                        events.push(self.parse_event(&comments));
-                        comments.clear();
-                        buffer.clear();
-                    } else if buffer.trim() == "enum" {
-                        enums.push(self.parse_enum(&comments));
-                        comments.clear();
-                        buffer.clear();
-                    } else if buffer.trim() == "struct" {
-                        structs.push(self.parse_struct(&comments));
-                        comments.clear();
-                        buffer.clear();
-                    } else if buffer.trim() == "function" {
-                        let function_header = self.parse_function_header(&comments);
-                        function_headers.push(function_header);
-                        comments.clear();
-                        buffer.clear();
+    /// Finds the field of the contract storing the mapping of a stored struct
+    ///
+    /// `parent` the syntax tree of the struct, eg. `proposals[id]`
+    /// `member` the name of the mapping in the struct, eg. `voted`
+    ///
+    /// returns the name of the field, eg. `proposals_voted`, the keys of the struct and if the
+    /// struct is stored in a storage array,
+    /// or None if the member is not a mapping of a stored struct
+    fn struct_mapping(
+        &self,
+        parent: &ast::Expression,
+        member: &str,
+    ) -> Option<(String, Vec<ast::Expression>, bool)> {
+        let struct_name = self.variable_type(parent)?;
+        let structure = self.structs.get(&struct_name)?;
+        structure
+            .fields
+            .iter()
+            .find(|field| field.name == member && field.field_type.starts_with("Mapping<"))?;
+        let mut keys = Vec::new();
+        let mut root = parent;
+        loop {
+            match root {
+                ast::Expression::Index(inner, Some(key), _) => {
+                    keys.insert(0, key.as_ref().clone());
+                    root = inner;
+                }
+                ast::Expression::Identifier(name, _) => {
+                    match self.storage_pointers.get(name) {
+                        Some(value) => root = value,
+                        None => break,
                     }
                 }
-                _ => {}
+                _ => break,
             }
         }
-
-        Ok(Interface {
-            name,
-            events,
-            enums,
-            structs,
-            function_headers,
-            imports: self.imports.clone(),
-            comments: contract_comments,
-        })
+        let ast::Expression::Identifier(name, _) = root else {
+            return None
+        };
+        if self.locals.contains_key(name) {
+            return None
+        }
+        let field_name = format!("{name}_{member}");
+        let array = self.storage_arrays.contains(name);
+        self.storage
+            .contains_key(&field_name)
+            .then_some((field_name, keys, array))
     }
 
-    /// Parses a field of the contract
+    /// Linearizes the inheritance of a contract the same way as solc does (C3 linearization)
     ///
-    /// `line` the raw representation of the field
-    /// `imports` the HashSet of imports of the contract
+    /// Base contracts which were not parsed are skipped
     ///
-    /// returns the representation of contract field as `ContractField` struct
-    fn parse_contract_field(&mut self, line_raw: &str, comments: &[String]) -> ContractField {
-        let mut line = line_raw.to_owned();
-        line = Regex::new(r"\s*=>\s*")
-            .unwrap()
-            .replace_all(&line, "=>")
-            .to_string();
-        line = Regex::new(r"\s*\(\s*")
-            .unwrap()
-            .replace_all(&line, "(")
-            .to_string();
-        line = Regex::new(r"\s*\)\s*")
-            .unwrap()
-            .replace_all(&line, ")")
-            .to_string();
-
-        let regex: Regex = Regex::new(
-            r#"(?x)^\s*
-        (?P<field_type>.+?(\s|\))+)
-        (?P<attributes>(\s*constant\s*|\s*private\s*|\s*public\s*|\s*immutable\s*|\s*override\s*)*)*
-        (?P<field_name>.+?)\s*
-        (=\s*(?P<initial_value>[^>].+)\s*)*
-        ;\s*$"#,
-        )
-        .unwrap();
-
-        let field_type_raw = capture_regex(&regex, &line, "field_type").unwrap();
-        let attributes_raw = capture_regex(&regex, &line, "attributes");
-        let field_name = capture_regex(&regex, &line, "field_name").unwrap();
-        let initial_value_maybe = capture_regex(&regex, &line, "initial_value");
-        let initial_value =
-            initial_value_maybe.map(|initial_raw| self.parse_expression(&initial_raw, false, None));
-        let constant = attributes_raw
-            .unwrap_or_else(|| String::from(""))
-            .contains("constant");
-        let field_type = self.convert_variable_type(trim(&field_type_raw));
-
-        ContractField {
-            field_type,
-            name: field_name,
-            comments: comments.to_vec(),
-            initial_value,
-            constant,
+    /// `name` the name of the contract
+    /// `derived` the contracts inheriting this contract, used to detect cycles
+    ///
+    /// returns the names of the contracts, starting with the most derived one
+    fn linearize(
+        &mut self,
+        name: &String,
+        derived: &mut Vec<String>,
+    ) -> Result<Vec<String>, ParserError> {
+        if derived.contains(name) {
+            return Err(ParserError::IncorrectInheritance(format!(
+                "{name} inherits itself"
+            )))
         }
-    }
 
-    /// Parses Solidity event
-    ///
-    /// `comments` the documentation comments of the event
-    ///
-    /// returns the event definition as `Event` struct
-    fn parse_event(&mut self, comments: &[String]) -> Event {
-        let event_raw = read_until(self.chars, vec![SEMICOLON])
-            .trim()
-            .replace("( ", "(")
-            .replace(" )", ")");
-
-        let tokens = split(&event_raw, " ", None);
-        let mut args_reader = ArgsReader::ArgName;
-        let mut indexed = false;
-
-        let split_brace = split(&tokens[0], "(", None);
-
-        let name = split_brace[0].to_owned();
-        let mut field_type = self.convert_variable_type(split_brace[1].to_owned());
-        let mut fields = Vec::<EventField>::new();
-
-        for item in tokens.iter().skip(1) {
-            let mut token = item.clone();
-            if token == "indexed" {
-                indexed = true;
-                continue
-            } else if args_reader == ArgsReader::ArgType {
-                field_type = self.convert_variable_type(token);
-                args_reader = ArgsReader::ArgName;
-            } else {
-                token.remove_matches(&[',', ')'][..]);
-                fields.push(EventField {
-                    indexed,
-                    field_type: field_type.to_owned(),
-                    name: token.to_owned(),
-                });
-                indexed = false;
-                args_reader = ArgsReader::ArgType;
+        let mut base = Vec::<String>::new();
+        let mut missing = Vec::<(String, Span)>::new();
+        if let Some(contract) = self.definitions.contracts.get(name) {
+            for base_contract in contract.base.iter() {
+                if self.definitions.contracts.contains_key(&base_contract.name)
+                    || self.definitions.interfaces.contains_key(&base_contract.name)
+                {
+                    base.push(base_contract.name.clone());
+                } else {
+                    missing.push((base_contract.name.clone(), base_contract.span));
+                }
             }
         }
-
-        Event {
-            name,
-            fields,
-            comments: comments.to_vec(),
+        for (base_name, span) in missing {
+            let origin = mem::replace(&mut self.origin, name.clone());
+            self.warn(
+                Category::MissingBaseContract,
+                format!("base contract `{base_name}` of `{name}` was not found, skipping"),
+                span,
+            );
+            self.origin = origin;
         }
-    }
-
-    /// Parses Solidity enum
-    ///
-    /// `comments` the documentation comments of the enum
-    ///
-    /// returns the enum as `Enum` struct
-    fn parse_enum(&mut self, comments: &[String]) -> Enum {
-        let enum_raw = read_until(self.chars, vec![CURLY_CLOSE]);
-        let tokens = split(&enum_raw, " ", None);
-        let name = tokens[0].to_owned();
-        let mut values = Vec::<String>::new();
 
-        for item in tokens.iter().skip(1) {
-            let mut token = item.clone();
-            if token == "{" {
-                continue
-            } else {
-                token.remove_matches(",");
-                values.push(token);
-            }
+        // solidity lists the base contracts from the most base one to the most derived one
+        derived.push(name.clone());
+        let mut sequences = Vec::<Vec<String>>::new();
+        for base_name in base.iter().rev() {
+            sequences.push(self.linearize(base_name, derived)?);
         }
-        Enum {
-            name,
-            values,
-            comments: comments.to_vec(),
+        derived.pop();
+        sequences.push(base.iter().rev().cloned().collect());
+
+        let mut output = vec![name.clone()];
+        loop {
+            sequences.retain(|sequence| !sequence.is_empty());
+            if sequences.is_empty() {
+                return Ok(output)
+            }
+            let candidate = sequences
+                .iter()
+                .map(|sequence| sequence[0].clone())
+                .find(|head| {
+                    !sequences
+                        .iter()
+                        .any(|sequence| sequence[1..].contains(head))
+                })
+                .ok_or_else(|| {
+                    ParserError::IncorrectInheritance(format!(
+                        "Linearization of {name} is impossible"
+                    ))
+                })?;
+            for sequence in sequences.iter_mut() {
+                if sequence[0] == candidate {
+                    sequence.remove(0);
+                }
+            }
+            output.push(candidate);
         }
     }
 
-    /// Parses Solidity structure
+    /// Parses the constructors of the base contracts, which are inlined into the constructor
     ///
-    /// `comments` the documentation comments of the struct
+    /// Parameters of a base constructor are declared as local variables initialized with
+    /// the arguments from the inheritance list or from the constructor header.
+    /// If no arguments were passed, the parameters are added to the parameters of `constructor`.
     ///
-    /// returns the struct definition as `Struct` struct
-    fn parse_struct(&mut self, comments: &[String]) -> Struct {
-        let mut struct_raw = read_until(self.chars, vec![CURLY_CLOSE]);
-        struct_raw = struct_raw.replace(" => ", "=>");
-        let split_brace = split(&struct_raw, "{", None);
-        let fields = split(split_brace[1].trim(), ";", None);
-        let struct_name = split_brace[0].to_owned();
-
-        let mut struct_fields = Vec::<StructField>::new();
+    /// `constructor` the constructor of the most derived contract
+    ///
+    /// returns the statements of the base constructors, starting with the most base contract
+    fn parse_base_constructors(&mut self, constructor: &mut Function) -> Vec<Statement> {
+        let mut statements = Vec::default();
+        let mut base_args = HashMap::<String, Vec<ast::Expression>>::new();
 
-        for field in fields.iter() {
-            if field.is_empty() {
-                continue
+        for name in self.linearization.iter() {
+            if let Some(definition) = self.definitions.contracts.get(name) {
+                for base in definition.base.iter().filter(|base| !base.args.is_empty()) {
+                    base_args.insert(base.name.clone(), base.args.clone());
+                }
+                // arguments of base constructors can be passed like modifiers of the constructor
+                for modifier in definition.constructor.header.modifiers.iter() {
+                    if let Expression::Raw(ast::Expression::Call(callee, args, _)) = modifier {
+                        if let ast::Expression::Identifier(base, _) = callee.as_ref() {
+                            if !args.is_empty() {
+                                base_args.insert(base.clone(), args.clone());
+                            }
+                        }
+                    }
+                }
             }
-            struct_fields.push(self.parse_struct_field(field.trim().to_owned()));
         }
 
-        Struct {
-            name: struct_name,
-            fields: struct_fields,
-            comments: comments.to_vec(),
+        for name in self.linearization.clone().iter().skip(1).rev() {
+            let base_constructor = match self.definitions.contracts.get(name) {
+                Some(definition) => definition.constructor.clone(),
+                None => continue,
+            };
+            let args = base_args.get(name).cloned().unwrap_or_default();
+            self.origin = name.clone();
+
+            for (i, param) in base_constructor.header.params.iter().enumerate() {
+                match args.get(i) {
+                    Some(arg) => {
+                        let value = self.parse_expression(arg, true);
+                        statements.push(Statement::Declaration(
+                            param.name.clone(),
+                            param.param_type.clone(),
+                            Some(value),
+                        ));
+                    }
+                    None => constructor.header.params.push(param.clone()),
+                }
+            }
+            statements.append(&mut self.parse_statements(&base_constructor.body, true));
         }
+
+        statements
     }
 
-    /// Parses struct fields
+    /// Finds the contract implementing a function called with `super` from the currently parsed function
     ///
-    /// `line` the Solidity definition of the struct field
+    /// `function_name` the name of the called function
     ///
-    /// returns the struct field as `StructField` struct
-    fn parse_struct_field(&mut self, line: String) -> StructField {
-        let tokens = split(&line, " ", None);
-        let field_type = self.convert_variable_type(tokens[0].to_owned());
-        let mut name = tokens[1].to_owned();
-        name.remove_matches(";");
-        StructField { name, field_type }
+    /// returns the name of the contract, or None if no base contract implements the function
+    fn next_implementation(&self, function_name: &str) -> Option<String> {
+        let implementations = self.implementations.get(function_name)?;
+        let origin_index = self
+            .linearization
+            .iter()
+            .position(|name| name == &self.origin)?;
+        self.linearization
+            .iter()
+            .skip(origin_index + 1)
+            .find(|name| implementations.contains(name))
+            .cloned()
     }
 
-    /// Parses the Solidity function
+    /// Resolves a function called with `super` from the currently parsed function
     ///
-    /// `comments` the documentation comments of the function
+    /// `function_name` the name of the called function
     ///
-    /// returns the function definition as `Function` struct
-    fn parse_function(&mut self, comments: &[String]) -> Result<Function, ParserError> {
-        Ok(Function {
-            header: self.parse_function_header(comments),
-            body: self.parse_body(),
-        })
+    /// returns the name of the function to call and whether it is external,
+    /// or None if no base contract implements the function
+    fn resolve_super(&self, function_name: &String) -> Option<(String, bool)> {
+        let implementation = self.next_implementation(function_name)?;
+
+        if implementation == self.implementations[function_name][0] {
+            Some((
+                function_name.clone(),
+                *self.functions.get(function_name).unwrap_or(&false),
+            ))
+        } else {
+            Some((format!("{implementation}_{function_name}"), false))
+        }
     }
 
-    /// Parses the function header of a Solidity function
+    /// Resolves the called overload of an overloaded function
     ///
-    /// `comments` the documentation comments of the function
+    /// `name` the Solidity name of the function, prefixed with the name of the library
+    /// for the library functions
+    /// `args` the syntax trees of the arguments
     ///
-    /// returns the representation of the function header as `FunctionHeader` struct
-    fn parse_function_header(&mut self, comments: &[String]) -> FunctionHeader {
-        let mut function_header_raw = read_until(self.chars, vec![SEMICOLON, CURLY_OPEN]);
-        function_header_raw.remove_matches(" memory");
-        function_header_raw.remove_matches(" storage");
-        function_header_raw.remove_matches(" calldata");
-
-        let regex_return_function = Regex::new(
-            r#"(?x)
-        ^\s*(?P<function_name>[a-zA-Z0-9_]*?)\s*
-        \(\s*(?P<parameters>[a-zA-Z0-9_,\s\[\]]*?)\s*\)\s*
-        (?P<attributes>.*)\s+returns\s*\(\s*(?P<return_parameters>[a-zA-Z0-9_,\s\[\]]*?)\s*\)
-        .*$"#,
-        )
-        .unwrap();
-        let return_parameters_maybe = capture_regex(
-            &regex_return_function,
-            &function_header_raw,
-            "return_parameters",
-        );
+    /// returns the name of the called overload, or None if the function is not overloaded
+    fn resolve_overload(&self, name: &str, args: &[ast::Expression]) -> Option<String> {
+        self.overloads
+            .get(name)
+            .map(|overloads| self.select_overload(overloads.iter(), args))
+    }
 
-        let (name, params, return_params, modifiers) = if let Some(return_parameters_raw) =
-            return_parameters_maybe
-        {
-            let function_name = capture_regex(
-                &regex_return_function,
-                &function_header_raw,
-                "function_name",
-            )
-            .unwrap();
-            let parameters_raw =
-                capture_regex(&regex_return_function, &function_header_raw, "parameters").unwrap();
-            let parameters = self.parse_function_parameters(parameters_raw);
-            let return_parameters = self.parse_return_parameters(return_parameters_raw);
-            let attribs_raw =
-                capture_regex(&regex_return_function, &function_header_raw, "attributes").unwrap();
-            (
-                function_name,
-                parameters,
-                return_parameters,
-                parse_modifiers(&attribs_raw),
-            )
-        } else {
-            let regex_no_return = Regex::new(
-                r#"(?x)
-            ^\s*(?P<function_name>[a-zA-Z0-9_]*?)\s*
-            \(\s*(?P<parameters>[a-zA-Z0-9_,\s\[\]]*?)\s*\)
-            \s*(?P<attributes>.*)\s*$"#,
-            )
-            .unwrap();
-            let function_name =
-                capture_regex(&regex_no_return, &function_header_raw, "function_name").unwrap();
-            let parameters_raw =
-                capture_regex(&regex_no_return, &function_header_raw, "parameters").unwrap();
-            let attribs_raw =
-                capture_regex(&regex_no_return, &function_header_raw, "attributes").unwrap();
-            let parameters = self.parse_function_parameters(parameters_raw);
-            (
-                function_name,
-                parameters,
-                Vec::default(),
-                parse_modifiers(&attribs_raw),
-            )
+    /// Selects the called overload by the number of the arguments and by the types
+    /// of the arguments which are known
+    ///
+    /// `overloads` the overloads of the called function
+    /// `args` the syntax trees of the arguments
+    ///
+    /// returns the name of the selected overload
+    fn select_overload<'h>(
+        &self,
+        overloads: impl Iterator<Item = &'h FunctionHeader> + Clone,
+        args: &[ast::Expression],
+    ) -> String {
+        let arg_types = args
+            .iter()
+            .map(|arg| self.guess_type(arg))
+            .collect::<Vec<_>>();
+        let accepts = |overload: &&FunctionHeader| {
+            overload
+                .params
+                .iter()
+                .zip(arg_types.iter())
+                .all(|(param, arg_type)| {
+                    match arg_type.as_deref() {
+                        Some("{integer}") => is_integer_param(&param.param_type),
+                        Some(arg_type) => param.param_type == arg_type,
+                        None => true,
+                    }
+                })
         };
-
-        let (external, view, payable) = parse_function_attributes(&function_header_raw);
-
-        FunctionHeader {
-            name,
-            params,
-            external,
-            view,
-            payable,
-            return_params,
-            comments: comments.to_vec(),
-            modifiers,
-        }
+        let same_arity = overloads
+            .clone()
+            .filter(|overload| overload.params.len() == args.len());
+        same_arity
+            .clone()
+            .find(accepts)
+            .or_else(|| same_arity.clone().next())
+            .or_else(|| overloads.clone().next())
+            .map(|overload| overload.name.clone())
+            .unwrap_or_default()
     }
 
-    /// Parses parameters of a function
+    /// Guesses the ink! type of the argument of an overloaded function
     ///
-    /// `parameters` the raw representation of the paramters of the function
+    /// `expression` the syntax tree of the argument
     ///
-    /// returns the vec of function parameters of this function as `FunctionParam` struct
-    fn parse_function_parameters(&mut self, parameters: String) -> Vec<FunctionParam> {
-        let mut out = Vec::<FunctionParam>::new();
-
-        if !parameters.is_empty() {
-            let tokens = split(&parameters, " ", Some(remove_commas()));
-
-            let mut mode = ArgsReader::ArgName;
-            let mut param_type = self.convert_variable_type(tokens[0].to_owned());
-
-            for item in tokens.iter().skip(1) {
-                if mode == ArgsReader::ArgType {
-                    param_type = self.convert_variable_type(item.to_owned());
-                    mode = ArgsReader::ArgName;
-                } else if mode == ArgsReader::ArgName {
-                    let name = item.to_owned();
-                    out.push(FunctionParam {
-                        name,
-                        param_type: param_type.to_owned(),
-                    });
-                    mode = ArgsReader::ArgType;
+    /// returns the type, `{integer}` for the integer literals or None if the type is unknown
+    fn guess_type(&self, expression: &ast::Expression) -> Option<String> {
+        match expression {
+            ast::Expression::Identifier(name, _) => {
+                self.locals
+                    .get(name)
+                    .or_else(|| self.storage.get(name).map(|field| &field.field_type))
+                    .cloned()
+            }
+            ast::Expression::NumberLiteral(..) => Some(String::from("{integer}")),
+            ast::Expression::StringLiteral(..) => Some(String::from("String")),
+            ast::Expression::BoolLiteral(..) => Some(String::from("bool")),
+            ast::Expression::Parenthesis(inner, _) => self.guess_type(inner),
+            _ if self.is_account(expression) => Some(String::from("AccountId")),
+            ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+                match callee.as_ref() {
+                    ast::Expression::Identifier(name, _) => {
+                        TYPES.get(name.as_str()).map(|the_type| the_type.0.to_owned())
+                    }
+                    _ => None,
                 }
             }
+            _ => None,
         }
-
-        out
     }
 
-    /// Parses return parameters of a function
+    /// returns the ink! type of the variable, of its element or of its member, eg. `u128`
+    /// of `values[i]` with `uint256[] values`, or None if the type is unknown
     ///
-    /// `parameters` the String which contains the return paramters of the function
+    /// The nested mapping indexed by some of its keys has the type of the mapping
+    /// of the remaining keys
     ///
-    /// returns the vec of function return parameters of this function
-    fn parse_return_parameters(&mut self, parameters: String) -> Vec<FunctionParam> {
-        let mut out = Vec::<FunctionParam>::new();
-        let tokens: Vec<String> = split(&parameters, " ", None);
-
-        let mut iterator = tokens.iter();
-        while let Some(token) = iterator.next() {
-            let mut param_raw = token.to_owned();
-            param_raw.remove_matches(",");
-            let param_type = self.convert_variable_type(param_raw);
-            let mut name = if tokens.len() >= (parameters.matches(',').count() + 1) * 2 {
-                iterator.next().unwrap().to_owned()
-            } else {
-                String::from("_")
-            };
-            name.remove_matches(",");
-            out.push(FunctionParam { name, param_type })
+    /// `expression` the syntax tree of the variable
+    fn variable_type(&self, expression: &ast::Expression) -> Option<String> {
+        match expression {
+            ast::Expression::Identifier(name, _) => {
+                self.locals
+                    .get(name)
+                    .or_else(|| self.storage.get(name).map(|field| &field.field_type))
+                    .cloned()
+            }
+            ast::Expression::Parenthesis(inner, _) => self.variable_type(inner),
+            ast::Expression::Index(collection, Some(_), _) => {
+                element_type(&self.variable_type(collection)?)
+            }
+            // `bytes(name)`
+            ast::Expression::Call(callee, args, _)
+                if args.len() == 1
+                    && matches!(
+                        callee.as_ref(),
+                        ast::Expression::Identifier(name, _) if name == "bytes"
+                    ) =>
+            {
+                Some(String::from("Vec<u8>"))
+            }
+            ast::Expression::Member(base, member, _) => {
+                let base_type = self.variable_type(base)?;
+                let struct_name = base_type.rsplit("::").next()?;
+                self.structs
+                    .get(struct_name)?
+                    .fields
+                    .iter()
+                    .find(|field| &field.name == member)
+                    .map(|field| field.field_type.clone())
+            }
+            _ => None,
         }
-
-        out
     }
 
-    /// Parses the body of a function or a modifier and returns them in a vector of `Statement`
-    fn parse_body(&mut self) -> Vec<Statement> {
-        let mut buffer = String::new();
-        let mut open_braces = 1;
-        let mut close_braces = 0;
-        let mut statements = Vec::<Statement>::new();
-        let mut action = Action::None;
-
-        while let Some(ch) = self.chars.next() {
-            if ch == CURLY_OPEN {
-                open_braces += 1;
-            } else if ch == CURLY_CLOSE {
-                close_braces += 1
-            }
-
-            if ch == NEW_LINE {
-                if action == Action::AssemblyStart {
-                    action = Action::Assembly;
-                } else if action == Action::Assembly {
-                    statements.push(Statement::Raw(buffer.clone()));
-                    buffer.clear();
-                } else {
-                    buffer.push(SPACE);
-                }
-            } else if ch == SEMICOLON || ch == CURLY_CLOSE || ch == CURLY_OPEN {
-                buffer.push(ch);
-                if open_braces == close_braces {
-                    break
+    /// returns true if the expression reads a value of the storage which is not `Copy`,
+    /// eg. a `string` field, so the value is cloned when it is returned
+    ///
+    /// The values of mappings are read as owned values
+    fn is_borrowed(&self, expression: &ast::Expression) -> bool {
+        let mut root = expression;
+        loop {
+            match root {
+                ast::Expression::Parenthesis(inner, _) | ast::Expression::Member(inner, ..) => {
+                    root = inner
                 }
-                let regex_struct_initializer =
-                    Regex::new(r#"(?x)^\s*(?P<code>.+)\s*\(\{$"#).unwrap();
-                if regex_struct_initializer.is_match(&buffer) {
-                    let left_code =
-                        capture_regex(&regex_struct_initializer, &buffer, "code").unwrap();
-                    let right_code = read_until(self.chars, vec![';']);
-                    buffer = format!("{left_code}({{{right_code}");
-                    close_braces += 1;
-                }
-                statements.push(Statement::Raw(buffer.clone()));
-                if action == Action::Assembly {
-                    action = Action::None;
-                }
-                buffer.clear();
-            } else if ch == SLASH {
-                let next_maybe = self.chars.next();
-                if next_maybe == Some(SLASH) {
-                    statements.push(Statement::Raw(format!("// {}", self.parse_comment())));
-                    continue
-                } else if next_maybe == Some(ASTERISK) {
-                    for comment in self.parse_multiline_comment().iter() {
-                        statements.push(Statement::Raw(format!("// {comment}")));
+                ast::Expression::Index(collection, Some(_), _) => {
+                    if self
+                        .variable_type(collection)
+                        .is_none_or(|collection| collection.starts_with("Mapping<"))
+                    {
+                        return false
                     }
-                    continue
+                    root = collection
                 }
-                buffer.push(ch);
-                buffer.push(next_maybe.unwrap());
-            } else {
-                buffer.push(ch);
-                if trim(&buffer) == "assembly" {
-                    action = Action::AssemblyStart;
-                } else if trim(&buffer) == "for" {
-                    open_braces += 1;
-                    let for_block = read_until(self.chars, vec!['{']);
-                    statements.push(Statement::Raw(format!("for{for_block}{{")));
-                    buffer.clear();
+                ast::Expression::Identifier(name, _) => {
+                    // the constants are converted to owned values where they are used
+                    if self.locals.contains_key(name)
+                        || self.storage.get(name).is_none_or(|field| field.constant)
+                    {
+                        return false
+                    }
+                    break
                 }
+                _ => return false,
             }
         }
-
-        statements
+        self.variable_type(expression).is_some_and(|variable_type| {
+            variable_type == "String"
+                || variable_type.starts_with("Vec<")
+                || variable_type
+                    .rsplit("::")
+                    .next()
+                    .is_some_and(|name| self.structs.contains_key(name))
+        })
     }
 
-    /// Parses the Solidity modifier
-    ///
-    /// `comments` the documentation comments of the modifier
+    /// returns true if the expression is a value in the storage whose keys and indices are
+    /// variables or literals, eg. `proposals[id]`, so a storage pointer to it can be resolved
+    /// to the expression where the pointer is used
     ///
-    /// returns the representation of the modifier header as `Modifier` struct
-    fn parse_modifier(&mut self, comments: &[String]) -> Result<Modifier, ParserError> {
-        self.imports
-            .insert(String::from("use openbrush::modifier_definition;"));
-        self.imports
-            .insert(String::from("use openbrush::modifiers;"));
-        Ok(Modifier {
-            header: self.parse_function_header(comments),
-            statements: self.parse_body(),
-            comments: comments.to_vec(),
-        })
+    /// `expression` the syntax tree of the expression
+    fn is_storage_path(&self, expression: &ast::Expression) -> bool {
+        let is_key = |key: &ast::Expression| {
+            matches!(
+                key,
+                ast::Expression::Identifier(..)
+                    | ast::Expression::NumberLiteral(..)
+                    | ast::Expression::StringLiteral(..)
+                    | ast::Expression::BoolLiteral(..)
+            ) || matches!(key.to_string().as_str(), "msg.sender" | "tx.origin")
+        };
+        match expression {
+            ast::Expression::Identifier(name, _) => {
+                self.storage_pointers.contains_key(name)
+                    || (!self.locals.contains_key(name) && self.storage.contains_key(name))
+            }
+            ast::Expression::Index(inner, Some(key), _) => {
+                is_key(key) && self.is_storage_path(inner)
+            }
+            ast::Expression::Member(inner, ..) | ast::Expression::Parenthesis(inner, _) => {
+                self.is_storage_path(inner)
+            }
+            _ => false,
+        }
     }
 
-    /// Parses all modifiers of a function and returns them as a vector of `Modifier` expressions
+    /// Points the storage pointer to the storage path, eg. `Proposal storage p = proposals[id]`
     ///
-    /// `raw_modifiers` the raw representations of modifiers of the function
+    /// The variable keys of the path are bound to locals, eg. `let p_key = id;`, so the pointer
+    /// keeps pointing to the same value if the variables change
     ///
-    /// returns the vector of `Modifier` expressions as function calls of the specific modifiers
-    fn process_function_modifiers(&mut self, raw_modifiers: &[Expression]) -> Vec<Expression> {
-        let regex_modifier_name = Regex::new(r#"(?x)^\s*(?P<name>.+?)\(.\)*"#).unwrap();
-        let mut out = Vec::default();
-        for raw_modifier in raw_modifiers.iter() {
-            if let Expression::Modifier(modifier) = raw_modifier {
-                let modifier_name =
-                    capture_regex(&regex_modifier_name, modifier, "name").unwrap_or_default();
-                if self.modifiers.contains_key(&modifier_name) {
-                    let function_call = self.parse_function_call(modifier, false, None);
-                    out.push(function_call)
+    /// `name` the name of the storage pointer
+    /// `path` the syntax tree of the storage path
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// returns the declarations of the locals
+    fn point_storage(
+        &mut self,
+        name: &str,
+        path: &ast::Expression,
+        constructor: bool,
+    ) -> Vec<Statement> {
+        let mut variable_keys = 0;
+        let mut root = path;
+        loop {
+            match root {
+                ast::Expression::Index(inner, Some(key), _) => {
+                    variable_keys += usize::from(self.variable_key_type(key).is_some());
+                    root = inner
+                }
+                ast::Expression::Member(inner, ..) | ast::Expression::Parenthesis(inner, _) => {
+                    root = inner
                 }
+                _ => break,
             }
         }
-        out
+        let mut declarations = Vec::new();
+        let path = self.bind_keys(name, path, variable_keys > 1, &mut declarations, constructor);
+        self.storage_pointers.insert(name.to_owned(), path);
+        declarations
     }
 
-    /// Parses raw statements of a function or modifier and returns them in a vector of `Statement`
-    ///
-    /// `statements` the raw statements of the function or modifier
-    /// `constructor` if the function is a constructor
-    fn parse_statements(&mut self, statements: &[Statement], constructor: bool) -> Vec<Statement> {
-        let mut iterator = statements.iter();
-        let mut stack = VecDeque::<Block>::new();
-        let mut out = Vec::default();
-
-        while let Some(statement) = iterator.next() {
-            if let Statement::Raw(line_raw) = statement {
-                out.push(self.parse_statement(line_raw, constructor, &mut stack, &mut iterator));
-            }
+    /// returns the ink! type of the key of the storage path if it is a variable,
+    /// or None if the key does not change, eg. a literal
+    fn variable_key_type(&self, key: &ast::Expression) -> Option<String> {
+        match key {
+            ast::Expression::Identifier(..) => self.variable_type(key),
+            _ => None,
         }
-
-        out
     }
 
-    /// Parses a soldity statement and returns it in a form of `Statement`
+    /// Replaces the variable keys of the storage path with the locals they are bound to
     ///
-    /// `line_raw` the solidity statement
+    /// `name` the name of the storage pointer
+    /// `path` the syntax tree of the storage path
+    /// `numbered` if the path has more variable keys, they are numbered from the root
+    /// `declarations` the declarations of the locals
     /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
     ///
-    /// returns the statement as `Statement` struct
-    fn parse_statement(
+    /// returns the path with the locals as its keys
+    fn bind_keys(
         &mut self,
-        line_raw: &str,
+        name: &str,
+        path: &ast::Expression,
+        numbered: bool,
+        declarations: &mut Vec<Statement>,
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let mut line = trim(line_raw);
-        line = line.replace(" memory ", " ");
-        line = line.replace(" calldata ", " ");
-        line = line.replace(" storage ", " ");
-
-        if line == "_;" {
-            return Statement::ModifierBody
-        } else if REGEX_RETURN.is_match(&line) {
-            return self.parse_return(&line)
-        } else if REGEX_DECLARE.is_match(&line) {
-            return self.parse_declaration(&line, constructor)
-        } else if REGEX_REQUIRE.is_match(&line) {
-            return self.parse_require(&line, constructor)
-        } else if REGEX_COMMENT.is_match(&line) {
-            let comment = capture_regex(&REGEX_COMMENT, &line, "comment").unwrap();
-            return Statement::Comment(comment)
-        } else if REGEX_CONDITION_ONE_LINE.is_match(&line) {
-            return self.parse_condition_one_line(&line, constructor, stack, iterator)
-        } else if REGEX_IF.is_match(&line) {
-            stack.push_back(Block::If);
-            return self.parse_if(&line, constructor, stack, iterator)
-        } else if REGEX_ELSE.is_match(&line) {
-            stack.push_back(Block::Else);
-            return self.parse_else(constructor, stack, iterator)
-        } else if REGEX_ELSE_IF.is_match(&line) {
-            stack.push_back(Block::ElseIf);
-            return self.parse_else_if(&line, constructor, stack, iterator)
-        } else if REGEX_UNCHECKED.is_match(&line) {
-            stack.push_back(Block::Unchecked);
-            return Statement::Comment(String::from("Please handle unchecked blocks manually >>>"))
-        } else if REGEX_END_BLOCK.is_match(&line) {
-            if stack.is_empty() {
-                return Statement::Comment(String::from(
-                    "Sol2Ink Not Implemented yet End Block here",
-                ))
+    ) -> ast::Expression {
+        match path {
+            ast::Expression::Index(inner, Some(key), span) => {
+                let inner = self.bind_keys(name, inner, numbered, declarations, constructor);
+                let key = match self.variable_key_type(key) {
+                    Some(key_type) => {
+                        let local = match numbered {
+                            true => format!("{name}_key_{}", declarations.len()),
+                            false => format!("{name}_key"),
+                        };
+                        let value = self.parse_expression(key, constructor);
+                        declarations.push(Statement::Declaration(
+                            local.clone(),
+                            key_type.clone(),
+                            Some(value),
+                        ));
+                        self.locals.insert(local.clone(), key_type);
+                        ast::Expression::Identifier(local, *span)
+                    }
+                    None => key.as_ref().clone(),
+                };
+                ast::Expression::Index(bx!(inner), Some(bx!(key)), *span)
             }
-            match stack.pop_back().unwrap() {
-                Block::Assembly => return Statement::AssemblyEnd,
-                Block::Catch => return Statement::CatchEnd,
-                Block::Unchecked => {}
-                Block::If => return Statement::IfEnd,
-                Block::Else => return Statement::IfEnd,
-                Block::ElseIf => return Statement::IfEnd,
-                Block::Try => return Statement::TryEnd,
-                Block::While => return Statement::WhileEnd,
-            }
-            return Statement::Comment(String::from("<<< Please handle unchecked blocks manually"))
-        } else if REGEX_TRY.is_match(&line) {
-            stack.push_back(Block::Try);
-            return self.parse_try(&line, constructor, stack, iterator)
-        } else if REGEX_FOR.is_match(&line) {
-            stack.push_back(Block::While);
-            return self.parse_for(&line, constructor, stack, iterator)
-        } else if REGEX_DO.is_match(&line) {
-            stack.push_back(Block::While);
-            return self.parse_do(constructor, stack, iterator)
-        } else if REGEX_WHILE.is_match(&line) {
-            stack.push_back(Block::While);
-            return self.parse_while(&line, constructor, stack, iterator)
-        } else if REGEX_ASSEMBLY.is_match(&line) {
-            stack.push_back(Block::Assembly);
-            return self.parse_assembly(stack, iterator)
-        } else if REGEX_CATCH.is_match(&line) {
-            stack.push_back(Block::Catch);
-            return self.parse_catch(&line, constructor, stack, iterator)
-        } else if REGEX_EMIT.is_match(&line) {
-            return self.parse_emit(&line, constructor)
-        } else if REGEX_ASSIGN.is_match(&line) {
-            return self.parse_assign(&line, constructor)
-        } else if REGEX_TERNARY.is_match(&line) {
-            return self.parse_ternary_statement(&line, constructor, stack, iterator)
-        } else if REGEX_BINARY_SUFFIX.is_match(&line) {
-            return self.parse_binary_operation(&line, constructor, &REGEX_BINARY_SUFFIX, None)
-        } else if REGEX_BINARY_PREFIX.is_match(&line) {
-            return self.parse_binary_operation(&line, constructor, &REGEX_BINARY_PREFIX, None)
-        } else if REGEX_FUNCTION_CALL.is_match(&line) {
-            let expression = self.parse_function_call(&line, constructor, None);
-            return Statement::FunctionCall(expression)
-        }
-
-        Statement::Comment(format!("Sol2Ink Not Implemented yet: {}", line.clone()))
-    }
-
-    /// Parses a return statement
-    ///
-    /// `line` the soldiity return statement
-    ///
-    /// returns the statements in form of `Statement::Return`
-    fn parse_return(&mut self, line: &str) -> Statement {
-        let raw_output = capture_regex(&REGEX_RETURN, line, "output").unwrap();
-        let output = self.parse_expression(&raw_output, false, None);
-
-        Statement::Return(output)
-    }
-
-    /// Parses a declaration statement
-    ///
-    /// `line` the soldity declaration statement
-    ///
-    /// returns the statements in form of `Statement::Declaration`
-    fn parse_declaration(&mut self, line: &str, constructor: bool) -> Statement {
-        let field_type_raw = capture_regex(&REGEX_DECLARE, line, "field_type").unwrap();
-        let field_name = capture_regex(&REGEX_DECLARE, line, "field_name").unwrap();
-        let value_raw = capture_regex(&REGEX_DECLARE, line, "value");
-        let field_type = self.convert_variable_type(field_type_raw);
-
-        if let Some(value) = value_raw {
-            let expression = self.parse_expression(&value, constructor, None);
-            Statement::Declaration(field_name, field_type, Some(expression))
-        } else {
-            Statement::Declaration(field_name, field_type, None)
+            ast::Expression::Member(inner, member, span) => {
+                let inner = self.bind_keys(name, inner, numbered, declarations, constructor);
+                ast::Expression::Member(bx!(inner), member.clone(), *span)
+            }
+            ast::Expression::Parenthesis(inner, span) => {
+                let inner = self.bind_keys(name, inner, numbered, declarations, constructor);
+                ast::Expression::Parenthesis(bx!(inner), *span)
+            }
+            path => path.clone(),
         }
     }
 
-    /// Parses a require statement
-    ///
-    /// `line` the soldity require statement
-    /// `constructor` whether the require is in a constructor or not
-    ///
-    /// returns the statements in form of `Statement::Require`
-    fn parse_require(&mut self, line: &str, constructor: bool) -> Statement {
-        self.imports
-            .insert(String::from("use ink_prelude::string::String;"));
+    /// returns true if the expression is an array or `bytes`, or if its type is unknown,
+    /// eg. the members `length`, `push` and `pop` of the expression are those of the array
+    fn is_array(&self, expression: &ast::Expression) -> bool {
+        self.variable_type(expression)
+            .is_none_or(|variable_type| is_array_type(&variable_type))
+    }
 
-        let condition = capture_regex(&REGEX_REQUIRE, line, "condition");
-        let error = capture_regex(&REGEX_REQUIRE, line, "error");
+    /// returns the name of the dynamic array state variable stored lazily, eg. `values`
+    /// of `values` or of the storage pointer to it, or None if the expression is not one
+    fn storage_array(&self, expression: &ast::Expression) -> Option<String> {
+        match expression {
+            ast::Expression::Parenthesis(inner, _) => self.storage_array(inner),
+            ast::Expression::Identifier(name, _) if self.storage_pointers.contains_key(name) => {
+                self.storage_array(&self.storage_pointers[name])
+            }
+            ast::Expression::Identifier(name, _)
+                if !self.locals.contains_key(name) && self.storage_arrays.contains(name) =>
+            {
+                Some(name.clone())
+            }
+            _ => None,
+        }
+    }
 
-        let condition = self.parse_condition(&condition.unwrap(), constructor, true, None);
-        let error_output = if constructor {
-            format!(
-                "panic!(\"{}\")",
-                error.unwrap_or_else(|| DEFAULT_ERROR.to_owned())
-            )
-        } else {
-            format!(
-                "return Err(Error::Custom(String::from(\"{}\")))",
-                error.unwrap_or_else(|| DEFAULT_ERROR.to_owned())
-            )
+    /// returns the field storing the elements of the lazy storage array, or its length
+    ///
+    /// `name` the name of the array
+    /// `length` if the field storing the length is returned
+    /// `constructor` if the field is accessed inside a constructor
+    fn storage_array_field(&self, name: &str, length: bool, constructor: bool) -> Expression {
+        let name = match length {
+            true => format!("{name}_length"),
+            false => name.to_owned(),
         };
-
-        Statement::Require(condition, error_output)
+        let selector = self.get_selector(constructor, &name);
+        Expression::Member(name, selector)
     }
 
-    /// Parses a solidity condition which is not enclosed in curly brackets
+    /// Converts the index of the lazy storage array to `u32`, the key of its mapping
     ///
-    /// `line_raw` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
-    ///
-    /// Return the statement in form of `Statement::If` or `Statement::ElseIf` or `Statement::Else`
-    fn parse_condition_one_line(
+    /// `index` the syntax tree of the index
+    /// `constructor` if the index is inside a constructor
+    fn parse_storage_array_index(
         &mut self,
-        line: &str,
+        index: &ast::Expression,
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let keyword = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "keyword").unwrap();
-        let condition_raw = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "condition").unwrap();
-        let then_raw = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "then").unwrap();
-        let then = self.parse_statement(&then_raw, constructor, stack, iterator);
-        let statements = vec![then];
-
-        let condition = if keyword == "if" || keyword == "else if" {
-            Some(self.parse_condition(&condition_raw, constructor, false, None))
-        } else {
-            None
-        };
-
-        match condition {
-            Some(condition) => {
-                if keyword == "if" {
-                    Statement::If(condition, statements)
-                } else {
-                    Statement::ElseIf(condition, statements)
-                }
+    ) -> Expression {
+        let wide = self.is_wide(index);
+        match self.parse_expression(index, constructor) {
+            Expression::Literal(number) if is_integer(&number) => Expression::Literal(number),
+            index if wide => {
+                Expression::WithSelector(
+                    bx!(index),
+                    bx!(Expression::Literal(String::from("as_u32()"))),
+                )
             }
-            None => Statement::Else(statements),
+            index => Expression::Cast(false, String::from("u32"), bx!(index)),
         }
     }
 
-    /// Parses a solidity if statement and the statements inside the if block
-    ///
-    /// `line` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
-    ///
-    /// Return the statement in form of `Statement::If`
-    fn parse_if(
-        &mut self,
-        line: &str,
-        constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let condition_raw = capture_regex(&REGEX_IF, line, "condition").unwrap();
-        let condition = self.parse_condition(&condition_raw, constructor, false, None);
-        let mut statements = Vec::default();
-
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::IfEnd,
-        );
+    /// returns true if the expression is a value of an enum
+    fn is_enum(&self, expression: &ast::Expression) -> bool {
+        self.variable_type(expression)
+            .is_some_and(|variable_type| self.enums.contains_key(&variable_type))
+    }
 
-        Statement::If(condition, statements)
+    /// Converts the index of an array to `usize`
+    ///
+    /// `index` the syntax tree of the index
+    /// `constructor` if the index is inside a constructor
+    fn parse_array_index(&mut self, index: &ast::Expression, constructor: bool) -> Expression {
+        let wide = self.is_wide(index);
+        match self.parse_expression(index, constructor) {
+            Expression::Literal(number) if is_integer(&number) => Expression::Literal(number),
+            // the length of an array is `usize` already
+            Expression::Cast(_, _, length)
+                if matches!(length.as_ref(), Expression::WithSelector(_, len)
+                    if matches!(len.as_ref(), Expression::Literal(len) if len == "len()")) =>
+            {
+                *length
+            }
+            index if wide => {
+                Expression::WithSelector(
+                    bx!(index),
+                    bx!(Expression::Literal(String::from("as_usize()"))),
+                )
+            }
+            index => Expression::Cast(false, String::from("usize"), bx!(index)),
+        }
     }
 
-    /// Parses a solidity else statement and the statements inside the else block
+    /// Converts the `push` and `pop` functions of an array
     ///
-    /// `line` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// The arrays stored in mappings are loaded from the mapping, modified and stored back
     ///
-    /// Return the statement in form of `Statement::ElseIf`
-    fn parse_else_if(
+    /// `array` the syntax tree of the array
+    /// `function` `push` or `pop`
+    /// `args_raw` the pushed value, if any
+    /// `constructor` if the call is inside a constructor
+    fn parse_array_function(
         &mut self,
-        line: &str,
+        array: &ast::Expression,
+        function: &str,
+        args_raw: &[ast::Expression],
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let condition_raw = capture_regex(&REGEX_ELSE_IF, line, "condition");
-        let condition = self.parse_condition(&condition_raw.unwrap(), constructor, false, None);
-        let mut statements = Vec::default();
-
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::IfEnd,
-        );
-
-        Statement::ElseIf(condition, statements)
+    ) -> Expression {
+        if let Some(name) = self.storage_array(array) {
+            return self.parse_storage_array_function(&name, function, args_raw, constructor)
+        }
+        let element_type = self
+            .variable_type(array)
+            .and_then(|array| element_type(&array));
+        let array = self.parse_expression(array, constructor);
+        let mut args = Vec::new();
+        let code = match (function, args_raw) {
+            ("push", []) => String::from("{array}.push(Default::default())"),
+            ("push", [value]) => {
+                // the bytes are pushed as `u8`, eg. `data.push(0x01)`
+                let byte = fold_constant(value, &self.constants)
+                    .ok()
+                    .filter(|_| element_type.as_deref() == Some("u8"))
+                    .and_then(|byte| render_constant(&byte, "u8"));
+                args.push(match byte {
+                    Some(byte) => Expression::Literal(byte),
+                    None => self.parse_expression(value, constructor),
+                });
+                String::from("{array}.push($1)")
+            }
+            _ if constructor => String::from("{array}.pop().expect(\"Pop from an empty array\")"),
+            _ => {
+                String::from(
+                    "{array}.pop().ok_or(Error::Custom(String::from(\"Pop from an empty array\")))\
+                     ?",
+                )
+            }
+        };
+        let code = code.replace("{array}", "$0");
+        match take_mapping(&array, "array") {
+            Some((Expression::Mapping(mapping, indices, None), local)) => {
+                let read = Expression::Mapping(mapping.clone(), indices.clone(), None);
+                let array = Expression::Literal(String::from("array"));
+                let stored = Expression::Mapping(mapping, indices, Some(bx!(array)));
+                let (read_index, stored_index) = (args.len() + 1, args.len() + 2);
+                let code = if function == "push" {
+                    format!("{{ let mut array = ${read_index}; {code}; ${stored_index}; }}")
+                } else {
+                    format!(
+                        "{{ let mut array = ${read_index}; let value = {code}; \
+                         ${stored_index}; value }}"
+                    )
+                };
+                args.insert(0, local);
+                args.extend([read, stored]);
+                Expression::Builtin(code, args)
+            }
+            _ => {
+                args.insert(0, array);
+                Expression::Builtin(code, args)
+            }
+        }
     }
 
-    /// Parses a solidity else statement and the statements inside the else block
+    /// Converts the `push` and `pop` functions of the lazy storage array, the element is stored
+    /// at the index of the length or removed from it
     ///
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
-    ///
-    /// Return the statement in form of `Statement::Else`
-    fn parse_else(
+    /// `name` the name of the array
+    /// `function` `push` or `pop`
+    /// `args_raw` the pushed value, if any
+    /// `constructor` if the call is inside a constructor
+    fn parse_storage_array_function(
         &mut self,
+        name: &str,
+        function: &str,
+        args_raw: &[ast::Expression],
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let mut statements = Vec::default();
-
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::IfEnd,
-        );
-
-        Statement::Else(statements)
+    ) -> Expression {
+        let elements = self.storage_array_field(name, false, constructor);
+        let length = self.storage_array_field(name, true, constructor);
+        if function == "push" {
+            let value = match args_raw {
+                [value] => self.parse_expression(value, constructor),
+                _ => Expression::Literal(String::from("Default::default()")),
+            };
+            let code = String::from("{ $0.insert(&$1, &($2)); $1 += 1; }");
+            return Expression::Builtin(code, vec![elements, length, value])
+        }
+        let error = match constructor {
+            true => String::from("expect(\"Pop from an empty array\")"),
+            false => {
+                String::from("ok_or(Error::Custom(String::from(\"Pop from an empty array\")))?")
+            }
+        };
+        let code = format!("{{ $1 = $1.checked_sub(1).{error}; $0.remove(&$1); }}");
+        Expression::Builtin(code, vec![elements, length])
     }
 
-    /// Parses a solidity condition and returns it as `Condition` struct
+    /// Converts the syntax tree of a Solidity library
     ///
-    /// `line` the raw representation of the solidity condition
-    /// `constructor` if the condition is inside a constructor
-    /// `inverted` if the condition is inverted (for example in require statements)
-    /// `enclosed_expressions` the previously parsed enclosed expressions
+    /// `definition` the syntax tree of the library
+    ///
+    /// returns the representation of the library as `Library` struct
+    fn parse_library(&mut self, definition: &ast::ContractDefinition) -> Library {
+        self.origin = definition.name.clone();
+        self.constants.clear();
+        let mut fields = Vec::<ContractField>::new();
+        let mut events = Vec::<Event>::new();
+        let mut errors = Vec::<CustomError>::new();
+        let mut enums = Vec::<Enum>::new();
+        let mut structs = Vec::<Struct>::new();
+        let mut functions = Vec::<Function>::new();
+
+        for part in definition.parts.iter() {
+            match part {
+                ast::ContractPart::Event(definition) => {
+                    let event = self.parse_event(definition);
+                    self.events.insert(event.name.clone(), event.clone());
+                    events.push(event);
+                }
+                ast::ContractPart::Enum(definition) => enums.push(parse_enum(definition)),
+                ast::ContractPart::Struct(definition) => structs.push(self.parse_struct(definition)),
+                ast::ContractPart::Function(definition) => {
+                    functions.push(self.parse_function(definition))
+                }
+                ast::ContractPart::Using(directive) => {
+                    if let Some(library) = &directive.library {
+                        self.use_library(library);
+                    }
+                }
+                ast::ContractPart::Variable(definition)
+                    if matches!(definition.type_name, ast::TypeName::Function(..)) =>
+                {
+                    self.skip_function_field(definition)
+                }
+                ast::ContractPart::Variable(definition) => {
+                    fields.push(self.parse_contract_field(definition))
+                }
+                ast::ContractPart::Error(definition) => errors.push(self.parse_error(definition)),
+            }
+        }
+
+        mangle_overloads(
+            functions.iter_mut().map(|function| &mut function.header),
+            self.mangling,
+            Selectors::Ink,
+        );
+        // library functions are free functions, so they are called without a selector
+        self.libraries.insert(
+            definition.name.clone(),
+            functions
+                .iter()
+                .map(|function| function.header.solidity_name().to_owned())
+                .collect(),
+        );
+        self.library_references.extend(library_references(
+            &definition.name,
+            functions.iter().map(|function| &function.header),
+        ));
+        // the functions of the library call each other without the name of the library
+        let overloads = collect_overloads(functions.iter().map(|function| &function.header));
+        let contract_overloads = mem::replace(&mut self.overloads, overloads.clone());
+        for (name, overloads) in overloads {
+            self.overloads
+                .insert(format!("{}.{name}", definition.name), overloads);
+        }
+        for field in fields.iter() {
+            self.storage.insert(field.name.clone(), field.clone());
+        }
+        for structure in structs.iter() {
+            self.structs
+                .insert(structure.name.clone(), structure.clone());
+        }
+        add_used_errors(
+            &mut errors,
+            &self.definitions.errors,
+            functions.iter().flat_map(|function| &function.body),
+        );
+        for error in errors.iter() {
+            self.errors.insert(error.name.clone(), error.clone());
+        }
+
+        for function in functions.iter_mut() {
+            self.enter_function(&function.header);
+            self.references = function
+                .header
+                .params
+                .iter()
+                .filter(|param| param.storage)
+                .map(|param| param.name.clone())
+                .collect();
+            function.body = self.parse_statements(&function.body, false);
+        }
+        self.references.clear();
+
+        // constants of the library are not members of the contract using it
+        for field in fields.iter() {
+            self.storage.remove(&field.name);
+        }
+        self.using.clear();
+        self.overloads = contract_overloads;
+
+        Library {
+            name: definition.name.clone(),
+            fields,
+            events,
+            errors,
+            enums,
+            structs,
+            functions,
+            imports: self.imports.clone(),
+            comments: definition.comments.clone(),
+            report: mem::take(&mut self.report),
+        }
+    }
+
+    /// Functions of the library called as members of a value are then parsed as library calls
+    ///
+    /// `library` the name of the library
+    fn use_library(&mut self, library: &String) {
+        if let Some(functions) = self.libraries.get(library) {
+            for function in functions.iter() {
+                self.using.insert(function.clone(), library.clone());
+            }
+        }
+    }
+
+    /// Converts the syntax tree of a Solidity interface
+    ///
+    /// `definition` the syntax tree of the interface
+    ///
+    /// returns the representation of the interface as `Interface` struct
+    pub fn parse_interface(&mut self, definition: &ast::ContractDefinition) -> Interface {
+        self.origin = definition.name.clone();
+        let mut events = Vec::<Event>::new();
+        let mut errors = Vec::<CustomError>::new();
+        let mut enums = Vec::<Enum>::new();
+        let mut structs = Vec::<Struct>::new();
+        let mut function_headers = Vec::<FunctionHeader>::new();
+
+        for part in definition.parts.iter() {
+            match part {
+                ast::ContractPart::Event(definition) => events.push(self.parse_event(definition)),
+                ast::ContractPart::Error(definition) => errors.push(self.parse_error(definition)),
+                ast::ContractPart::Enum(definition) => enums.push(parse_enum(definition)),
+                ast::ContractPart::Struct(definition) => structs.push(self.parse_struct(definition)),
+                ast::ContractPart::Function(definition)
+                    if definition.kind == ast::FunctionKind::Function =>
+                {
+                    function_headers.push(self.parse_function_header(definition))
+                }
+                _ => {}
+            }
+        }
+        mangle_overloads(function_headers.iter_mut(), self.mangling, self.selectors);
+
+        // the crate of the interface is named after it, so the prefix stays if the name
+        // without it names a contract, eg. `IVault` of `contract Vault`
+        let name = match interface_name(&definition.name) {
+            name if self.local.contains_key(&name)
+                || self.definitions.contracts.contains_key(&name) =>
+            {
+                definition.name.clone()
+            }
+            name => name,
+        };
+        let interface = Interface {
+            name,
+            events,
+            errors,
+            enums,
+            structs,
+            function_headers,
+            imports: self.imports.clone(),
+            comments: definition.comments.clone(),
+            report: mem::take(&mut self.report),
+        };
+        self.definitions
+            .interfaces
+            .insert(definition.name.clone(), interface.clone());
+
+        interface
+    }
+
+    /// Reports the state variable of a function type, the storage of ink! can not hold
+    /// the functions, so the state variable is skipped
+    ///
+    /// `definition` the syntax tree of the state variable
+    fn skip_function_field(&mut self, definition: &ast::VariableDefinition) {
+        self.warn(
+            Category::UnsupportedDefinition,
+            format!(
+                "state variable `{}` of the function type `{}` is not supported, skipping",
+                definition.name, definition.type_name
+            ),
+            definition.span,
+        );
+    }
+
+    /// Converts a state variable of the contract
+    ///
+    /// `definition` the syntax tree of the state variable
+    ///
+    /// returns the representation of contract field as `ContractField` struct
+    fn parse_contract_field(&mut self, definition: &ast::VariableDefinition) -> ContractField {
+        let mut field_type = self.convert_variable_type(&definition.type_name, definition.span);
+        if let ast::TypeName::UserDefined(path) = &definition.type_name {
+            match path.last() {
+                Some(name) if self.definitions.interfaces.contains_key(name) => {
+                    self.interface_fields
+                        .insert(definition.name.clone(), name.clone());
+                }
+                _ => {}
+            }
+        }
+        // the dynamic arrays are stored lazily as a mapping of the indices and a length,
+        // `Vec<T>` of the storage of ink! is loaded and stored whole
+        match &definition.type_name {
+            ast::TypeName::Array(element, None) if definition.value.is_none() => {
+                let mapping = ast::TypeName::Mapping(
+                    bx!(ast::TypeName::Elementary(String::from("uint32"))),
+                    element.clone(),
+                );
+                field_type = self.convert_variable_type(&mapping, definition.span);
+                self.storage_arrays.insert(definition.name.clone());
+                self.warn(
+                    Category::StorageArray,
+                    format!(
+                        "`{}` is stored as `{field_type}` and its length, the indices are not \
+                         checked against the length and the whole array can not be read \
+                         or assigned",
+                        definition.name
+                    ),
+                    definition.span,
+                );
+            }
+            ast::TypeName::Array(_, None) => {
+                self.warn(
+                    Category::StorageArray,
+                    format!(
+                        "`{}` is transpiled to `Vec`, the whole array is loaded and stored \
+                         with the storage of the contract",
+                        definition.name
+                    ),
+                    definition.span,
+                );
+            }
+            _ => {}
+        }
+        // `U256` of `primitive-types` does not implement the storage traits of ink!
+        if !definition.constant && field_type.contains("U256") {
+            self.warn(
+                Category::U256Storage,
+                format!(
+                    "`{}` is stored as `U256`, which does not implement the storage traits \
+                     of ink!, the contract builds only without the `std` feature",
+                    definition.name
+                ),
+                definition.span,
+            );
+        }
+        // the values of constants and immutables are folded if they are known to the transpiler,
+        // eg. `keccak256("MINTER_ROLE")`, as the Rust constants can not call the functions
+        let folded = match &definition.value {
+            Some(value) if definition.constant || definition.immutable => {
+                self.fold_field(definition, value, &field_type)
+            }
+            _ => None,
+        };
+        let initial_value = match folded {
+            Some(value) => Some(Expression::Literal(value)),
+            None => {
+                definition.value.as_ref().map(|value| {
+                    // the state variables are initialized by the constructor
+                    match field_type.as_str() {
+                        "U256" => self.parse_wide(value, !definition.constant),
+                        _ => self.parse_expression(value, !definition.constant),
+                    }
+                })
+            }
+        };
+
+        ContractField {
+            field_type,
+            name: definition.name.clone(),
+            comments: definition.comments.clone(),
+            initial_value,
+            constant: definition.constant,
+            immutable: definition.immutable,
+            visibility: convert_visibility(definition.visibility),
+        }
+    }
+
+    /// Folds the value of the constant or immutable state variable to a Rust literal
+    ///
+    /// The values which do not compile with solc, eg. a division by zero, are reported
+    /// as errors, the constants which can not be folded are reported
+    ///
+    /// `definition` the syntax tree of the state variable
+    /// `value` the syntax tree of its value
+    /// `field_type` the ink! type of the state variable
+    ///
+    /// returns the literal of the value, or None if it is not folded
+    fn fold_field(
+        &mut self,
+        definition: &ast::VariableDefinition,
+        value: &ast::Expression,
+        field_type: &str,
+    ) -> Option<String> {
+        let name = &definition.name;
+        let folded = match fold_constant(value, &self.constants) {
+            Ok(folded) => folded,
+            Err(FoldError::Invalid(message)) => {
+                self.error(format!("{message} in the value of `{name}`"), value.span());
+                return None
+            }
+            // the immutables may be initialized by any expression
+            Err(FoldError::Unknown) if definition.immutable => return None,
+            // the constants of user defined types are enums, their values are Rust constants,
+            // eg. `State::Active`
+            Err(FoldError::Unknown)
+                if matches!(definition.type_name, ast::TypeName::UserDefined(_)) =>
+            {
+                return None
+            }
+            Err(FoldError::Unknown) => {
+                self.warn(
+                    Category::Constant,
+                    format!(
+                        "the value of `{name}` can not be computed by Sol2Ink, \
+                         it is transpiled as it is"
+                    ),
+                    value.span(),
+                );
+                return None
+            }
+        };
+        // the value has to fit the Solidity type, as solc checks it
+        if let (ast::TypeName::Elementary(solidity_type), Constant::Integer(integer, negative)) =
+            (&definition.type_name, &folded)
+        {
+            if let Some(size) = integer_size(solidity_type) {
+                let signed = !solidity_type.starts_with('u');
+                if !fits_integer(integer, *negative, size as usize, signed) {
+                    let sign = if *negative { "-" } else { "" };
+                    let message = format!(
+                        "the value `{sign}{integer}` of `{name}` does not fit `{solidity_type}`"
+                    );
+                    self.error(message, value.span());
+                    return None
+                }
+            }
+        }
+        if definition.constant {
+            self.constants.insert(name.clone(), folded.clone());
+        }
+        let rendered = render_constant(&folded, field_type);
+        if rendered.is_none() {
+            self.warn(
+                Category::Constant,
+                format!(
+                    "the value of `{name}` does not fit `{field_type}`, \
+                     it is transpiled as it is"
+                ),
+                value.span(),
+            );
+        }
+        rendered
+    }
+
+    /// Generates the getter message of a public state variable, the way Solidity does
+    ///
+    /// The keys of mappings and the indices of arrays are the parameters of the getter,
+    /// structs are returned whole instead of as tuples of their members
+    ///
+    /// `definition` the syntax tree of the state variable
+    ///
+    /// returns the getter as a function with raw statements
+    fn parse_getter(&mut self, definition: &ast::VariableDefinition) -> Function {
+        let span = definition.span;
+        let mut params = Vec::<ast::Parameter>::new();
+        let mut value = ast::Expression::Identifier(definition.name.clone(), span);
+        let mut value_type = &definition.type_name;
+        // the values of mappings are read as owned values
+        let mut owned = false;
+        loop {
+            let (key_type, next_type) = match value_type {
+                ast::TypeName::Mapping(from, to) => (from.as_ref().clone(), to),
+                ast::TypeName::Array(element, _) => {
+                    (ast::TypeName::Elementary(String::from("uint256")), element)
+                }
+                _ => break,
+            };
+            owned = matches!(value_type, ast::TypeName::Mapping(..));
+            let key = format!("arg_{}", params.len());
+            let index = ast::Expression::Identifier(key.clone(), span);
+            value = ast::Expression::Index(bx!(value), Some(bx!(index)), span);
+            params.push(ast::Parameter {
+                type_name: key_type,
+                name: Some(key),
+                storage: false,
+                span,
+            });
+            value_type = next_type;
+        }
+        // the constants are converted to owned values where they are used
+        if !owned && !definition.constant && !is_copy(value_type, &self.definitions.abi_types) {
+            let clone = ast::Expression::Member(bx!(value), String::from("clone"), span);
+            value = ast::Expression::Call(bx!(clone), Vec::new(), span);
+        }
+
+        self.parse_function(&ast::FunctionDefinition {
+            kind: ast::FunctionKind::Function,
+            name: definition.name.clone(),
+            params,
+            returns: vec![ast::Parameter {
+                type_name: value_type.clone(),
+                name: None,
+                storage: false,
+                span,
+            }],
+            visibility: Some(ast::Visibility::External),
+            mutability: Some(ast::Mutability::View),
+            is_virtual: false,
+            is_override: false,
+            modifiers: Vec::new(),
+            body: Some(vec![ast::Statement::Return(Some(value), span)]),
+            comments: definition.comments.clone(),
+            span,
+        })
+    }
+
+    /// Converts Solidity event
+    ///
+    /// `definition` the syntax tree of the event
+    ///
+    /// returns the event definition as `Event` struct
+    fn parse_event(&mut self, definition: &ast::EventDefinition) -> Event {
+        let fields = definition
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                EventField {
+                    indexed: param.indexed,
+                    field_type: self.convert_variable_type(&param.type_name, param.span),
+                    name: param.name.clone().unwrap_or_else(|| format!("arg_{i}")),
+                }
+            })
+            .collect();
+
+        Event {
+            name: definition.name.clone(),
+            fields,
+            comments: definition.comments.clone(),
+        }
+    }
+
+    /// Converts Solidity custom error
+    ///
+    /// `definition` the syntax tree of the error
+    ///
+    /// returns the error definition as `CustomError` struct
+    fn parse_error(&mut self, definition: &ast::ErrorDefinition) -> CustomError {
+        let fields = definition
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                FunctionParam {
+                    name: param.name.clone().unwrap_or_else(|| format!("arg_{i}")),
+                    param_type: self.convert_variable_type(&param.type_name, param.span),
+                    solidity_type: abi_type(&param.type_name, &HashMap::new()),
+                    storage: false,
+                }
+            })
+            .collect();
+
+        CustomError {
+            name: definition.name.clone(),
+            fields,
+            comments: definition.comments.clone(),
+        }
+    }
+
+    /// Converts Solidity structure
+    ///
+    /// `definition` the syntax tree of the struct
+    ///
+    /// returns the struct definition as `Struct` struct
+    fn parse_struct(&mut self, definition: &ast::StructDefinition) -> Struct {
+        let fields = definition
+            .fields
+            .iter()
+            .map(|field| {
+                StructField {
+                    name: field.name.clone().unwrap_or_default(),
+                    field_type: self.convert_variable_type(&field.type_name, field.span),
+                }
+            })
+            .collect();
+
+        Struct {
+            name: definition.name.clone(),
+            fields,
+            comments: definition.comments.clone(),
+            stored: false,
+            exposed: false,
+        }
+    }
+
+    /// Converts the Solidity function
+    ///
+    /// The statements of the function stay raw until the inheritance of the contract is resolved.
+    /// The `receive` and `fallback` functions are converted to messages, `receive` is payable
+    ///
+    /// `definition` the syntax tree of the function
+    ///
+    /// returns the function definition as `Function` struct
+    fn parse_function(&mut self, definition: &ast::FunctionDefinition) -> Function {
+        let mut header = self.parse_function_header(definition);
+        if matches!(definition.kind, ast::FunctionKind::Receive | ast::FunctionKind::Fallback) {
+            header.visibility = Visibility::External;
+            header.payable |= definition.kind == ast::FunctionKind::Receive;
+            self.warn(
+                Category::Fallback,
+                format!(
+                    "`{}` is transpiled to a message, ink! does not dispatch the plain transfers \
+                     and the calls of unknown selectors to it",
+                    definition.name
+                ),
+                definition.span,
+            );
+        }
+        Function {
+            header,
+            body: raw_statements(definition),
+        }
+    }
+
+    /// Converts the function header of a Solidity function
+    ///
+    /// `definition` the syntax tree of the function
+    ///
+    /// returns the representation of the function header as `FunctionHeader` struct
+    fn parse_function_header(&mut self, definition: &ast::FunctionDefinition) -> FunctionHeader {
+        let params = definition
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                FunctionParam {
+                    name: param.name.clone().unwrap_or_else(|| format!("arg_{i}")),
+                    param_type: self.convert_variable_type(&param.type_name, param.span),
+                    solidity_type: abi_type(&param.type_name, &HashMap::new()),
+                    storage: param.storage,
+                }
+            })
+            .collect();
+        let return_params = definition
+            .returns
+            .iter()
+            .map(|param| {
+                FunctionParam {
+                    name: param.name.clone().unwrap_or_else(|| String::from("_")),
+                    param_type: self.convert_variable_type(&param.type_name, param.span),
+                    solidity_type: abi_type(&param.type_name, &HashMap::new()),
+                    storage: false,
+                }
+            })
+            .collect();
+        let modifiers = definition
+            .modifiers
+            .iter()
+            .map(|modifier| {
+                let name = ast::Expression::Identifier(modifier.name.clone(), modifier.span);
+                match &modifier.args {
+                    Some(args) => {
+                        Expression::Raw(ast::Expression::Call(
+                            bx!(name),
+                            args.clone(),
+                            modifier.span,
+                        ))
+                    }
+                    None => Expression::Raw(name),
+                }
+            })
+            .collect();
+
+        let param_types = definition
+            .params
+            .iter()
+            .map(|param| abi_type(&param.type_name, &self.definitions.abi_types))
+            .collect::<Vec<_>>();
+
+        FunctionHeader {
+            name: definition.name.clone(),
+            signature: format!("{}({})", definition.name, param_types.join(",")),
+            selector: None,
+            params,
+            visibility: convert_visibility(definition.visibility),
+            view: matches!(
+                definition.mutability,
+                Some(ast::Mutability::View) | Some(ast::Mutability::Pure)
+            ),
+            payable: definition.mutability == Some(ast::Mutability::Payable),
+            return_params,
+            comments: definition.comments.clone(),
+            modifiers,
+        }
+    }
+
+    /// Converts the Solidity modifier
+    ///
+    /// The modifier executing the function more than once is reported, the function
+    /// has to be callable repeatedly
+    ///
+    /// `definition` the syntax tree of the modifier
+    ///
+    /// returns the representation of the modifier as `Modifier` struct
+    fn parse_modifier(&mut self, definition: &ast::FunctionDefinition) -> Modifier {
+        self.imports
+            .insert(String::from("use openbrush::modifier_definition;"));
+        self.imports
+            .insert(String::from("use openbrush::modifiers;"));
+        let mut placeholders = 0;
+        for statement in definition.body.iter().flatten() {
+            statement.walk(&mut |statement| {
+                if matches!(statement, ast::Statement::ModifierBody(_)) {
+                    placeholders += 1;
+                }
+            });
+        }
+        if placeholders > 1 {
+            self.warn(
+                Category::Modifier,
+                format!(
+                    "`{}` executes the function {placeholders} times, the function is passed \
+                     to the modifier as `FnMut`",
+                    definition.name
+                ),
+                definition.span,
+            );
+        }
+        Modifier {
+            header: self.parse_function_header(definition),
+            statements: raw_statements(definition),
+            comments: definition.comments.clone(),
+        }
+    }
+
+    /// Parses all modifiers of a function and returns them as a vector of function calls
+    ///
+    /// Invocations of unknown modifiers, eg. arguments of base constructors, are skipped
+    ///
+    /// `raw_modifiers` the raw modifier invocations of the function
+    ///
+    /// returns the vector of function calls of the specific modifiers
+    fn process_function_modifiers(&mut self, raw_modifiers: &[Expression]) -> Vec<Expression> {
+        let mut out = Vec::default();
+        for raw_modifier in raw_modifiers.iter() {
+            let (name, args) = match raw_modifier {
+                Expression::Raw(ast::Expression::Call(callee, args, _)) => {
+                    match callee.as_ref() {
+                        ast::Expression::Identifier(name, _) => (name, args.as_slice()),
+                        _ => continue,
+                    }
+                }
+                Expression::Raw(ast::Expression::Identifier(name, _)) => (name, &[][..]),
+                _ => continue,
+            };
+            if self.modifiers.contains_key(name) {
+                let args = args
+                    .iter()
+                    .map(|arg| self.parse_expression(arg, false))
+                    .collect();
+                out.push(Expression::FunctionCall(name.clone(), args, None, true));
+            }
+        }
+        out
+    }
+
+    /// Remembers the types of the parameters of the function, eg. so the literals used with
+    /// `U256` parameters are converted to `U256`
+    ///
+    /// `header` the header of the function or modifier whose statements are parsed next
+    fn enter_function(&mut self, header: &FunctionHeader) {
+        self.locals = header
+            .params
+            .iter()
+            .chain(header.return_params.iter())
+            .map(|param| (param.name.clone(), param.param_type.clone()))
+            .collect();
+        self.returns_wide =
+            matches!(header.return_params.as_slice(), [param] if param.param_type == "U256");
+        self.storage_pointers.clear();
+    }
+
+    /// Parses raw statements of a function or modifier and returns them in a vector of `Statement`
+    ///
+    /// `statements` the raw statements of the function or modifier
+    /// `constructor` if the function is a constructor
+    fn parse_statements(&mut self, statements: &[Statement], constructor: bool) -> Vec<Statement> {
+        let mut out = Vec::default();
+
+        for statement in statements.iter() {
+            if let Statement::Raw(statement) = statement {
+                out.push(self.parse_statement(statement, constructor));
+            }
+        }
+
+        out
+    }
+
+    /// Parses the statements of a block
+    ///
+    /// `statements` the syntax trees of the statements
+    /// `constructor` if the block is inside a constructor
+    fn parse_block(
+        &mut self,
+        statements: &[ast::Statement],
+        constructor: bool,
+    ) -> Vec<Statement> {
+        statements
+            .iter()
+            .map(|statement| self.parse_statement(statement, constructor))
+            .collect()
+    }
+
+    /// Parses a soldity statement and returns it in a form of `Statement`
+    ///
+    /// Side effects of the expressions, eg. of `i++`, are grouped with the statement
+    ///
+    /// `statement` the syntax tree of the statement
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// returns the statement as `Statement` struct
+    fn parse_statement(&mut self, statement: &ast::Statement, constructor: bool) -> Statement {
+        let preceding = mem::take(&mut self.preceding);
+        let following = mem::take(&mut self.following);
+
+        let parsed = self.convert_statement(statement, constructor);
+
+        let mut group = mem::replace(&mut self.preceding, preceding);
+        let mut following = mem::replace(&mut self.following, following);
+        if group.is_empty() && following.is_empty() {
+            return parsed
+        }
+        group.push(parsed);
+        group.append(&mut following);
+        Statement::Group(group)
+    }
+
+    fn convert_statement(&mut self, statement: &ast::Statement, constructor: bool) -> Statement {
+        match statement {
+            ast::Statement::Assembly(lines, span) => {
+                self.warn(
+                    Category::Assembly,
+                    String::from("assembly blocks are not supported, they are transpiled as comments"),
+                    *span,
+                );
+                let mut statements = vec![Statement::Comment(String::from(
+                    "Please handle assembly blocks manually >>>",
+                ))];
+                statements.extend(lines.iter().cloned().map(Statement::Comment));
+                statements.push(Statement::Comment(String::from(
+                    "<<< Please handle assembly blocks manually",
+                )));
+                Statement::Group(statements)
+            }
+            ast::Statement::Block(statements, _) => {
+                Statement::Group(self.parse_block(statements, constructor))
+            }
+            ast::Statement::Break(_) => {
+                Statement::FunctionCall(Expression::Literal(String::from("break")))
+            }
+            ast::Statement::Comment(comment, _) => Statement::Comment(comment.clone()),
+            ast::Statement::Continue(_) => {
+                Statement::FunctionCall(Expression::Literal(String::from("continue")))
+            }
+            ast::Statement::DoWhile(statements, condition, _) => {
+                let mut statements = self.parse_block(statements, constructor);
+                // the loop ends when the condition is not met
+                let condition = self.parse_condition(condition, constructor, true);
+                // side effects of the condition happen in every iteration, eg. `++i < n`
+                statements.append(&mut self.preceding);
+                statements.append(&mut self.following);
+                Statement::Loop(
+                    None,
+                    Expression::Condition(bx!(condition)),
+                    None,
+                    statements,
+                )
+            }
+            ast::Statement::Emit(event, _) => self.parse_emit(event, constructor),
+            ast::Statement::Expression(expression, _) => {
+                self.parse_expression_statement(expression, constructor)
+            }
+            ast::Statement::For(init, condition, next, statements, _) => {
+                let init = init
+                    .as_ref()
+                    .map(|init| bx!(self.parse_statement(init, constructor)));
+                let condition = match condition {
+                    Some(condition) => self.parse_expression(condition, constructor),
+                    None => Expression::Literal(String::from("true")),
+                };
+                let next = next
+                    .as_ref()
+                    .map(|next| bx!(self.parse_expression_statement(next, constructor)));
+                let statements = self.parse_block(statements, constructor);
+                Statement::While(init, condition, next, statements)
+            }
+            ast::Statement::If(condition, statements, else_statements, _) => {
+                let condition = self.parse_condition(condition, constructor, false);
+                let statements = self.parse_block(statements, constructor);
+                let mut group = vec![Statement::If(condition, statements)];
+                let mut else_statements = else_statements.as_ref();
+                while let Some(statements) = else_statements {
+                    match statements.as_slice() {
+                        [ast::Statement::If(condition, statements, next, _)] => {
+                            let condition = self.parse_condition(condition, constructor, false);
+                            let statements = self.parse_block(statements, constructor);
+                            group.push(Statement::ElseIf(condition, statements));
+                            else_statements = next.as_ref();
+                        }
+                        _ => {
+                            group.push(Statement::Else(self.parse_block(statements, constructor)));
+                            break
+                        }
+                    }
+                }
+                Statement::Group(group)
+            }
+            ast::Statement::ModifierBody(_) => Statement::ModifierBody,
+            ast::Statement::Return(value, _) => {
+                Statement::Return(match value {
+                    Some(value) if self.returns_wide => self.parse_wide(value, constructor),
+                    Some(value) if self.is_borrowed(value) => {
+                        Expression::WithSelector(
+                            bx!(self.parse_expression(value, constructor)),
+                            bx!(Expression::Literal(String::from("clone()"))),
+                        )
+                    }
+                    Some(value) => self.parse_expression(value, constructor),
+                    // a modifier returns the result of the function, or the default values
+                    // if the function was not executed
+                    None if self.modifier => {
+                        Expression::Literal(String::from("result.unwrap_or_default()"))
+                    }
+                    None => Expression::Literal(String::from("()")),
+                })
+            }
+            ast::Statement::Try(expression, _, returns, statements, clauses, _)
+                if self.is_external_call(expression) =>
+            {
+                self.parse_try(expression, returns, statements, clauses, constructor)
+            }
+            ast::Statement::Try(_, header, _, statements, clauses, span) => {
+                self.warn(
+                    Category::TryCatch,
+                    String::from("try/catch is not supported, the clauses need a manual review"),
+                    *span,
+                );
+                let mut try_statements = vec![Statement::Comment(remove_locations(header))];
+                try_statements.append(&mut self.parse_block(statements, constructor));
+                let mut group = vec![Statement::Try(try_statements)];
+                for clause in clauses.iter() {
+                    let mut catch_statements =
+                        vec![Statement::Comment(remove_locations(&clause.header))];
+                    catch_statements.append(&mut self.parse_block(&clause.body, constructor));
+                    group.push(Statement::Catch(catch_statements));
+                }
+                Statement::Group(group)
+            }
+            ast::Statement::Unchecked(statements, _) => {
+                let unchecked = mem::replace(&mut self.unchecked, true);
+                let group = self.parse_block(statements, constructor);
+                self.unchecked = unchecked;
+                Statement::Group(group)
+            }
+            ast::Statement::VariableDeclaration(declarations, value, _) => {
+                match declarations.as_slice() {
+                    // the storage pointer is resolved to the value it points to where it is used,
+                    // so the changes of its members are stored
+                    [Some(declaration)]
+                        if declaration.storage
+                            && value.as_ref().is_some_and(|value| self.is_storage_path(value)) =>
+                    {
+                        let declaration_type =
+                            self.convert_variable_type(&declaration.type_name, declaration.span);
+                        self.locals
+                            .insert(declaration.name.clone(), declaration_type);
+                        let value = value.as_ref().unwrap();
+                        Statement::Group(self.point_storage(&declaration.name, value, constructor))
+                    }
+                    [Some(declaration)]
+                        if matches!(declaration.type_name, ast::TypeName::Function(..)) =>
+                    {
+                        self.warn(
+                            Category::UnsupportedStatement,
+                            format!(
+                                "local variable `{}` of the function type `{}` is not supported, \
+                                 it is transpiled as a comment",
+                                declaration.name, declaration.type_name
+                            ),
+                            declaration.span,
+                        );
+                        Statement::Comment(format!("Sol2Ink Not Implemented yet: {statement}"))
+                    }
+                    [Some(declaration)] => {
+                        let declaration_type =
+                            self.convert_variable_type(&declaration.type_name, declaration.span);
+                        if declaration.storage {
+                            self.warn(
+                                Category::UnsupportedStatement,
+                                format!(
+                                    "the storage pointer `{}` is transpiled to a copy of \
+                                     the value, the changes of its members are not stored",
+                                    declaration.name
+                                ),
+                                declaration.span,
+                            );
+                        }
+                        let value = value.as_ref().map(|value| {
+                            match declaration_type.as_str() {
+                                "U256" => self.parse_wide(value, constructor),
+                                _ => {
+                                    let parsed = self.parse_expression(value, constructor);
+                                    let parsed =
+                                        self.widen_integer(value, parsed, &declaration_type);
+                                    self.convert_byte(value, parsed, &declaration_type)
+                                }
+                            }
+                        });
+                        self.locals
+                            .insert(declaration.name.clone(), declaration_type.clone());
+                        Statement::Declaration(declaration.name.clone(), declaration_type, value)
+                    }
+                    // `(bool success, ) = to.call{value: amount}("")`
+                    [Some(success), rest @ ..] if value.as_ref().is_some_and(is_value_call) => {
+                        let value = value.as_ref().unwrap();
+                        let mut declarations = vec![Statement::Declaration(
+                            success.name.clone(),
+                            String::from("bool"),
+                            Some(self.parse_expression(value, constructor)),
+                        )];
+                        // no data is returned by the transfer
+                        declarations.extend(rest.iter().flatten().map(|data| {
+                            Statement::Declaration(
+                                data.name.clone(),
+                                String::from("Vec<u8>"),
+                                Some(Expression::Literal(String::from("Vec::new()"))),
+                            )
+                        }));
+                        Statement::Group(declarations)
+                    }
+                    _ => self.not_implemented(statement),
+                }
+            }
+            ast::Statement::While(condition, statements, _) => {
+                let condition = self.parse_expression(condition, constructor);
+                let statements = self.parse_block(statements, constructor);
+                Statement::While(None, condition, None, statements)
+            }
+        }
+    }
+
+    /// Parses an expression which is used as a statement, eg. an assignment or a function call
+    ///
+    /// `expression` the syntax tree of the expression
+    /// `constructor` if the statement is inside a constructor
+    fn parse_expression_statement(
+        &mut self,
+        expression: &ast::Expression,
+        constructor: bool,
+    ) -> Statement {
+        match expression {
+            ast::Expression::Call(callee, args, _)
+                if matches!(callee.as_ref(), ast::Expression::Identifier(name, _) if name == "require")
+                    && !args.is_empty() =>
+            {
+                self.parse_require(args, constructor)
+            }
+            ast::Expression::Call(callee, args, _)
+                if matches!(callee.as_ref(), ast::Expression::Identifier(name, _) if name == "revert") =>
+            {
+                self.parse_revert(args, constructor)
+            }
+            ast::Expression::Assign(left, operation, right, _) => {
+                self.parse_assign(left, operation, right, constructor)
+            }
+            ast::Expression::Postfix(operand, operation, _)
+            | ast::Expression::Prefix(operation, operand, _)
+                if operation == "++" || operation == "--" =>
+            {
+                let wide = self.is_wide(operand);
+                let operand = self.parse_expression(operand, constructor);
+                self.increment(operand, operation, wide, constructor)
+            }
+            // the elements of the lazy storage array are removed one by one
+            ast::Expression::Prefix(operation, operand, _)
+                if operation == "delete" && self.storage_array(operand).is_some() =>
+            {
+                let name = self.storage_array(operand).unwrap();
+                let elements = self.storage_array_field(&name, false, constructor);
+                let length = self.storage_array_field(&name, true, constructor);
+                let code = "{ for index in 0..$1 { $0.remove(&index); } $1 = 0; }";
+                Statement::FunctionCall(Expression::Builtin(
+                    String::from(code),
+                    vec![elements, length],
+                ))
+            }
+            ast::Expression::Prefix(operation, operand, _) if operation == "delete" => {
+                let operand = self.parse_expression(operand, constructor);
+                let default = Expression::Literal(String::from("Default::default()"));
+                match operand {
+                    // the removed values are read as their defaults
+                    Expression::Mapping(mapping, indices, None) => {
+                        let key = match indices.len() {
+                            1 => String::from("$1"),
+                            len => {
+                                let keys = (1..=len).map(|i| format!("${i}")).collect::<Vec<_>>();
+                                format!("({})", keys.join(", "))
+                            }
+                        };
+                        let mut args = vec![*mapping];
+                        args.extend(indices);
+                        Statement::FunctionCall(Expression::Builtin(
+                            format!("$0.remove(&{key})"),
+                            args,
+                        ))
+                    }
+                    operand => store_entry(Statement::Assign(operand, default, Operation::Assign)),
+                }
+            }
+            ast::Expression::Conditional(condition, if_true, if_false, _) => {
+                let condition = self.parse_condition(condition, constructor, false);
+                let if_true = self.parse_expression_statement(if_true, constructor);
+                let if_false = self.parse_expression_statement(if_false, constructor);
+                Statement::Ternary(condition, bx!(if_true), bx!(if_false))
+            }
+            ast::Expression::Call(..) | ast::Expression::NamedCall(..) => {
+                Statement::FunctionCall(self.parse_expression(expression, constructor))
+            }
+            _ => {
+                self.warn(
+                    Category::UnsupportedStatement,
+                    String::from("this statement is not supported yet, it is transpiled as a comment"),
+                    expression.span(),
+                );
+                Statement::Comment(format!(
+                    "Sol2Ink Not Implemented yet: {expression};"
+                ))
+            }
+        }
+    }
+
+    /// Parses a require statement
+    ///
+    /// `args` the arguments of the require call, the condition and the optional error message
+    /// `constructor` whether the require is in a constructor or not
+    ///
+    /// returns the statements in form of `Statement::Require`
+    fn parse_require(&mut self, args: &[ast::Expression], constructor: bool) -> Statement {
+        self.imports
+            .insert(String::from("use ink_prelude::string::String;"));
+
+        let condition = self.parse_condition(&args[0], constructor, true);
+        // `require(condition, CustomError(args))`
+        if let Some(error) = args.get(1).filter(|error| self.is_custom_error(error)) {
+            let error = self.parse_custom_error(error, constructor);
+            return Statement::If(condition, vec![Statement::Revert(error, constructor)])
+        }
+        let error_output = if let Some(ast::Expression::Identifier(variable, _)) = args.get(1) {
+            // the error message is passed in a variable
+            let variable = variable.to_case(Snake);
+            if constructor {
+                format!("panic!(\"{{}}\", {variable})")
+            } else {
+                format!("return Err(Error::Custom({variable}))")
+            }
+        } else {
+            let error = match args.get(1) {
+                Some(ast::Expression::StringLiteral(error, _)) => {
+                    error[1..error.len() - 1].to_owned()
+                }
+                _ => DEFAULT_ERROR.to_owned(),
+            };
+            if constructor {
+                format!("panic!(\"{error}\")")
+            } else {
+                format!("return Err(Error::Custom(String::from(\"{error}\")))")
+            }
+        };
+
+        Statement::Require(condition, error_output)
+    }
+
+    /// Parses a solidity revert statement, eg. `revert("message")` or `revert CustomError(args)`
+    ///
+    /// `args` the arguments of the revert, the custom error or the optional error message
+    /// `constructor` if the revert is inside a constructor
+    ///
+    /// returns the statement in form of `Statement::Revert`
+    fn parse_revert(&mut self, args: &[ast::Expression], constructor: bool) -> Statement {
+        self.imports
+            .insert(String::from("use ink_prelude::string::String;"));
+
+        let error = match args.first() {
+            Some(error) if self.is_custom_error(error) => self.parse_custom_error(error, constructor),
+            Some(ast::Expression::StringLiteral(message, _)) => {
+                let message = &message[1..message.len() - 1];
+                Expression::Literal(format!("Error::Custom(String::from(\"{message}\"))"))
+            }
+            Some(message) => {
+                let message = self.parse_expression(message, constructor);
+                Expression::Cast(true, String::from("Error::Custom"), bx!(message))
+            }
+            None => Expression::Literal(format!("Error::Custom(String::from(\"{DEFAULT_ERROR}\"))")),
+        };
+
+        Statement::Revert(error, constructor)
+    }
+
+    /// Converts a try statement to a match of the result of the cross-contract call
+    ///
+    /// The values returned by the call are bound in the arm of the successful call,
+    /// `catch Error(string reason)` matches the `Error::Custom` returned by the called contract,
+    /// `catch Panic(uint code)` matches the trapped callee and the other clauses match
+    /// any failure. Without such a clause, the failure is reverted as in Solidity
+    ///
+    /// `expression` the syntax tree of the called interface function
+    /// `returns` the parameters of the `returns` of the try statement
+    /// `statements` the statements executed if the call succeeded
+    /// `clauses` the catch clauses of the statement
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// returns the statement as `Statement::Match`
+    fn parse_try(
+        &mut self,
+        expression: &ast::Expression,
+        returns: &[ast::Parameter],
+        statements: &[ast::Statement],
+        clauses: &[ast::CatchClause],
+        constructor: bool,
+    ) -> Statement {
+        // the result of the call is wrapped in the result of the call itself,
+        // ink! 4 wraps it in `ink::MessageResult` too
+        let ink4 = self.backend == Backend::Ink4;
+        let (invoke, env) = if ink4 {
+            ("try_invoke()", "ink::env")
+        } else {
+            ("fire()", "ink_env")
+        };
+        let result = |pattern: String| {
+            if ink4 {
+                format!("Ok(Ok({pattern}))")
+            } else {
+                format!("Ok({pattern})")
+            }
+        };
+        let call = self.parse_external_call(expression, true, constructor);
+        // the errors returned by the called contract are the errors of its interface crate
+        let error = match &call {
+            Expression::ExternalCall(wrapper, ..) => {
+                self.interfaces
+                    .values()
+                    .find(|interface| format!("{}Ref", interface.name) == *wrapper)
+                    .map_or(String::from("Error"), |interface| {
+                        format!("{}::Error", dependency_name(&interface.name))
+                    })
+            }
+            _ => String::from("Error"),
+        };
+        let call = Expression::WithSelector(bx!(call), bx!(Expression::Literal(invoke.to_owned())));
+
+        let bindings = returns
+            .iter()
+            .map(|param| {
+                param
+                    .name
+                    .as_ref()
+                    .map_or(String::from("_"), |name| name.to_case(Snake))
+            })
+            .collect::<Vec<_>>();
+        let values = match bindings.as_slice() {
+            [] => String::from("_"),
+            [value] => value.clone(),
+            _ => format!("({})", bindings.join(", ")),
+        };
+        let mut arms = vec![(
+            Expression::Literal(result(format!("Ok({values})"))),
+            self.parse_block(statements, constructor),
+        )];
+
+        let mut catch_all = None;
+        for clause in clauses.iter() {
+            let parameter = clause.parameters.first();
+            let name = parameter.and_then(|parameter| parameter.name.clone());
+            let mut body = Vec::new();
+            match clause.kind.as_deref() {
+                Some("Error") => {
+                    let reason = name.map_or(String::from("_"), |name| name.to_case(Snake));
+                    let pattern = result(format!("Err({error}::Custom({reason}))"));
+                    body.append(&mut self.parse_block(&clause.body, constructor));
+                    arms.push((Expression::Literal(pattern), body));
+                }
+                Some("Panic") => {
+                    if let (Some(name), Some(parameter)) = (name, parameter) {
+                        self.warn(
+                            Category::TryCatch,
+                            format!("the panic code is not available in ink!, `{name}` is zero"),
+                            clause.span,
+                        );
+                        let code_type =
+                            self.convert_variable_type(&parameter.type_name, clause.span);
+                        body.push(Statement::Declaration(
+                            name,
+                            code_type,
+                            Some(Expression::Literal(String::from("Default::default()"))),
+                        ));
+                    }
+                    body.append(&mut self.parse_block(&clause.body, constructor));
+                    let pattern = format!("Err({env}::Error::CalleeTrapped)");
+                    arms.push((Expression::Literal(pattern), body));
+                }
+                _ => {
+                    if let Some(name) = name {
+                        self.warn(
+                            Category::TryCatch,
+                            format!(
+                                "the revert data is not available in ink!, `{name}` is empty"
+                            ),
+                            clause.span,
+                        );
+                        self.imports
+                            .insert(String::from("use ink_prelude::vec::Vec;\n"));
+                        body.push(Statement::Declaration(
+                            name,
+                            String::from("Vec<u8>"),
+                            Some(Expression::Literal(String::from("Vec::new()"))),
+                        ));
+                    }
+                    body.append(&mut self.parse_block(&clause.body, constructor));
+                    catch_all = Some(body);
+                }
+            }
+        }
+
+        // the error of the called contract is propagated, the failed call is reverted
+        let catch_all = catch_all.unwrap_or_else(|| {
+            self.imports
+                .insert(String::from("use ink_prelude::string::String;"));
+            arms.push((
+                Expression::Literal(result(String::from("Err(error)"))),
+                vec![Statement::Revert(
+                    Expression::Literal(String::from("Error::from(error)")),
+                    constructor,
+                )],
+            ));
+            vec![Statement::Revert(
+                Expression::Literal(String::from(
+                    "Error::Custom(String::from(\"Cross-contract call failed\"))",
+                )),
+                constructor,
+            )]
+        });
+        arms.push((Expression::Literal(String::from("_")), catch_all));
+
+        Statement::Match(call, arms)
+    }
+
+    /// returns true if the expression is a call of a function of an interface,
+    /// eg. `IERC20(token).transfer(to, amount)` or `token.transfer(to, amount)`
+    /// where `token` is a state variable of an interface type
+    ///
+    /// the interfaces which are not defined are expected to be cast to the called address
+    ///
+    /// `expression` the syntax tree of the call
+    fn is_external_call(&self, expression: &ast::Expression) -> bool {
+        let base = match expression {
+            ast::Expression::Call(callee, ..) => {
+                match callee.as_ref() {
+                    ast::Expression::Member(base, ..) => base,
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        };
+        match base.as_ref() {
+            ast::Expression::Call(interface, args, _) if args.len() == 1 => {
+                match interface.as_ref() {
+                    ast::Expression::Identifier(name, _) => {
+                        self.definitions.interfaces.contains_key(name)
+                            || !(self.known_types.contains(name)
+                                || TYPES.contains_key(name.as_str())
+                                || self.functions.contains_key(name)
+                                || self.events.contains_key(name)
+                                || self.errors.contains_key(name)
+                                || name == "payable")
+                    }
+                    _ => false,
+                }
+            }
+            ast::Expression::Identifier(name, _) => {
+                self.storage.contains_key(name) && self.interface_fields.contains_key(name)
+            }
+            _ => false,
+        }
+    }
+
+    /// Converts a call of a function of an interface to a call through the OpenBrush wrapper
+    /// of the interface, eg. `IERC20(token).transfer(to, amount)` to
+    /// `ERC20Ref::transfer(&token, to, amount)?`
+    ///
+    /// The called interfaces are added to the contract, so its crate depends on their crates.
+    /// The interfaces which are not defined are reported and named as if they were defined
+    ///
+    /// `expression` the syntax tree of the call, `is_external_call` must be true
+    /// `builder` if the call builder of the function is returned instead of calling it
+    /// `constructor` if the call is inside a constructor
+    ///
+    /// returns the call as `Expression::ExternalCall`
+    fn parse_external_call(
+        &mut self,
+        expression: &ast::Expression,
+        builder: bool,
+        constructor: bool,
+    ) -> Expression {
+        let (callee, args) = match expression {
+            ast::Expression::Call(callee, args, _) => (callee, args),
+            _ => return self.unsupported_expression(expression),
+        };
+        let (base, function) = match callee.as_ref() {
+            ast::Expression::Member(base, function, _) => (base, function),
+            _ => return self.unsupported_expression(expression),
+        };
+        let (interface, address) = match base.as_ref() {
+            ast::Expression::Call(interface, address, _) => {
+                let name = match interface.as_ref() {
+                    ast::Expression::Identifier(name, _) => name.clone(),
+                    _ => return self.unsupported_expression(expression),
+                };
+                (name, self.parse_expression(&address[0], constructor))
+            }
+            ast::Expression::Identifier(name, _) => {
+                (
+                    self.interface_fields[name].clone(),
+                    self.parse_expression(base, constructor),
+                )
+            }
+            _ => return self.unsupported_expression(expression),
+        };
+        // the `I` prefix of the interface is removed, the same way as in `parse_interface`
+        let wrapper = match self.definitions.interfaces.get(&interface) {
+            Some(definition) => {
+                self.interfaces
+                    .insert(interface.clone(), definition.clone());
+                format!("{}Ref", definition.name)
+            }
+            None => {
+                self.warn(
+                    Category::UnknownType,
+                    format!("interface `{interface}` is not defined, the call needs a review"),
+                    expression.span(),
+                );
+                format!("{}Ref", interface_name(&interface))
+            }
+        };
+        let function = match self.definitions.interfaces.get(&interface) {
+            Some(definition) => {
+                let overloads = definition
+                    .function_headers
+                    .iter()
+                    .filter(|header| header.solidity_name() == function);
+                if overloads.clone().count() > 1 {
+                    self.select_overload(overloads, args)
+                } else {
+                    function.clone()
+                }
+            }
+            None => function.clone(),
+        };
+        let args = self.parse_args(args, constructor);
+
+        Expression::ExternalCall(wrapper, function, bx!(address), args, builder)
+    }
+
+    /// returns true if the argument of a revert is a custom error, not a call returning
+    /// the error message
+    ///
+    /// `error` the syntax tree of the argument
+    fn is_custom_error(&self, error: &ast::Expression) -> bool {
+        let callee = match error {
+            ast::Expression::Call(callee, ..) | ast::Expression::NamedCall(callee, ..) => callee,
+            _ => return false,
+        };
+        match callee.as_ref() {
+            ast::Expression::Identifier(name, _) => {
+                self.errors.contains_key(name)
+                    || !(TYPES.contains_key(name.as_str()) || self.functions.contains_key(name))
+            }
+            // errors defined in other contracts, eg. `revert IERC20.Unauthorized()`
+            ast::Expression::Member(_, name, _) => self.errors.contains_key(name),
+            _ => false,
+        }
+    }
+
+    /// Parses the custom error of a revert, eg. `InsufficientBalance(available, required)`
+    ///
+    /// Undefined errors are reported and reverted with their name as the message
+    ///
+    /// `error` the syntax tree of the error
+    /// `constructor` if the revert is inside a constructor
+    ///
+    /// returns the error as `Expression::CustomError`
+    fn parse_custom_error(&mut self, error: &ast::Expression, constructor: bool) -> Expression {
+        let name = match error {
+            ast::Expression::Call(callee, ..) | ast::Expression::NamedCall(callee, ..) => {
+                match callee.as_ref() {
+                    ast::Expression::Identifier(name, _) => name.clone(),
+                    ast::Expression::Member(_, name, _) => name.clone(),
+                    _ => String::new(),
+                }
+            }
+            _ => String::new(),
+        };
+        let fields = match self.errors.get(&name) {
+            Some(definition) => definition.fields.clone(),
+            None => {
+                self.warn(
+                    Category::UndefinedError,
+                    format!("error `{name}` is not defined, it is reverted as a message"),
+                    error.span(),
+                );
+                return Expression::Literal(format!("Error::Custom(String::from(\"{name}\"))"))
+            }
+        };
+
+        let args = match error {
+            ast::Expression::Call(_, args, _) => {
+                fields
+                    .iter()
+                    .zip(args.iter())
+                    .map(|(field, arg)| {
+                        Expression::StructArg(
+                            field.name.clone(),
+                            bx!(self.parse_expression(arg, constructor)),
+                        )
+                    })
+                    .collect()
+            }
+            ast::Expression::NamedCall(_, args, _) => {
+                args.iter()
+                    .map(|arg| {
+                        Expression::StructArg(
+                            arg.name.clone(),
+                            bx!(self.parse_expression(&arg.value, constructor)),
+                        )
+                    })
+                    .collect()
+            }
+            _ => Vec::default(),
+        };
+
+        Expression::CustomError(name, args)
+    }
+
+    /// Parses a solidity condition and returns it as `Condition` struct
+    ///
+    /// `expression` the syntax tree of the condition
+    /// `constructor` if the condition is inside a constructor
+    /// `inverted` if the condition is inverted (for example in require statements)
     fn parse_condition(
         &mut self,
-        line: &String,
+        expression: &ast::Expression,
+        constructor: bool,
+        inverted: bool,
+    ) -> Condition {
+        let mut expression = expression;
+        while let ast::Expression::Parenthesis(inner, _) = expression {
+            expression = inner;
+        }
+
+        let (mut left, mut operation, mut right) = match expression {
+            ast::Expression::Binary(left, operation, right, _)
+                if COMPARISONS.contains(&operation.as_str()) =>
+            {
+                let (left, right) = self.parse_operands(left, right, constructor);
+                (left, OPERATIONS[operation], Some(right))
+            }
+            ast::Expression::Prefix(operation, value, _) if operation == "!" => {
+                (self.parse_expression(value, constructor), Operation::Not, None)
+            }
+            _ => {
+                (
+                    self.parse_expression(expression, constructor),
+                    Operation::True,
+                    None,
+                )
+            }
+        };
+
+        if let Some(Expression::ZeroAddressInto) = right {
+            operation = match operation {
+                Operation::Equal => Operation::True,
+                Operation::NotEqual => Operation::Not,
+                _ => operation,
+            };
+            right = None;
+            left = Expression::IsZero(bx!(left));
+            self.imports
+                .insert(String::from("use openbrush::traits::AccountIdExt;\n"));
+        }
+
+        if inverted {
+            operation = operation.negate();
+            // the negation applies to the whole expression
+            if operation == Operation::Not
+                && matches!(
+                    left,
+                    Expression::Arithmetic(..)
+                        | Expression::Condition(..)
+                        | Expression::Logical(..)
+                        | Expression::Ternary(..)
+                )
+            {
+                left = Expression::Enclosed(bx!(left));
+            }
+        }
+
+        Condition {
+            left,
+            operation,
+            right,
+        }
+    }
+
+    /// Parses a solidity emit statement
+    ///
+    /// `event` the syntax tree of the emitted event
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// Return the statement in form of `Statement::Emit`
+    fn parse_emit(&mut self, event: &ast::Expression, constructor: bool) -> Statement {
+        let fields = match event_name(event).and_then(|name| self.events.get(&name)) {
+            Some(definition) => definition.fields.clone(),
+            None => {
+                self.warn(
+                    Category::UndefinedEvent,
+                    format!(
+                        "event `{}` is not defined, the emit is transpiled as a comment",
+                        event_name(event).unwrap_or_default()
+                    ),
+                    event.span(),
+                );
+                return Statement::Comment(format!("Sol2Ink Not Implemented yet: emit {event};"))
+            }
+        };
+        let name = event_name(event).unwrap_or_default();
+
+        let args = match event {
+            ast::Expression::Call(_, args, _) => {
+                fields
+                    .iter()
+                    .zip(args.iter())
+                    .map(|(field, arg)| {
+                        Expression::StructArg(
+                            field.name.clone(),
+                            bx!(self.parse_expression(arg, constructor)),
+                        )
+                    })
+                    .collect()
+            }
+            ast::Expression::NamedCall(_, args, _) => {
+                args.iter()
+                    .map(|arg| {
+                        Expression::StructArg(
+                            arg.name.clone(),
+                            bx!(self.parse_expression(&arg.value, constructor)),
+                        )
+                    })
+                    .collect()
+            }
+            _ => Vec::default(),
+        };
+
+        Statement::Emit(name, args)
+    }
+
+    /// Parses a solidity assignment statement
+    ///
+    /// `left` the assigned expression
+    /// `operation` the assignment operator, eg. `=` or `+=`
+    /// `right` the assigned value
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// Return the statement in form of `Statement::Assign`
+    fn parse_assign(
+        &mut self,
+        left: &ast::Expression,
+        operation: &str,
+        right: &ast::Expression,
         constructor: bool,
-        inverted: bool,
-        enclosed_expressions: Option<HashMap<String, Expression>>,
-    ) -> Condition {
-        let (mut left, mut operation, mut right) = if REGEX_BOOLEAN.is_match(line) {
-            let left_raw = capture_regex(&REGEX_BOOLEAN, line, "left").unwrap();
-            let operation_raw = capture_regex(&REGEX_BOOLEAN, line, "operation").unwrap();
-            let right_raw = capture_regex(&REGEX_BOOLEAN, line, "right").unwrap();
-
-            let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
-            let operation = *OPERATIONS.get(&operation_raw).unwrap();
-            let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
+    ) -> Statement {
+        // the storage pointer points to another value, eg. `p = proposals[other]`
+        if let ast::Expression::Identifier(name, _) = left {
+            if operation == "=" && self.storage_pointers.contains_key(name) {
+                if self.is_storage_path(right) {
+                    return Statement::Group(self.point_storage(name, right, constructor))
+                }
+                self.storage_pointers.remove(name);
+            }
+        }
+        let wide = self.is_wide(left);
+        let left_type = self.variable_type(left);
+        let left = self.parse_expression(left, constructor);
+        let right = match (wide, left_type) {
+            (true, _) => self.parse_wide(right, constructor),
+            // the shift amount keeps its type
+            (false, Some(left_type)) if !matches!(operation, "<<=" | ">>=" | ">>>=") => {
+                let parsed = self.parse_expression(right, constructor);
+                let parsed = self.widen_integer(right, parsed, &left_type);
+                match operation {
+                    "=" => self.convert_byte(right, parsed, &left_type),
+                    _ => parsed,
+                }
+            }
+            (false, _) => self.parse_expression(right, constructor),
+        };
+        // the arithmetic operation of a compound assignment, eg. `+` of `+=`
+        let arithmetic = operation
+            .strip_suffix('=')
+            .filter(|arithmetic| !arithmetic.is_empty())
+            .map(|arithmetic| {
+                match arithmetic {
+                    ">>>" => Operation::ShiftRight,
+                    _ => OPERATIONS[arithmetic],
+                }
+            });
 
-            (left, operation, Some(right))
-        } else {
-            let regex_negative = Regex::new(r#"(?x)^\s*!(?P<value>.+?)\s*$"#).unwrap();
-            if regex_negative.is_match(line) {
-                let left_raw = capture_regex(&regex_negative, line, "value").unwrap();
-                let left = self.parse_expression(&left_raw, constructor, enclosed_expressions);
-                (left, Operation::Not, None)
-            } else {
-                let left = self.parse_expression(line, constructor, enclosed_expressions);
-                (left, Operation::True, None)
+        let assign = match (left, arithmetic) {
+            (left, Some(arithmetic)) if overflows(arithmetic) => {
+                return self.assign_arithmetic(left, right, arithmetic, wide, constructor)
+            }
+            (Expression::Mapping(name, indices, None), arithmetic) => {
+                let value = match arithmetic {
+                    Some(arithmetic) => {
+                        Expression::Arithmetic(
+                            bx!(Expression::Mapping(name.clone(), indices.clone(), None)),
+                            bx!(right),
+                            arithmetic,
+                        )
+                    }
+                    None => right,
+                };
+                Statement::FunctionCall(Expression::Mapping(name, indices, Some(bx!(value))))
+            }
+            (left, Some(arithmetic)) if !OPERATIONS.contains_key(operation) => {
+                Statement::Assign(
+                    left.clone(),
+                    Expression::Arithmetic(bx!(left), bx!(right), arithmetic),
+                    Operation::Assign,
+                )
             }
+            (left, _) => Statement::Assign(left, right, OPERATIONS[operation]),
         };
+        store_entry(assign)
+    }
 
-        if let Some(Expression::ZeroAddressInto) = right {
-            operation = match operation {
-                Operation::Equal => Operation::True,
-                Operation::NotEqual => Operation::Not,
-                _ => operation,
-            };
-            right = None;
-            left = Expression::IsZero(bx!(left));
-            self.imports
-                .insert(String::from("use openbrush::traits::AccountIdExt;\n"));
+    /// Converts a byte of `bytes` to `bytes1` and back, as the elements of `bytes` are `u8`
+    /// and `bytes1` is `[u8; 1]`, eg. `bytes1 first = data[0]`
+    ///
+    /// `raw` the syntax tree of the value
+    /// `value` the parsed value
+    /// `target_type` the ink! type the value is assigned to
+    fn convert_byte(
+        &self,
+        raw: &ast::Expression,
+        value: Expression,
+        target_type: &str,
+    ) -> Expression {
+        match (self.variable_type(raw).as_deref(), target_type) {
+            (Some("u8"), "[u8; 1]") => Expression::Builtin(String::from("[$0]"), vec![value]),
+            (Some("[u8; 1]"), "u8") => Expression::Builtin(String::from("$0[0]"), vec![value]),
+            _ => value,
         }
+    }
 
-        Condition {
-            left,
-            operation: if inverted {
-                operation.negate()
-            } else {
-                operation
-            },
-            right,
+    /// Converts the integer to the wider integer type of the other operand or of the assigned
+    /// variable, as Rust does not mix the integer types, eg. `b` of `a + b` with `uint128 a`
+    /// and `uint8 b`
+    ///
+    /// `raw` the syntax tree of the value
+    /// `value` the parsed value
+    /// `target_type` the ink! type of the other operand or of the assigned variable
+    fn widen_integer(
+        &self,
+        raw: &ast::Expression,
+        value: Expression,
+        target_type: &str,
+    ) -> Expression {
+        let value_size = self.variable_type(raw).as_deref().and_then(rust_integer_size);
+        match (value_size, rust_integer_size(target_type)) {
+            (Some((signed, size)), Some((target_signed, target_size)))
+                if signed == target_signed && size < target_size =>
+            {
+                Expression::Cast(false, target_type.to_string(), bx!(value))
+            }
+            _ => value,
         }
     }
 
-    /// Parses a solidity try statement and the statements inside the try block
-    /// The try statement is inserted as a comment and the actual try statement
-    /// is rendered as if true block
+    /// returns the ink! type of the fixed size bytes, eg. `[u8; 4]` of a `bytes4` variable
+    /// or of a cast to `bytes4`, or None if the expression is not known to be fixed size bytes
     ///
-    /// `line` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// `expression` the syntax tree of the expression
+    fn bytes_type(&self, expression: &ast::Expression) -> Option<String> {
+        let bytes_type = match expression {
+            ast::Expression::Parenthesis(inner, _) => return self.bytes_type(inner),
+            ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+                match callee.to_string().as_str() {
+                    "keccak256" | "sha256" => String::from("[u8; 32]"),
+                    name if fixed_bytes_size(name).is_some() => TYPES[name].0.to_owned(),
+                    _ => return None,
+                }
+            }
+            _ => self.variable_type(expression)?,
+        };
+        fixed_bytes_size(&bytes_type).map(|_| bytes_type)
+    }
+
+    /// returns the ink! type of the integer expression, eg. `u32` of a `uint32` variable
+    /// or of a cast to `uint32`, or None if the type is not known
     ///
-    /// Return the statement in form of `Statement::Try`
-    fn parse_try(
+    /// `expression` the syntax tree of the expression
+    fn integer_type(&self, expression: &ast::Expression) -> Option<String> {
+        match expression {
+            ast::Expression::Parenthesis(inner, _) => self.integer_type(inner),
+            ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+                let name = callee.to_string();
+                match self.is_wide_int(&name) {
+                    true => Some(String::from("U256")),
+                    false => is_integer_type(&name).then(|| TYPES[name.as_str()].0.to_owned()),
+                }
+            }
+            _ => {
+                self.variable_type(expression).filter(|integer_type| {
+                    integer_type == "U256" || rust_integer_size(integer_type).is_some()
+                })
+            }
+        }
+    }
+
+    /// Converts a cast between the fixed size bytes and an integer of the same size,
+    /// the bytes are the big endian representation of the integer, eg. `uint32(bytes4 id)`
+    ///
+    /// The integer types transpiled to wider or narrower Rust integers are padded or truncated,
+    /// eg. `bytes32(uint256 value)` with `uint256` transpiled to `u128`
+    ///
+    /// `name` the type the value is cast to
+    /// `value` the syntax tree of the cast value
+    /// `expression` the syntax tree of the cast, reported if it is not supported
+    /// `constructor` if the cast is inside a constructor
+    fn convert_bytes_cast(
         &mut self,
-        line: &str,
+        name: &str,
+        value: &ast::Expression,
+        expression: &ast::Expression,
+        constructor: bool,
+    ) -> Expression {
+        let target_type = TYPES[name].0;
+        let code = match fixed_bytes_size(name) {
+            // integer to bytes
+            Some(size) => {
+                if let Some(bytes) = fold_constant(value, &self.constants)
+                    .ok()
+                    .and_then(|constant| render_constant(&constant, target_type))
+                {
+                    return Expression::Literal(bytes)
+                }
+                match self.integer_type(value).as_deref() {
+                    Some("U256") if size == 32 => {
+                        String::from(
+                            "{ let mut bytes = [0; 32]; $0.to_big_endian(&mut bytes); bytes }",
+                        )
+                    }
+                    Some(integer_type) => {
+                        match rust_integer_size(integer_type).map(|(_, bits)| bits as usize / 8) {
+                            Some(width) if width == size => String::from("$0.to_be_bytes()"),
+                            Some(width) if width > size => {
+                                format!(
+                                    "<[u8; {size}]>::try_from(&$0.to_be_bytes()[{}..]).unwrap()",
+                                    width - size
+                                )
+                            }
+                            Some(width) => {
+                                format!(
+                                    "{{ let mut bytes = [0; {size}]; \
+                                     bytes[{}..].copy_from_slice(&$0.to_be_bytes()); bytes }}",
+                                    size - width
+                                )
+                            }
+                            None => String::new(),
+                        }
+                    }
+                    None => String::new(),
+                }
+            }
+            // bytes to integer
+            None => {
+                let size = self
+                    .bytes_type(value)
+                    .as_deref()
+                    .and_then(fixed_bytes_size)
+                    .unwrap_or_default();
+                match rust_integer_size(target_type).map(|(_, bits)| bits as usize / 8) {
+                    Some(width) if width == size => format!("{target_type}::from_be_bytes($0)"),
+                    Some(width) if width < size => {
+                        format!(
+                            "{target_type}::from_be_bytes(\
+                             <[u8; {width}]>::try_from(&$0[{}..]).unwrap())",
+                            size - width
+                        )
+                    }
+                    Some(width) => {
+                        format!(
+                            "{target_type}::from_be_bytes({{ let mut bytes = [0; {width}]; \
+                             bytes[{}..].copy_from_slice(&$0); bytes }})",
+                            width - size
+                        )
+                    }
+                    None => String::new(),
+                }
+            }
+        };
+        if code.is_empty() {
+            return self.unsupported_expression_with(
+                expression,
+                format!(
+                    "the cast of `{value}` to `{name}` is not supported, \
+                     it is transpiled to `todo!`"
+                ),
+            )
+        }
+        let value = match self.parse_expression(value, constructor) {
+            value @ (Expression::Arithmetic(..)
+            | Expression::Checked(..)
+            | Expression::Wrapping(..)
+            | Expression::Ternary(..)) => Expression::Enclosed(bx!(value)),
+            value => value,
+        };
+        Expression::Builtin(code, vec![value])
+    }
+
+    /// Converts an increment or a decrement, eg. `i++`
+    ///
+    /// `operand` the incremented expression
+    /// `operation` `++` or `--`
+    /// `wide` if the operand is `U256`
+    /// `constructor` if the increment is inside a constructor
+    ///
+    /// returns the statement incrementing the operand
+    fn increment(
+        &self,
+        operand: Expression,
+        operation: &str,
+        wide: bool,
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
     ) -> Statement {
-        let mut statements = Vec::default();
-        statements.push(Statement::Comment(line.to_owned()));
+        let one = match wide {
+            true => widen(Expression::Literal(String::from("1"))),
+            false => Expression::Literal(String::from("1")),
+        };
+        let arithmetic = match operation {
+            "++" => Operation::Add,
+            _ => Operation::Subtract,
+        };
+        self.assign_arithmetic(operand, one, arithmetic, wide, constructor)
+    }
 
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::TryEnd,
-        );
+    /// Assigns the result of an arithmetic operation to its left operand, eg. of `a += b`
+    ///
+    /// `left` the assigned expression
+    /// `right` the right operand
+    /// `arithmetic` the arithmetic operation
+    /// `wide` if the operands are `U256`
+    /// `constructor` if the statement is inside a constructor
+    fn assign_arithmetic(
+        &self,
+        left: Expression,
+        right: Expression,
+        arithmetic: Operation,
+        wide: bool,
+        constructor: bool,
+    ) -> Statement {
+        let value = self.arithmetic(left.clone(), right, arithmetic, wide, constructor);
+        match left {
+            Expression::Mapping(name, indices, None) => {
+                Statement::FunctionCall(Expression::Mapping(name, indices, Some(bx!(value))))
+            }
+            left => store_entry(Statement::Assign(left, value, Operation::Assign)),
+        }
+    }
+
+    /// Converts an arithmetic operation
+    ///
+    /// Since Solidity 0.8 the operations which can overflow are checked, inside of `unchecked`
+    /// blocks and before Solidity 0.8 they wrap around. The division by zero is checked always.
+    ///
+    /// `left` the left operand
+    /// `right` the right operand
+    /// `operation` the arithmetic operation
+    /// `wide` if the operands are `U256`
+    /// `constructor` if the operation is inside a constructor
+    fn arithmetic(
+        &self,
+        left: Expression,
+        right: Expression,
+        operation: Operation,
+        wide: bool,
+        constructor: bool,
+    ) -> Expression {
+        let is_literal = |expression: &Expression| {
+            matches!(expression, Expression::Literal(number) if is_integer(number))
+        };
+        // the operations of literals are evaluated by the compiler
+        if !overflows(operation) || (is_literal(&left) && is_literal(&right)) {
+            return Expression::Arithmetic(bx!(left), bx!(right), operation)
+        }
+        // the type of a literal is inferred from the other operand
+        let (left, right) = match operation {
+            Operation::Add | Operation::Mul if is_literal(&left) => (right, left),
+            _ => (left, right),
+        };
+        match operation {
+            _ if self.checked && !self.unchecked => {
+                Expression::Checked(bx!(left), bx!(right), operation, constructor)
+            }
+            Operation::Div | Operation::Modulo => {
+                Expression::Checked(bx!(left), bx!(right), operation, constructor)
+            }
+            _ => Expression::Wrapping(bx!(left), bx!(right), operation, wide),
+        }
+    }
+
+    /// Parses a solidity expression and returns it as `Expression`
+    ///
+    /// Increments and decrements inside of the expression are added to the side effects
+    /// of the currently parsed statement
+    ///
+    /// `expression` the syntax tree of the expression
+    /// `constructor` if the expression is inside a constructor
+    fn parse_expression(&mut self, expression: &ast::Expression, constructor: bool) -> Expression {
+        match expression {
+            ast::Expression::BoolLiteral(value, _) => Expression::Literal(value.to_string()),
+            ast::Expression::NumberLiteral(number, _, _) if number.starts_with("0x") => {
+                Expression::Cast(
+                    true,
+                    String::from("&hex::decode"),
+                    bx!(Expression::Literal(format!("\"{number}\""))),
+                )
+            }
+            ast::Expression::NumberLiteral(number, unit, _) => {
+                let number = convert_number(number, unit.as_deref());
+                if self.u256 && is_integer(&number) && number.parse::<u128>().is_err() {
+                    self.imports.insert(String::from(U256_IMPORT));
+                    return Expression::Literal(format!("U256::from_dec_str(\"{number}\").unwrap()"))
+                }
+                Expression::Literal(number)
+            }
+            ast::Expression::HexLiteral(content, _) => {
+                Expression::Cast(
+                    true,
+                    String::from("&hex::decode"),
+                    bx!(Expression::Literal(format!("\"{content}\""))),
+                )
+            }
+            ast::Expression::StringLiteral(content, _) => {
+                Expression::Literal(match content.strip_prefix('\'') {
+                    Some(content) => format!("\"{}\"", content.trim_end_matches('\'')),
+                    None => content.clone(),
+                })
+            }
+            ast::Expression::Identifier(name, _)
+                if (name == "this" || name == "now") && !self.storage.contains_key(name) =>
+            {
+                self.parse_builtin(name, &[], expression, constructor)
+            }
+            ast::Expression::Identifier(name, _) if self.storage_pointers.contains_key(name) => {
+                let value = self.storage_pointers[name].clone();
+                self.parse_expression(&value, constructor)
+            }
+            ast::Expression::Identifier(name, _) if self.storage_array(expression).is_some() => {
+                self.unsupported_expression_with(
+                    expression,
+                    format!(
+                        "the storage array `{name}` is stored lazily, the whole array can not be \
+                         read or assigned, it is transpiled to `todo!`"
+                    ),
+                )
+            }
+            ast::Expression::Identifier(name, _) => {
+                if self.is_wide_int(name) {
+                    return Expression::Literal(self.wide_int())
+                }
+                if let Some(the_type) = TYPES.get(name.as_str()) {
+                    return Expression::Literal(the_type.0.to_owned())
+                }
+                if let Some(contract_field) = self.storage.get(name) {
+                    if contract_field.constant {
+                        let constant = Expression::Constant(contract_field.name.clone());
+                        // the constants are stored as the types allowed in `const`
+                        let conversion = match contract_field.field_type.as_str() {
+                            "String" => "String::from($0)",
+                            "AccountId" => "AccountId::from($0)",
+                            _ => return constant,
+                        };
+                        return Expression::Builtin(String::from(conversion), vec![constant])
+                    }
+                }
+                Expression::Member(name.clone(), self.get_selector(constructor, name))
+            }
+            ast::Expression::Member(base, member, _) => {
+                match (base.as_ref(), member.as_str()) {
+                    (ast::Expression::Identifier(name, _), "sender") if name == "msg" => {
+                        Expression::EnvCaller(Some(selector!(self, constructor)))
+                    }
+                    (ast::Expression::Identifier(name, _), "value") if name == "msg" => {
+                        Expression::TransferredValue(Some(selector!(self, constructor)))
+                    }
+                    (ast::Expression::Identifier(name, _), _)
+                        if matches!(name.as_str(), "block" | "msg" | "tx")
+                            && BUILTINS.contains_key(format!("{name}.{member}").as_str()) =>
+                    {
+                        let name = format!("{name}.{member}");
+                        self.parse_builtin(&name, &[], expression, constructor)
+                    }
+                    (ast::Expression::Identifier(name, _), "balance") if name == "this" => {
+                        self.parse_builtin("address(this).balance", &[], expression, constructor)
+                    }
+                    (ast::Expression::Call(callee, args, _), "balance")
+                        if callee.to_string() == "address" && args.len() == 1 =>
+                    {
+                        let name = match &args[0] {
+                            ast::Expression::Identifier(name, _) if name == "this" => {
+                                "address(this).balance"
+                            }
+                            _ => "address.balance",
+                        };
+                        self.parse_builtin(name, &[], expression, constructor)
+                    }
+                    // `type(T).max` of an integer type
+                    (ast::Expression::Call(callee, args, _), "max" | "min")
+                        if matches!(callee.as_ref(), ast::Expression::Identifier(name, _) if name == "type")
+                            && matches!(args.as_slice(), [ast::Expression::Identifier(name, _)] if is_integer_type(name)) =>
+                    {
+                        let name = match &args[0] {
+                            ast::Expression::Identifier(name, _) => name,
+                            _ => unreachable!(),
+                        };
+                        if self.is_wide_int(name) {
+                            let bound = match member.as_str() {
+                                "max" => "MAX",
+                                _ => "zero()",
+                            };
+                            return Expression::Literal(format!("{}::{bound}", self.wide_int()))
+                        }
+                        Expression::Literal(format!(
+                            "{}::{}",
+                            TYPES[name.as_str()].0,
+                            member.to_uppercase()
+                        ))
+                    }
+                    (_, "length") if self.storage_array(base).is_some() => {
+                        let name = self.storage_array(base).unwrap();
+                        let length = self.storage_array_field(&name, true, constructor);
+                        if self.u256 {
+                            let wide_int = self.wide_int();
+                            Expression::Cast(true, format!("{wide_int}::from"), bx!(length))
+                        } else {
+                            Expression::Cast(false, String::from("u128"), bx!(length))
+                        }
+                    }
+                    (_, "length") if self.is_array(base) => {
+                        let length = Expression::WithSelector(
+                            bx!(self.parse_expression(base, constructor)),
+                            bx!(Expression::Literal(String::from("len()"))),
+                        );
+                        if self.u256 {
+                            let wide_int = self.wide_int();
+                            Expression::Cast(true, format!("{wide_int}::from"), bx!(length))
+                        } else {
+                            Expression::Cast(false, String::from("u128"), bx!(length))
+                        }
+                    }
+                    (ast::Expression::Identifier(name, _), _) if self.enums.contains_key(name) => {
+                        Expression::Literal(format!(
+                            "{}::{}",
+                            name.to_case(Pascal),
+                            member.to_case(Pascal)
+                        ))
+                    }
+                    // `type(T).name`
+                    (ast::Expression::Call(callee, args, _), _)
+                        if matches!(callee.as_ref(), ast::Expression::Identifier(name, _) if name == "type")
+                            && args.len() == 1 =>
+                    {
+                        Expression::WithSelector(
+                            bx!(self.parse_expression(&args[0], constructor)),
+                            bx!(Expression::Member(member.clone(), None)),
+                        )
+                    }
+                    _ => {
+                        Expression::WithSelector(
+                            bx!(self.parse_expression(base, constructor)),
+                            bx!(Expression::Member(member.clone(), None)),
+                        )
+                    }
+                }
+            }
+            ast::Expression::Call(callee, args, _) => {
+                self.parse_function_call(expression, callee, args, constructor)
+            }
+            ast::Expression::NamedCall(callee, args, _) => {
+                match callee.as_ref() {
+                    ast::Expression::Identifier(name, _) => {
+                        let args = args
+                            .iter()
+                            .map(|arg| {
+                                Expression::StructArg(
+                                    arg.name.clone(),
+                                    bx!(self.parse_expression(&arg.value, constructor)),
+                                )
+                            })
+                            .collect();
+                        Expression::StructInit(name.clone(), args)
+                    }
+                    _ => self.unsupported_expression(expression),
+                }
+            }
+            ast::Expression::Index(array, Some(index), _)
+                if self.storage_array(array).is_some() =>
+            {
+                let name = self.storage_array(array).unwrap();
+                Expression::Mapping(
+                    bx!(self.storage_array_field(&name, false, constructor)),
+                    vec![self.parse_storage_array_index(index, constructor)],
+                    None,
+                )
+            }
+            ast::Expression::Index(array, Some(index), _)
+                if self.variable_type(array).is_some_and(|array| is_array_type(&array)) =>
+            {
+                Expression::Index(
+                    bx!(self.parse_expression(array, constructor)),
+                    bx!(self.parse_array_index(index, constructor)),
+                )
+            }
+            ast::Expression::Index(..) => {
+                let mut indices = Vec::new();
+                let mut mapping = expression;
+                while let ast::Expression::Index(inner, Some(index), _) = mapping {
+                    indices.insert(0, self.parse_expression(index, constructor));
+                    mapping = inner;
+                    // the keys of the nested mappings are flattened into a tuple
+                    let nested = matches!(mapping, ast::Expression::Index(..))
+                        && self
+                            .variable_type(mapping)
+                            .is_none_or(|mapping| mapping.starts_with("Mapping<"));
+                    if !nested {
+                        break
+                    }
+                }
+                if indices.is_empty() {
+                    return self.unsupported_expression(expression)
+                }
+                // the mapping of a stored struct is keyed by the keys of the struct too
+                if let ast::Expression::Member(parent, member, _) = mapping {
+                    if let Some((field_name, keys, array)) = self.struct_mapping(parent, member) {
+                        // the index of a storage array is `u32`
+                        let mut struct_keys = keys
+                            .iter()
+                            .enumerate()
+                            .map(|(i, key)| {
+                                if array && i == 0 {
+                                    self.parse_storage_array_index(key, constructor)
+                                } else {
+                                    self.parse_expression(key, constructor)
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        struct_keys.append(&mut indices);
+                        let field = Expression::Member(
+                            field_name.clone(),
+                            self.get_selector(constructor, &field_name),
+                        );
+                        return Expression::Mapping(bx!(field), struct_keys, None)
+                    }
+                }
+                Expression::Mapping(bx!(self.parse_expression(mapping, constructor)), indices, None)
+            }
+            ast::Expression::Binary(left, operation, right, _) => {
+                match operation.as_str() {
+                    "&&" | "||" => {
+                        Expression::Logical(
+                            bx!(self.parse_expression(left, constructor)),
+                            OPERATIONS[operation],
+                            bx!(self.parse_expression(right, constructor)),
+                        )
+                    }
+                    _ if COMPARISONS.contains(&operation.as_str()) => {
+                        Expression::Condition(bx!(self.parse_condition(
+                            expression,
+                            constructor,
+                            false
+                        )))
+                    }
+                    // the shift amount keeps its type
+                    "<<" | ">>" | ">>>" => {
+                        let operation = match operation.as_str() {
+                            ">>>" => Operation::ShiftRight,
+                            operation => OPERATIONS[operation],
+                        };
+                        Expression::Arithmetic(
+                            bx!(self.parse_expression(left, constructor)),
+                            bx!(self.parse_expression(right, constructor)),
+                            operation,
+                        )
+                    }
+                    // the exponent of a `U256` is a `U256` too
+                    "**" if self.is_wide(left) => {
+                        let left = self.parse_expression(left, constructor);
+                        let right = Expression::Cast(
+                            true,
+                            String::from("U256::from"),
+                            bx!(self.parse_expression(right, constructor)),
+                        );
+                        self.arithmetic(left, right, Operation::Pow, true, constructor)
+                    }
+                    _ => {
+                        let wide = self.is_wide(expression);
+                        let (left, right) = self.parse_operands(left, right, constructor);
+                        // the literal base of a power is a `uint256` since Solidity 0.7,
+                        // so its methods can be called
+                        let left = match left {
+                            Expression::Literal(number)
+                                if operation == "**"
+                                    && is_integer(&number)
+                                    && !matches!(&right, Expression::Literal(_)) =>
+                            {
+                                Expression::Literal(format!("{number}_u128"))
+                            }
+                            left => left,
+                        };
+                        self.arithmetic(left, right, OPERATIONS[operation], wide, constructor)
+                    }
+                }
+            }
+            ast::Expression::Prefix(operation, value, _) => {
+                match operation.as_str() {
+                    "!" | "~" => {
+                        Expression::Condition(bx!(Condition {
+                            left: self.parse_expression(value, constructor),
+                            operation: Operation::Not,
+                            right: None,
+                        }))
+                    }
+                    "-" => {
+                        match value.as_ref() {
+                            ast::Expression::NumberLiteral(number, unit, _)
+                                if !number.starts_with("0x") =>
+                            {
+                                Expression::Literal(format!(
+                                    "-{}",
+                                    convert_number(number, unit.as_deref())
+                                ))
+                            }
+                            // the negation of the minimal signed integer overflows
+                            _ => {
+                                let value = bx!(self.parse_expression(value, constructor));
+                                let none = bx!(Expression::Literal(String::new()));
+                                let negation = Operation::Neg;
+                                match self.checked && !self.unchecked {
+                                    true => Expression::Checked(value, none, negation, constructor),
+                                    false => Expression::Wrapping(value, none, negation, false),
+                                }
+                            }
+                        }
+                    }
+                    "++" | "--" => {
+                        let wide = self.is_wide(value);
+                        let value = self.parse_expression(value, constructor);
+                        let increment = self.increment(value.clone(), operation, wide, constructor);
+                        self.preceding.push(increment);
+                        value
+                    }
+                    _ => self.unsupported_expression(expression),
+                }
+            }
+            ast::Expression::Postfix(value, operation, _) => {
+                let wide = self.is_wide(value);
+                let value = self.parse_expression(value, constructor);
+                let increment = self.increment(value.clone(), operation, wide, constructor);
+                self.following.push(increment);
+                value
+            }
+            ast::Expression::Parenthesis(inner, _) => {
+                Expression::Enclosed(bx!(self.parse_expression(inner, constructor)))
+            }
+            ast::Expression::Conditional(condition, if_true, if_false, _) => {
+                Expression::Ternary(
+                    bx!(self.parse_condition(condition, constructor, false)),
+                    bx!(self.parse_expression(if_true, constructor)),
+                    bx!(self.parse_expression(if_false, constructor)),
+                )
+            }
+            _ => self.unsupported_expression(expression),
+        }
+    }
+
+    /// Parses a solidity function call
+    ///
+    /// Calls of types are casts, calls of library functions are library calls
+    /// and calls of struct names are struct initializations
+    ///
+    /// `expression` the whole call expression
+    /// `callee` the called expression
+    /// `args` the arguments of the call
+    /// `constructor` if the expression is inside a constructor
+    ///
+    /// Return the expression in form of `Expression::FunctionCall`
+    fn parse_function_call(
+        &mut self,
+        expression: &ast::Expression,
+        callee: &ast::Expression,
+        args: &[ast::Expression],
+        constructor: bool,
+    ) -> Expression {
+        match callee {
+            ast::Expression::Identifier(name, _) if name == "address" && is_zero(args) => {
+                self.imports
+                    .insert(String::from("use openbrush::traits::ZERO_ADDRESS;"));
+                Expression::ZeroAddressInto
+            }
+            ast::Expression::Identifier(name, _)
+                if name == "address"
+                    && matches!(args, [ast::Expression::Identifier(this, _)] if this == "this") =>
+            {
+                self.parse_builtin("this", &[], expression, constructor)
+            }
+            ast::Expression::Identifier(name, _)
+                if BUILTINS.contains_key(name.as_str()) && !self.functions.contains_key(name) =>
+            {
+                self.parse_builtin(name, args, expression, constructor)
+            }
+            ast::Expression::Member(base, function_name, _)
+                if matches!(base.to_string().as_str(), "abi" | "string")
+                    && BUILTINS.contains_key(format!("{base}.{function_name}").as_str()) =>
+            {
+                let name = format!("{base}.{function_name}");
+                self.parse_builtin(&name, args, expression, constructor)
+            }
+            // `string(abi.encodePacked(a, b))` concatenates the strings
+            ast::Expression::Identifier(name, _)
+                if name == "string"
+                    && matches!(
+                        args,
+                        [ast::Expression::Call(callee, ..)] if callee.to_string() == "abi.encodePacked"
+                    ) =>
+            {
+                let packed = match &args[0] {
+                    ast::Expression::Call(_, packed, _) => packed,
+                    _ => unreachable!(),
+                };
+                self.parse_builtin("string.concat", packed, expression, constructor)
+            }
+            // the accounts of ink! are 32 bytes long, they are not integers as the addresses
+            ast::Expression::Identifier(name, _)
+                if args.len() == 1
+                    && ((is_integer_type(name) && self.is_account(&args[0]))
+                        || (name == "address" && self.is_integer_value(&args[0]))) =>
+            {
+                self.unsupported_expression_with(
+                    expression,
+                    String::from(
+                        "the casts between addresses and integers are not supported, \
+                         it is transpiled to `todo!`",
+                    ),
+                )
+            }
+            ast::Expression::Identifier(name, _) if self.is_wide_int(name) && args.len() == 1 => {
+                let mut value = self.parse_expression(&args[0], constructor);
+                // the enums are converted by their discriminant
+                if self.is_enum(&args[0]) {
+                    value = Expression::Cast(false, String::from("u8"), bx!(value));
+                }
+                Expression::Cast(true, format!("{}::from", self.wide_int()), bx!(value))
+            }
+            // the integer out of the range of the enum reverts, as in Solidity
+            ast::Expression::Identifier(name, _)
+                if self.enums.contains_key(name) && args.len() == 1 =>
+            {
+                let from_index = format!("{}::from_index($0)", name.to_case(Pascal));
+                let message = "\"Enum value out of range\"";
+                let code = match constructor {
+                    true => format!("{from_index}.expect({message})"),
+                    false => format!("{from_index}.ok_or(Error::Custom(String::from({message})))?"),
+                };
+                Expression::Builtin(code, vec![self.parse_expression(&args[0], constructor)])
+            }
+            // `U256` is truncated to the lower 128 bits before it is cast to a narrower integer
+            ast::Expression::Identifier(name, _)
+                if is_integer_type(name) && args.len() == 1 && self.is_wide(&args[0]) =>
+            {
+                Expression::Cast(
+                    false,
+                    TYPES[name.as_str()].0.to_owned(),
+                    bx!(Expression::WithSelector(
+                        bx!(self.parse_expression(&args[0], constructor)),
+                        bx!(Expression::Literal(String::from("low_u128()"))),
+                    )),
+                )
+            }
+            // `payable(x)` is the address itself
+            ast::Expression::Identifier(name, _) if name == "payable" && args.len() == 1 => {
+                self.parse_expression(&args[0], constructor)
+            }
+            // the interface is called by the address it is cast from
+            ast::Expression::Identifier(name, _)
+                if self.definitions.interfaces.contains_key(name) && args.len() == 1 =>
+            {
+                self.parse_expression(&args[0], constructor)
+            }
+            // the byte of `bytes` is wrapped, eg. `bytes1(data[i])`
+            ast::Expression::Identifier(name, _)
+                if name == "bytes1"
+                    && args.len() == 1
+                    && self.variable_type(&args[0]).as_deref() == Some("u8") =>
+            {
+                let value = self.parse_expression(&args[0], constructor);
+                self.convert_byte(&args[0], value, "[u8; 1]")
+            }
+            // the fixed size bytes are the big endian integers of the same size
+            ast::Expression::Identifier(name, _)
+                if args.len() == 1
+                    && ((fixed_bytes_size(name).is_some() && self.is_integer_value(&args[0]))
+                        || (is_integer_type(name) && self.bytes_type(&args[0]).is_some())) =>
+            {
+                self.convert_bytes_cast(name, &args[0], expression, constructor)
+            }
+            ast::Expression::Identifier(name, _)
+                if TYPES.contains_key(name.as_str()) && args.len() == 1 =>
+            {
+                let the_type = TYPES.get(name.as_str()).unwrap();
+                let mut value = self.parse_expression(&args[0], constructor);
+                // the values are converted by their owned copies, eg. `bytes(name)`
+                if the_type.1.is_some() && self.is_borrowed(&args[0]) {
+                    value = Expression::WithSelector(
+                        bx!(value),
+                        bx!(Expression::Literal(String::from("clone()"))),
+                    );
+                }
+                Expression::Cast(
+                    the_type.1.is_some(),
+                    the_type.1.unwrap_or(the_type.0).to_owned(),
+                    bx!(value),
+                )
+            }
+            ast::Expression::Identifier(name, _) if self.structs.contains_key(name) => {
+                let fields = self.structs[name].fields.clone();
+                let args = fields
+                    .iter()
+                    .zip(args.iter())
+                    .map(|(field, arg)| {
+                        Expression::StructArg(
+                            field.name.clone(),
+                            bx!(self.parse_expression(arg, constructor)),
+                        )
+                    })
+                    .collect();
+                Expression::StructInit(name.clone(), args)
+            }
+            ast::Expression::Identifier(name, _) => {
+                let name = &self
+                    .resolve_overload(name, args)
+                    .unwrap_or_else(|| name.clone());
+                let args = self.parse_args(args, constructor);
+                let selector = if self.functions.contains_key(name) {
+                    Some(selector!(self, constructor))
+                } else {
+                    None
+                };
+                // the functions of a library call each other without the name of the library
+                let function = format!("{}.{name}", self.origin);
+                self.pass_references(&function, args, |args| {
+                    Expression::FunctionCall(
+                        name.clone(),
+                        args,
+                        selector,
+                        *self.functions.get(name).unwrap_or(&true),
+                    )
+                })
+            }
+            // native value transfers, eg. `payable(to).transfer(amount)`
+            ast::Expression::Member(base, function_name, _)
+                if matches!(function_name.as_str(), "transfer" | "send")
+                    && args.len() == 1
+                    && self.is_account(base) =>
+            {
+                let args = [base.as_ref().clone(), args[0].clone()];
+                let name = format!("address.{function_name}");
+                self.parse_builtin(&name, &args, expression, constructor)
+            }
+            ast::Expression::CallOptions(call, options, _) => {
+                self.parse_value_call(expression, call, options, args, constructor)
+            }
+            // `value.clone()` only comes from the getters of the public state variables
+            ast::Expression::Member(base, function_name, _)
+                if function_name == "clone" && args.is_empty() =>
+            {
+                Expression::WithSelector(
+                    bx!(self.parse_expression(base, constructor)),
+                    bx!(Expression::Literal(String::from("clone()"))),
+                )
+            }
+            ast::Expression::Member(base, function_name, _)
+                if matches!(function_name.as_str(), "push" | "pop")
+                    && !self.using.contains_key(function_name)
+                    && (self.is_array(base) || self.storage_array(base).is_some()) =>
+            {
+                self.parse_array_function(base, function_name, args, constructor)
+            }
+            ast::Expression::Member(..) if self.is_external_call(expression) => {
+                self.parse_external_call(expression, false, constructor)
+            }
+            ast::Expression::Member(base, function_name, _) => {
+                let raw_args = args;
+                let args = self.parse_args(args, constructor);
+                if let ast::Expression::Identifier(base_name, _) = base.as_ref() {
+                    if base_name == "super" {
+                        let function_name = &self
+                            .resolve_overload(function_name, raw_args)
+                            .unwrap_or_else(|| function_name.clone());
+                        if let Some((function, external)) = self.resolve_super(function_name) {
+                            return Expression::FunctionCall(
+                                function,
+                                args,
+                                Some(selector!(self, constructor)),
+                                external,
+                            )
+                        }
+                    }
+                    if self.libraries.contains_key(base_name) {
+                        let function_name = self
+                            .resolve_overload(&format!("{base_name}.{function_name}"), raw_args)
+                            .unwrap_or_else(|| function_name.clone());
+                        let function = format!("{base_name}.{function_name}");
+                        return self.pass_references(&function, args, |args| {
+                            Expression::LibraryCall(base_name.clone(), function_name, args)
+                        })
+                    }
+                }
+                if let Some(library) = self.using.get(function_name).cloned() {
+                    // the value is the first argument of the library function
+                    let library_args = std::iter::once(base.as_ref().clone())
+                        .chain(raw_args.iter().cloned())
+                        .collect::<Vec<_>>();
+                    let function_name = self
+                        .resolve_overload(&format!("{library}.{function_name}"), &library_args)
+                        .unwrap_or_else(|| function_name.clone());
+                    let mut library_args = vec![self.parse_expression(base, constructor)];
+                    library_args.extend(args);
+                    let function = format!("{library}.{function_name}");
+                    return self.pass_references(&function, library_args, |args| {
+                        Expression::LibraryCall(library, function_name, args)
+                    })
+                }
+                let base = self.parse_expression(base, constructor);
+                Expression::WithSelector(
+                    bx!(base),
+                    bx!(Expression::FunctionCall(
+                        function_name.clone(),
+                        args,
+                        None,
+                        *self.functions.get(function_name).unwrap_or(&true),
+                    )),
+                )
+            }
+            ast::Expression::New(ast::TypeName::Array(array_type, None), _) if args.len() == 1 => {
+                let array_type = self.convert_variable_type(array_type, expression.span());
+                let array_size = self.parse_array_index(&args[0], constructor);
+                Expression::NewArray(array_type, bx!(array_size))
+            }
+            _ => self.unsupported_expression(expression),
+        }
+    }
 
-        Statement::Try(statements)
+    /// Passes the storage arguments of a library function as references, eg. `&mut self.data.ids`,
+    /// a struct or an array stored in a mapping is read into `entry` and stored back after the call
+    ///
+    /// `function` the called function prefixed with its library, eg. `Counters.increment`
+    /// `args` the parsed arguments of the call
+    /// `call` creates the call expression from the passed arguments
+    fn pass_references(
+        &self,
+        function: &str,
+        mut args: Vec<Expression>,
+        call: impl FnOnce(Vec<Expression>) -> Expression,
+    ) -> Expression {
+        let Some(references) = self.library_references.get(function) else {
+            return call(args)
+        };
+        let mut stored = None;
+        for (arg, reference) in args.iter_mut().zip(references.iter()) {
+            if reference.is_empty()
+                || matches!(arg, Expression::Member(name, None) if self.references.contains(name))
+            {
+                continue
+            }
+            if *reference == "&mut " && stored.is_none() {
+                if let Some((Expression::Mapping(mapping, indices, None), entry)) =
+                    take_mapping(arg, "entry")
+                {
+                    let read = Expression::Mapping(mapping.clone(), indices.clone(), None);
+                    let value = Expression::Literal(String::from("entry"));
+                    let store = Expression::Mapping(mapping, indices, Some(bx!(value)));
+                    stored = Some((read, store));
+                    *arg = entry;
+                }
+            }
+            *arg = Expression::Builtin(format!("{reference}$0"), vec![arg.clone()]);
+        }
+        match stored {
+            Some((read, store)) => {
+                Expression::Builtin(
+                    String::from("{ let mut entry = $1; let result = $0; $2; result }"),
+                    vec![call(args), read, store],
+                )
+            }
+            None => call(args),
+        }
     }
 
-    /// Parses a solidity catch statement and the statements inside the catch block
-    /// The catch statement is inserted as a comment and the actual catch statement
-    /// is rendered as if false block
+    /// Converts a call of an address with value, eg. `to.call{value: amount}("")`,
+    /// to a transfer of the value which returns whether the transfer succeeded
     ///
-    /// `line` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// ink! does not dispatch calls by their data, so the data of the call is reported
+    /// and dropped, as well as the gas limit
     ///
-    /// Return the statement in form of `Statement::Catch`
-    fn parse_catch(
+    /// `expression` the whole call expression
+    /// `call` the called expression with its options
+    /// `options` the call options, eg. `value: amount`
+    /// `args` the arguments of the call
+    /// `constructor` if the expression is inside a constructor
+    ///
+    /// returns the transfer as `Expression::Builtin`
+    fn parse_value_call(
         &mut self,
-        line: &str,
+        expression: &ast::Expression,
+        call: &ast::Expression,
+        options: &[ast::NamedArgument],
+        args: &[ast::Expression],
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let mut statements = Vec::default();
-        statements.push(Statement::Comment(line.to_owned()));
-
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::CatchEnd,
+    ) -> Expression {
+        let value = options.iter().find(|option| option.name == "value");
+        let (base, value) = match (call, value) {
+            (ast::Expression::Member(base, member, _), Some(value)) if member == "call" => {
+                (base, value)
+            }
+            _ => return self.unsupported_expression(expression),
+        };
+        let empty = matches!(
+            args,
+            [ast::Expression::StringLiteral(data, _)] if data == "\"\"" || data == "''"
         );
-
-        Statement::Catch(statements)
+        if !empty || options.len() > 1 {
+            self.warn(
+                Category::Builtin,
+                String::from(
+                    "`address.call` only transfers the value, the data and the gas of the call \
+                     are dropped",
+                ),
+                expression.span(),
+            );
+        }
+        let args = [base.as_ref().clone(), value.value.clone()];
+        self.parse_builtin("address.call", &args, expression, constructor)
     }
 
-    /// Parses a solidity for statement and the statements inside the for block
+    /// Converts a Solidity builtin to its ink! equivalent, eg. `block.timestamp`
+    /// or `keccak256(data)`
     ///
-    /// `line` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// The builtins without an equivalent and the builtins with differing semantics
+    /// are reported, the builtins without an equivalent are transpiled as they are
+    ///
+    /// `name` the name of the builtin in `BUILTINS`
+    /// `args` the arguments of the builtin
+    /// `expression` the syntax tree of the whole expression
+    /// `constructor` if the expression is inside a constructor
     ///
-    /// Return the statement in form of `Statement::While`
-    fn parse_for(
+    /// returns the builtin as `Expression::Builtin`
+    fn parse_builtin(
         &mut self,
-        line: &str,
+        name: &str,
+        args: &[ast::Expression],
+        expression: &ast::Expression,
         constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let assignment_raw = capture_regex(&REGEX_FOR, line, "assignment").unwrap();
-        let condition_raw = capture_regex(&REGEX_FOR, line, "condition").unwrap();
-        let modification_raw = capture_regex(&REGEX_FOR, line, "modification").unwrap();
-
-        let assignment = self.parse_statement(&assignment_raw, constructor, stack, iterator);
-        let condition = self.parse_expression(&condition_raw, constructor, None);
-        let modification = self.parse_statement(&modification_raw, constructor, stack, iterator);
+    ) -> Expression {
+        let (code, note) = BUILTINS[name];
+        let code = match code {
+            Some(code) => code,
+            None => {
+                self.warn(
+                    Category::Builtin,
+                    format!("`{name}` has no equivalent in ink!, it is transpiled to `todo!`"),
+                    expression.span(),
+                );
+                return todo(expression)
+            }
+        };
+        if let Some(note) = note {
+            self.warn(Category::Builtin, format!("`{name}` {note}"), expression.span());
+        }
 
-        let mut statements = Vec::default();
+        let mut code = code.replace("$env", &format!("{}.env()", selector!(self, constructor)));
+        if self.backend == Backend::Ink4 {
+            code = code
+                .replace("ink_env::", "ink::env::")
+                .replace("ink_prelude::", "ink::prelude::");
+        }
+        let args = match (name, args) {
+            // the types of the decoded values are the arguments following the data
+            ("abi.decode", [data, types]) => {
+                let types = match types {
+                    ast::Expression::Tuple(types, _) => types.iter().flatten().collect::<Vec<_>>(),
+                    ast::Expression::Parenthesis(the_type, _) => vec![the_type.as_ref()],
+                    the_type => vec![the_type],
+                };
+                let placeholders = (1..=types.len())
+                    .map(|i| format!("${i}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let decode = format!("<({placeholders})>::decode(&mut &$0[..])");
+                let message = "\"Decoding failed\"";
+                let error = format!("Error::Custom(String::from({message}))");
+                code = match constructor {
+                    true => format!("{decode}.expect({message})"),
+                    false => format!("{decode}.map_err(|_| {error})?"),
+                };
+                std::iter::once(data)
+                    .chain(types)
+                    .map(|arg| self.parse_expression(arg, constructor))
+                    .collect()
+            }
+            ("string.concat", _) => {
+                code = format!("{code}(\"{}\", $args)", "{}".repeat(args.len()));
+                self.parse_args(args, constructor)
+            }
+            // constructors do not return errors
+            ("address.transfer", _) if constructor => {
+                code = code.replace(
+                    "map_err(|_| Error::Custom(String::from(\"Transfer failed\")))?",
+                    "expect(\"Transfer failed\")",
+                );
+                self.parse_args(args, constructor)
+            }
+            // the hashed strings are hashed as bytes
+            ("keccak256" | "sha256", [ast::Expression::StringLiteral(content, _)]) => {
+                let content = match content.strip_prefix('\'') {
+                    Some(content) => format!("\"{}\"", content.trim_end_matches('\'')),
+                    None => content.clone(),
+                };
+                vec![Expression::Literal(format!("{content}.as_bytes()"))]
+            }
+            _ => self.parse_args(args, constructor),
+        };
 
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::WhileEnd,
-        );
+        Expression::Builtin(code, args)
+    }
 
-        Statement::While(
-            Some(bx!(assignment)),
-            condition,
-            Some(bx!(modification)),
-            statements,
-        )
+    fn parse_args(&mut self, args: &[ast::Expression], constructor: bool) -> Vec<Expression> {
+        args.iter()
+            .map(|arg| self.parse_expression(arg, constructor))
+            .collect()
     }
 
-    /// Parses a solidity do/while statement and the statements inside the do block
+    /// Converts solidity variable type to ink! variable type (eg. address -> AccountId, uint -> u128, ...)
     ///
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// `type_name` solidity type
+    /// `span` the position of the code using the type, reported if the type is unknown
     ///
-    /// Return the statement in form of `Statement::Loop`
-    fn parse_do(
-        &mut self,
-        constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let mut statements = Vec::default();
-
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::WhileEnd,
-        );
-
-        let next_statement = iterator.next().unwrap();
-        let condition = if let Statement::Raw(content) = next_statement {
-            let condition_raw = capture_regex(&REGEX_WHILE, content, "condition").unwrap();
-            self.parse_expression(&condition_raw, constructor, None)
-        } else {
-            panic!("Expected Raw statement after do block")
-        };
+    /// return the converted type
+    fn convert_variable_type(&mut self, type_name: &ast::TypeName, span: Span) -> String {
+        match type_name {
+            ast::TypeName::Mapping(from, to) => {
+                self.imports
+                    .insert(String::from("use openbrush::storage::Mapping;\n"));
+                // nested mappings are flattened into a mapping with a tuple key
+                let mut from_vec = vec![self.convert_variable_type(from, span)];
+                let mut to = to.as_ref();
+                while let ast::TypeName::Mapping(from, nested_to) = to {
+                    from_vec.push(self.convert_variable_type(from, span));
+                    to = nested_to;
+                }
+                let to = self.convert_variable_type(to, span);
 
-        Statement::Loop(None, condition, None, statements)
+                let from = if from_vec.len() > 1 {
+                    format!("({})", from_vec.join(", "))
+                } else {
+                    from_vec[0].to_owned()
+                };
+                format!("Mapping<{}, {}>", from, to)
+            }
+            ast::TypeName::Array(element_type, None) => {
+                self.imports
+                    .insert(String::from("use ink_prelude::vec::Vec;\n"));
+                format!("Vec<{}>", self.convert_variable_type(element_type, span))
+            }
+            ast::TypeName::Array(element_type, Some(size)) => {
+                format!("[{}; {size}]", self.convert_variable_type(element_type, span))
+            }
+            ast::TypeName::UserDefined(path) => {
+                let name = path.last().cloned().unwrap_or_default();
+                // contracts are called through the wrappers of their interfaces by their address
+                if self.definitions.interfaces.contains_key(&name) {
+                    return String::from("AccountId")
+                }
+                if !self.known_types.contains(&name) {
+                    self.warn(
+                        Category::UnknownType,
+                        format!("type `{}` is not defined, it is transpiled as it is", path.join(".")),
+                        span,
+                    );
+                }
+                // types defined inside of a library are accessed through the library module
+                match path.as_slice() {
+                    [library, library_type] if self.libraries.contains_key(library) => {
+                        format!("{}::{}", library.to_case(Snake), library_type)
+                    }
+                    _ => path.join("."),
+                }
+            }
+            ast::TypeName::Function(params, returns) => {
+                self.warn(
+                    Category::UnsupportedDefinition,
+                    format!(
+                        "function type `{type_name}` is transpiled to a function pointer, \
+                         the functions of the contract can not be passed as it"
+                    ),
+                    span,
+                );
+                let params = params
+                    .iter()
+                    .map(|param| self.convert_variable_type(param, span))
+                    .collect::<Vec<_>>();
+                let returns = returns
+                    .iter()
+                    .map(|returned| self.convert_variable_type(returned, span))
+                    .collect::<Vec<_>>();
+                match returns.as_slice() {
+                    [] => format!("fn({})", params.join(", ")),
+                    [returned] => format!("fn({}) -> {returned}", params.join(", ")),
+                    _ => format!("fn({}) -> ({})", params.join(", "), returns.join(", ")),
+                }
+            }
+            ast::TypeName::Elementary(name) if self.is_wide_int(name) => self.wide_int(),
+            ast::TypeName::Elementary(name) => {
+                // there is no signed counterpart of `U256`, the integers are reported once
+                // per definition
+                if integer_size(name).is_some_and(|size| size > 128) {
+                    let message = match name.starts_with("uint") {
+                        true => {
+                            format!(
+                                "`{name}` is transpiled as `u128`, pass `--u256` to transpile \
+                                 it as `U256`"
+                            )
+                        }
+                        false => format!("`{name}` is transpiled as `i128`"),
+                    };
+                    if self.truncated.insert((self.origin.clone(), name.clone())) {
+                        self.warn(Category::TruncatedInteger, message, span);
+                    }
+                }
+                match convert_int(name.clone()).as_str() {
+                    str if str.contains("uint") => str.replace("uint", "u"),
+                    str if str.contains("int") => str.replace("int", "i"),
+                    str if TYPES.contains_key(str) => {
+                        let the_type = TYPES.get(str).unwrap();
+                        if let Some(import) = the_type.2 {
+                            self.imports.insert(format!("use {import};\n"));
+                        }
+                        the_type.0.to_string()
+                    }
+                    _ => {
+                        self.warn(
+                            Category::UnknownType,
+                            format!("type `{name}` is not supported, it is transpiled as it is"),
+                            span,
+                        );
+                        name.clone()
+                    }
+                }
+            }
+        }
     }
 
-    /// Parses a solidity while statement and the statements inside the while block
-    ///
-    /// `line` the solidity while condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// returns true if the solidity type is an unsigned integer converted to `U256`
     ///
-    /// Return the statement in form of `Statement::While`
-    fn parse_while(
-        &mut self,
-        line: &str,
-        constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let condition_raw = capture_regex(&REGEX_WHILE, line, "condition").unwrap();
-        let condition = self.parse_expression(&condition_raw, constructor, None);
-        let mut statements = Vec::default();
+    /// `name` the elementary solidity type, eg. `uint256`
+    fn is_wide_int(&self, name: &str) -> bool {
+        self.u256 && name.starts_with("uint") && integer_size(name).is_some_and(|size| size > 128)
+    }
 
-        self.parse_block(
-            constructor,
-            stack,
-            iterator,
-            &mut statements,
-            Statement::WhileEnd,
-        );
+    /// returns `U256`, which is imported by the parsed code from now on
+    fn wide_int(&mut self) -> String {
+        self.imports.insert(String::from(U256_IMPORT));
+        String::from("U256")
+    }
 
-        Statement::While(None, condition, None, statements)
+    /// returns true if `name` is a parameter or a local variable of the type `local_type`
+    fn is_local(&self, name: &str, local_type: &str) -> bool {
+        self.locals
+            .get(name)
+            .is_some_and(|declared| declared == local_type)
     }
 
-    /// Parses a block of statements (if, else if, else, ...)
+    /// Guesses whether the expression is a `U256`
     ///
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
-    /// `statements` the vector of statements into which we are adding the parsed statements
-    /// `until` the statement which ends the block
-    fn parse_block(
-        &mut self,
-        constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-        statements: &mut Vec<Statement>,
-        until: Statement,
-    ) {
-        while let Some(statement_raw) = iterator.next() {
-            if let Statement::Raw(line_raw) = statement_raw {
-                let statement = self.parse_statement(line_raw, constructor, stack, iterator);
-                if statement == until {
-                    break
-                } else {
-                    statements.push(statement)
+    /// Only the storage, the parameters and the local variables of the function are typed,
+    /// so the literals used with them can be converted to `U256`
+    ///
+    /// `expression` the syntax tree of the expression
+    fn is_wide(&self, expression: &ast::Expression) -> bool {
+        if !self.u256 {
+            return false
+        }
+        let is_wide_field = |name: &String, field_type: &str| {
+            self.storage
+                .get(name)
+                .is_some_and(|field| field.field_type == field_type)
+        };
+        match expression {
+            ast::Expression::Identifier(name, _) => {
+                self.is_local(name, "U256") || is_wide_field(name, "U256")
+            }
+            ast::Expression::Index(..) => {
+                self.variable_type(expression)
+                    .is_some_and(|value_type| value_type == "U256")
+            }
+            ast::Expression::Member(array, member, _) if member == "length" => {
+                self.is_array(array)
+            }
+            ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+                matches!(callee.as_ref(), ast::Expression::Identifier(name, _) if self.is_wide_int(name))
+            }
+            ast::Expression::Member(base, member, _) if member == "max" || member == "min" => {
+                match base.as_ref() {
+                    ast::Expression::Call(callee, args, _) => {
+                        matches!(callee.as_ref(), ast::Expression::Identifier(name, _) if name == "type")
+                            && matches!(args.as_slice(), [ast::Expression::Identifier(name, _)] if self.is_wide_int(name))
+                    }
+                    _ => false,
+                }
+            }
+            ast::Expression::NumberLiteral(number, unit, _) if !number.starts_with("0x") => {
+                let number = convert_number(number, unit.as_deref());
+                is_integer(&number) && number.parse::<u128>().is_err()
+            }
+            ast::Expression::Parenthesis(inner, _) => self.is_wide(inner),
+            ast::Expression::Binary(left, operation, right, _) => {
+                match operation.as_str() {
+                    "<<" | ">>" | ">>>" | "**" => self.is_wide(left),
+                    "&&" | "||" => false,
+                    operation if COMPARISONS.contains(&operation) => false,
+                    _ => self.is_wide(left) || self.is_wide(right),
                 }
             }
+            _ => false,
         }
     }
 
-    /// Parses a solidity assembly statement and the statements inside the assembly block
-    /// A notice is inserted to the beginning and to the end of the assembly block
-    /// All statements of the assembly block are parsed as a `Statement::Comment`
-    ///
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// Guesses whether the expression is an integer, eg. a number, an integer variable
+    /// or a cast to an integer type
     ///
-    /// Return the statement in form of `Statement::Group`
-    fn parse_assembly(
-        &self,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let mut statements = Vec::default();
-        statements.push(Statement::Comment(String::from(
-            "Please handle assembly blocks manually >>>",
-        )));
-
-        for statement_raw in iterator.by_ref() {
-            if let Statement::Raw(content_raw) = statement_raw {
-                let content = trim(content_raw);
-                if content == "}" {
-                    stack.pop_back();
-                    statements.push(Statement::AssemblyEnd);
-                    break
-                } else {
-                    statements.push(Statement::Comment(content.clone()));
-                }
+    /// `expression` the syntax tree of the expression
+    fn is_integer_value(&self, expression: &ast::Expression) -> bool {
+        match expression {
+            ast::Expression::NumberLiteral(..) => true,
+            ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+                matches!(
+                    callee.as_ref(),
+                    ast::Expression::Identifier(name, _) if is_integer_type(name)
+                )
+            }
+            ast::Expression::Parenthesis(inner, _) => self.is_integer_value(inner),
+            _ => {
+                self.variable_type(expression).is_some_and(|variable_type| {
+                    variable_type == "U256"
+                        || TYPES.iter().any(|(name, the_type)| {
+                            is_integer_type(name) && the_type.0 == variable_type
+                        })
+                })
             }
         }
-
-        statements.push(Statement::Comment(String::from(
-            "<<< Please handle assembly blocks manually",
-        )));
-
-        Statement::Group(statements)
     }
 
-    /// Parses a solidity emit statement
-    ///
-    /// `line` the solidity line of the emit statement
-    /// `constructor` if the statement is inside a constructor
+    /// Guesses whether the expression is an `AccountId`, which can receive native value
     ///
-    /// Return the statement in form of `Statement::Emit`
-    fn parse_emit(&mut self, line: &str, constructor: bool) -> Statement {
-        let event_name_raw = capture_regex(&REGEX_EMIT, line, "event_name").unwrap();
-        let args_raw = capture_regex(&REGEX_EMIT, line, "args").unwrap();
-
-        let mut args = Vec::<Expression>::new();
-        let mut buffer = String::new();
-        let mut open_parentheses = 0;
-        let mut close_parenthesis = 0;
-        let mut event_count = 0;
-
-        for ch in args_raw.chars() {
-            match ch {
-                PARENTHESIS_OPEN => {
-                    open_parentheses += 1;
-                    buffer.push(ch)
-                }
-                PARENTHESIS_CLOSE => {
-                    close_parenthesis += 1;
-                    buffer.push(ch)
-                }
-                COMMA => {
-                    if open_parentheses == close_parenthesis {
-                        args.push(Expression::StructArg(
-                            self.events
-                                .get(&event_name_raw)
-                                .unwrap_or_else(|| panic!("Event {event_name_raw} not defined"))
-                                .fields
-                                .get(event_count)
-                                .unwrap()
-                                .name
-                                .clone(),
-                            bx!(self.parse_expression(&trim(&buffer), constructor, None)),
-                        ));
-                        event_count += 1;
-                        buffer.clear();
-                    } else {
-                        buffer.push(ch)
+    /// `expression` the syntax tree of the expression
+    fn is_account(&self, expression: &ast::Expression) -> bool {
+        match expression {
+            ast::Expression::Identifier(name, _) => {
+                self.is_local(name, "AccountId")
+                    || self.storage.get(name).is_some_and(|field| {
+                        field.field_type == "AccountId" && !self.interface_fields.contains_key(name)
+                    })
+            }
+            ast::Expression::Index(mapping, ..) => {
+                match mapping.as_ref() {
+                    ast::Expression::Identifier(name, _) => {
+                        self.storage.get(name).is_some_and(|field| {
+                            field.field_type.ends_with(", AccountId>")
+                                || field.field_type == "Vec<AccountId>"
+                        })
                     }
+                    _ => false,
                 }
-                _ => buffer.push(ch),
             }
+            ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+                matches!(callee.to_string().as_str(), "payable" | "address")
+            }
+            ast::Expression::Member(..) => {
+                matches!(expression.to_string().as_str(), "msg.sender" | "tx.origin")
+            }
+            ast::Expression::Parenthesis(inner, _) => self.is_account(inner),
+            _ => false,
         }
+    }
 
-        args.push(Expression::StructArg(
-            self.events
-                .get(&event_name_raw)
-                .unwrap()
-                .fields
-                .get(event_count)
-                .unwrap()
-                .name
-                .clone(),
-            bx!(self.parse_expression(&trim(&buffer), constructor, None)),
-        ));
+    /// Parses the operands of a binary operation, the other operand of a `U256` operand
+    /// is converted to `U256`
+    ///
+    /// `left` the syntax tree of the left operand
+    /// `right` the syntax tree of the right operand
+    /// `constructor` if the operation is inside a constructor
+    fn parse_operands(
+        &mut self,
+        left: &ast::Expression,
+        right: &ast::Expression,
+        constructor: bool,
+    ) -> (Expression, Expression) {
+        match (self.is_wide(left), self.is_wide(right)) {
+            (true, false) => {
+                let left = self.parse_expression(left, constructor);
+                (left, self.parse_wide(right, constructor))
+            }
+            (false, true) => {
+                let left = self.parse_wide(left, constructor);
+                (left, self.parse_expression(right, constructor))
+            }
+            _ => {
+                let left_type = self.variable_type(left);
+                let right_type = self.variable_type(right);
+                let mut parsed_left = self.parse_expression(left, constructor);
+                let mut parsed_right = self.parse_expression(right, constructor);
+                if let Some(right_type) = right_type {
+                    parsed_left = self.widen_integer(left, parsed_left, &right_type);
+                }
+                if let Some(left_type) = left_type {
+                    parsed_right = self.widen_integer(right, parsed_right, &left_type);
+                }
+                (parsed_left, parsed_right)
+            }
+        }
+    }
 
-        Statement::Emit(event_name_raw, args)
+    /// Parses the operand of a `U256` operation or the value of a `U256` variable,
+    /// the operand of a narrower integer type is converted with `U256::from`
+    ///
+    /// `operand` the syntax tree of the operand
+    /// `constructor` if the operand is inside a constructor
+    fn parse_wide(&mut self, operand: &ast::Expression, constructor: bool) -> Expression {
+        match operand {
+            ast::Expression::Parenthesis(inner, _) => {
+                Expression::Enclosed(bx!(self.parse_wide(inner, constructor)))
+            }
+            ast::Expression::Conditional(condition, if_true, if_false, _) => {
+                Expression::Ternary(
+                    bx!(self.parse_condition(condition, constructor, false)),
+                    bx!(self.parse_wide(if_true, constructor)),
+                    bx!(self.parse_wide(if_false, constructor)),
+                )
+            }
+            _ if self.is_wide(operand) => self.parse_expression(operand, constructor),
+            _ => widen(self.parse_expression(operand, constructor)),
+        }
     }
 
-    /// Parses a solidity assignment statement
+    /// Reports a warning located at `span`
     ///
-    /// `line` the solidity representation of the assign statement
-    /// `constructor` if the statement is inside a constructor
+    /// The statements of contracts inherited from other files are located in those files,
+    /// so their warnings name the contract instead of pointing to the source code
     ///
-    /// Return the statement in form of `Statement::Assign`
-    fn parse_assign(&mut self, line: &str, constructor: bool) -> Statement {
-        let left_raw = capture_regex(&REGEX_ASSIGN, line, "left").unwrap();
-        let operation_raw =
-            capture_regex(&REGEX_ASSIGN, line, "operation").unwrap_or_else(|| String::from("="));
-        let right_raw = capture_regex(&REGEX_ASSIGN, line, "right").unwrap();
-
-        let left = self.parse_expression(&left_raw, constructor, None);
-        let operation = *OPERATIONS.get(&operation_raw).unwrap();
-        let right = self.parse_expression(&right_raw, constructor, None);
-
-        if REGEX_BINARY_PREFIX.is_match(&right_raw) {
-            let value_raw = capture_regex(&REGEX_BINARY_PREFIX, &right_raw, "value").unwrap();
-            let value = self.parse_expression(&value_raw, constructor, None);
-            let assign = Statement::Assign(left, value, operation);
-            let arithmetic =
-                self.parse_binary_operation(&right_raw, constructor, &REGEX_BINARY_PREFIX, None);
-            return Statement::Group(vec![arithmetic, assign])
-        } else if REGEX_BINARY_SUFFIX.is_match(&right_raw) {
-            let value_raw = capture_regex(&REGEX_BINARY_SUFFIX, &right_raw, "value").unwrap();
-            let value = self.parse_expression(&value_raw, constructor, None);
-            let assign = Statement::Assign(left, value, operation);
-            let arithmetic =
-                self.parse_binary_operation(&right_raw, constructor, &REGEX_BINARY_SUFFIX, None);
-            return Statement::Group(vec![assign, arithmetic])
-        }
-
-        if let Expression::Mapping(name, indices, None) = left {
-            let converted_operation = match operation {
-                Operation::AddAssign => Operation::Add,
-                Operation::MulAssign => Operation::Mul,
-                Operation::DivAssign => Operation::Div,
-                Operation::SubtractAssign => Operation::Subtract,
-                _ => operation,
-            };
-            let right_mapping = match converted_operation {
-                Operation::Add | Operation::Mul | Operation::Div | Operation::Subtract => {
-                    Some(bx!(Expression::Arithmetic(
-                        bx!(Expression::Mapping(name.clone(), indices.clone(), None,)),
-                        bx!(right),
-                        converted_operation,
-                    )))
-                }
-                _ => Some(bx!(right)),
-            };
-            Statement::FunctionCall(Expression::Mapping(name, indices, right_mapping))
+    /// The code is also added to the report of the currently parsed definition
+    fn warn(&mut self, category: Category, message: String, span: Span) {
+        let local = self.origin.is_empty() || self.local.contains_key(&self.origin);
+        self.report.push(ReportEntry {
+            category,
+            reason: message.clone(),
+            contract: (!self.origin.is_empty()).then(|| self.origin.clone()),
+            line: span.line,
+            column: span.column,
+            code: self
+                .source
+                .get(span.start..span.end)
+                .filter(|_| local)
+                .and_then(|code| code.lines().next())
+                .map(|code| code.trim().to_string()),
+        });
+        if local {
+            self.diagnostics.warning(message, Some(span));
         } else {
-            Statement::Assign(left, right, operation)
+            self.diagnostics.warning(
+                format!("{message} (in inherited contract `{}`)", self.origin),
+                None,
+            );
         }
     }
 
-    /// Parses a solidity ternary operation
-    ///
-    /// `line` the solidity representation of the ternary operation
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// Reports an error located at `span`, the file is not transpiled
     ///
-    /// Return the statement in form of `Statement::Ternary`
-    fn parse_ternary_statement(
-        &mut self,
-        line: &str,
-        constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let condition_raw = capture_regex(&REGEX_TERNARY, line, "condition").unwrap();
-        let if_true_raw = capture_regex(&REGEX_TERNARY, line, "if_true").unwrap();
-        let if_false_raw = capture_regex(&REGEX_TERNARY, line, "if_false").unwrap();
+    /// The errors of contracts inherited from other files name the contract instead of
+    /// pointing to the source code
+    fn error(&mut self, message: String, span: Span) {
+        if self.origin.is_empty() || self.local.contains_key(&self.origin) {
+            self.diagnostics.error(message, Some(span));
+        } else {
+            self.diagnostics.error(
+                format!("{message} (in inherited contract `{}`)", self.origin),
+                None,
+            );
+        }
+    }
 
-        let condition = self.parse_condition(&condition_raw, constructor, false, None);
-        let if_true = self.parse_statement(&if_true_raw, constructor, stack, iterator);
-        let if_false = self.parse_statement(&if_false_raw, constructor, stack, iterator);
-        Statement::Ternary(condition, bx!(if_true), bx!(if_false))
+    /// returns the comment noting that the statement could not be transpiled
+    fn not_implemented(&mut self, statement: &ast::Statement) -> Statement {
+        self.warn(
+            Category::UnsupportedStatement,
+            String::from("this statement is not supported yet, it is transpiled as a comment"),
+            statement.span(),
+        );
+        Statement::Comment(format!("Sol2Ink Not Implemented yet: {statement}"))
     }
 
-    /// Parses a solidity expression and returns it as `Expression`
+    /// returns the array literals and the tuples transpiled as they are written in Solidity,
+    /// the other expressions are reported and transpiled to `todo!` with the Solidity code,
+    /// so the generated code still compiles
+    fn unsupported_expression(&mut self, expression: &ast::Expression) -> Expression {
+        if matches!(
+            expression,
+            ast::Expression::ArrayLiteral(..) | ast::Expression::Tuple(..)
+        ) {
+            return Expression::Literal(expression.to_string())
+        }
+        self.unsupported_expression_with(
+            expression,
+            String::from("this expression is not supported yet, it is transpiled to `todo!`"),
+        )
+    }
+
+    /// Reports the unsupported expression with the message and transpiles it to `todo!`
     ///
-    /// `raw` the raw representation of the expression
-    /// `constructor` if the expression is inside a constructor
-    /// `enclosed_expressions` the previously parsed enclosed expressions
-    fn parse_expression(
+    /// `expression` the syntax tree of the expression
+    /// `message` the message of the report
+    fn unsupported_expression_with(
         &mut self,
-        raw: &String,
-        constructor: bool,
-        enclosed_expressions: Option<HashMap<String, Expression>>,
+        expression: &ast::Expression,
+        message: String,
     ) -> Expression {
-        if is_literal(raw) {
-            return Expression::Literal(raw.clone())
-        } else if let Some(expression) = SPECIFIC_EXPRESSION.get(raw) {
-            if expression == &Expression::ZeroAddressInto {
-                self.imports
-                    .insert(String::from("use openbrush::traits::ZERO_ADDRESS;"));
-            } else if expression == &Expression::EnvCaller(None) {
-                return Expression::EnvCaller(Some(selector!(constructor)))
-            } else if expression == &Expression::TransferredValue(None) {
-                return Expression::TransferredValue(Some(selector!(constructor)))
-            }
-
-            return expression.clone()
-        } else if let Some(new_type) = TYPES.get(raw.as_str()) {
-            return Expression::Literal(new_type.0.to_owned())
-        }
+        self.warn(Category::UnsupportedExpression, message, expression.span());
+        todo(expression)
+    }
 
-        if let Some(expression) = enclosed_expressions.clone().unwrap_or_default().get(raw) {
-            let regex = Regex::new(r"___0\d+___").unwrap();
-            return if regex.is_match(raw) {
-                expression.clone()
-            } else {
-                Expression::Enclosed(bx!(expression.clone()))
-            }
+    /// returns the selctor of the field (function or a variable)
+    ///
+    /// `constructor` if we access this field from a constructor
+    /// `field_name` the name of the field
+    ///
+    /// returns Some if the field needs a selector, None otherwise
+    fn get_selector(&self, constructor: bool, field_name: &String) -> Option<String> {
+        if self.storage.contains_key(field_name) {
+            Some(selector!(self, constructor))
+        } else {
+            None
         }
+    }
+}
 
-        let extracted = self.extract_parentheses(raw, constructor, false);
-        if extracted.1 > 0 {
-            return self.parse_expression(&extracted.0, constructor, Some(extracted.2))
-        }
+/// Converts Solidity enum
+///
+/// `definition` the syntax tree of the enum
+///
+/// returns the enum as `Enum` struct
+fn parse_enum(definition: &ast::EnumDefinition) -> Enum {
+    Enum {
+        name: definition.name.clone(),
+        values: definition.values.clone(),
+        comments: definition.comments.clone(),
+        stored: false,
+    }
+}
 
-        if enclosed_expressions.is_none() {
-            let indices = self.extract_parentheses(raw, constructor, true);
-            if indices.1 > 0 {
-                return self.parse_expression(&indices.0, constructor, Some(indices.2))
-            }
-        }
+/// returns the statements of the function body wrapped in `Statement::Raw`
+fn raw_statements(definition: &ast::FunctionDefinition) -> Vec<Statement> {
+    definition
+        .body
+        .iter()
+        .flatten()
+        .cloned()
+        .map(Statement::Raw)
+        .collect()
+}
 
-        let regex_hex_string = Regex::new(r#"(?x)^\s*hex"(?P<value>.+?)"\s*$"#).unwrap();
-        let regex_hex = Regex::new(r#"(?x)^\s*(?P<value>0x[0-9A-Fa-f]*?)\s*$"#).unwrap();
-        if regex_hex_string.is_match(raw) || regex_hex.is_match(raw) {
-            let value = capture_regex(&regex_hex_string, raw, "value")
-                .unwrap_or_else(|| capture_regex(&regex_hex, raw, "value").unwrap());
-            return self.parse_expression(
-                &format!("hex(\"{value}\")"),
-                constructor,
-                enclosed_expressions,
-            )
+/// Calls `visit` with all expressions of the raw statements, including the subexpressions
+fn visit_raw_expressions<'s>(
+    statements: impl Iterator<Item = &'s Statement>,
+    visit: &mut dyn FnMut(&ast::Expression),
+) {
+    for statement in statements {
+        if let Statement::Raw(statement) = statement {
+            statement.walk_expressions(visit);
         }
+    }
+}
 
-        let regex_struct_init = Regex::new(
-            r#"(?x)^\s*
-            (?P<struct_name>.+)\s*\(\{\s*
-            (?P<args>.+)\s*\}\)\s*$"#,
-        )
-        .unwrap();
-        if regex_struct_init.is_match(raw) {
-            let struct_name_raw = capture_regex(&regex_struct_init, raw, "struct_name").unwrap();
-            let mut args_string = trim(&capture_regex(&regex_struct_init, raw, "args").unwrap());
-            args_string = args_string.replace(": ", ":");
-            args_string = args_string.replace(", ", ",");
-
-            if args_string.contains(':') {
-                // named params
-                let args_raw = split(&args_string, ",", None);
-                let regex_named_param = Regex::new(
-                    r#"(?x)^\s*
-                    (?P<param_name>.+)\s*:\s*
-                    (?P<value>.+)\s*$"#,
-                )
-                .unwrap();
-                let args = args_raw
-                    .iter()
-                    .map(|raw| {
-                        let param_name =
-                            capture_regex(&regex_named_param, raw, "param_name").unwrap();
-                        let value_raw = capture_regex(&regex_named_param, raw, "value").unwrap();
-                        let value = self.parse_expression(
-                            &value_raw,
-                            constructor,
-                            enclosed_expressions.clone(),
-                        );
-                        Expression::StructArg(param_name, bx!(value))
-                    })
-                    .collect::<Vec<Expression>>();
-                return Expression::StructInit(struct_name_raw, args)
-            } else {
-                let args_raw = split(&args_string, ",", None);
-                let mut args = Vec::default();
-                for (i, raw) in args_raw.iter().enumerate() {
-                    let value =
-                        self.parse_expression(raw, constructor, enclosed_expressions.clone());
-                    let param_name = self
-                        .structs
-                        .get(&struct_name_raw)
-                        .unwrap_or_else(|| panic!("Struct {struct_name_raw} not defined"))
-                        .fields[i]
-                        .name
-                        .clone();
-
-                    args.push(Expression::StructArg(param_name, bx!(value)));
-                }
-                return Expression::StructInit(struct_name_raw, args)
+/// Adds the errors defined outside of contracts which are used by the statements
+///
+/// `errors` the errors of the contract or library using the statements
+/// `shared` the errors defined outside of contracts
+/// `statements` the raw statements of the contract or library
+fn add_used_errors<'s>(
+    errors: &mut Vec<CustomError>,
+    shared: &[CustomError],
+    statements: impl Iterator<Item = &'s Statement>,
+) {
+    let mut called = HashSet::<String>::new();
+    visit_raw_expressions(statements, &mut |expression| {
+        if let ast::Expression::Call(callee, ..) | ast::Expression::NamedCall(callee, ..) = expression
+        {
+            if let ast::Expression::Identifier(name, _) = callee.as_ref() {
+                called.insert(name.clone());
             }
         }
-
-        if REGEX_FUNCTION_CALL.is_match(raw) {
-            return self.parse_function_call(raw, constructor, enclosed_expressions)
+    });
+    for error in shared.iter() {
+        if called.contains(&error.name) && !errors.iter().any(|defined| defined.name == error.name) {
+            errors.push(error.clone());
         }
+    }
+}
 
-        let regex_new_array = Regex::new(
-            r#"(?x)^\s*new\s+(?P<array_type>.+?)\s*
-            \[\s*\]\s*\((?P<array_size>.+?)\s*\)\s*$"#,
-        )
-        .unwrap();
-        if regex_new_array.is_match(raw) {
-            let array_type_raw = capture_regex(&regex_new_array, raw, "array_type").unwrap();
-            let array_size_raw = capture_regex(&regex_new_array, raw, "array_size").unwrap();
-
-            let array_type = self.convert_variable_type(array_type_raw);
-            let array_size =
-                self.parse_expression(&array_size_raw, constructor, enclosed_expressions);
-            return Expression::NewArray(array_type, bx!(array_size))
+/// returns the name of the emitted event, eg. `Transfer` of `emit Transfer(from, to, value)`
+fn event_name(event: &ast::Expression) -> Option<String> {
+    let callee = match event {
+        ast::Expression::Call(callee, ..) | ast::Expression::NamedCall(callee, ..) => callee,
+        _ => return None,
+    };
+    match callee.as_ref() {
+        ast::Expression::Identifier(name, _) | ast::Expression::Member(_, name, _) => {
+            Some(name.clone())
         }
+        _ => None,
+    }
+}
 
-        let regex_type = Regex::new(
-            r#"(?x)
-            ^\s*type\s*\(\s*(?P<selector>.+?)
-            \)\.(?P<member>.+?)\s*$"#,
-        )
-        .unwrap();
-        if regex_type.is_match(raw) {
-            let selector_raw = capture_regex(&regex_type, raw, "selector").unwrap();
-            let member_raw = capture_regex(&regex_type, raw, "member").unwrap();
-
-            let selector =
-                self.parse_expression(&selector_raw, constructor, enclosed_expressions.clone());
-            let member = self.parse_expression(&member_raw, constructor, enclosed_expressions);
-
-            return Expression::WithSelector(bx!(selector), bx!(member))
-        }
+/// returns the visibility of the function or the state variable, internal if it is not declared
+fn convert_visibility(visibility: Option<ast::Visibility>) -> Visibility {
+    match visibility {
+        Some(ast::Visibility::External) => Visibility::External,
+        Some(ast::Visibility::Public) => Visibility::Public,
+        Some(ast::Visibility::Private) => Visibility::Private,
+        Some(ast::Visibility::Internal) | None => Visibility::Internal,
+    }
+}
 
-        let regex_arithmetic = Regex::new(
-            r#"(?x)
-            ^\s*(?P<left>[^+\-]*?)
-            \s*(?P<operation>[/+\-*%][*]*)
-            \s*(?P<right>[^+\-=].*)
-            \s*$"#,
-        )
-        .unwrap();
-        if regex_arithmetic.is_match(raw) {
-            let left_raw = capture_regex(&regex_arithmetic, raw, "left").unwrap();
-            let right_raw = capture_regex(&regex_arithmetic, raw, "right").unwrap();
-            let operation_raw = capture_regex(&regex_arithmetic, raw, "operation").unwrap();
-            let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
-            let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
-            let operation = *OPERATIONS.get(&operation_raw).unwrap();
+/// returns true if the ink! type is an array, `Vec` or a fixed size array
+fn is_array_type(variable_type: &str) -> bool {
+    variable_type.starts_with("Vec<") || variable_type.starts_with('[')
+}
 
-            return Expression::Arithmetic(bx!(left), bx!(right), operation)
+/// returns the type of the elements of the ink! array, or of the values of the mapping,
+/// the nested mapping indexed by its first key has the type of the mapping of the remaining
+/// keys, eg. `Mapping<B, V>` of `Mapping<(A, B), V>`
+fn element_type(collection: &str) -> Option<String> {
+    if let Some(element) = collection
+        .strip_prefix("Vec<")
+        .and_then(|element| element.strip_suffix('>'))
+    {
+        return Some(element.to_owned())
+    }
+    if let Some(array) = collection
+        .strip_prefix('[')
+        .and_then(|array| array.strip_suffix(']'))
+    {
+        return array.rsplit_once("; ").map(|(element, _)| element.to_owned())
+    }
+    let mapping = collection.strip_prefix("Mapping<")?.strip_suffix('>')?;
+    let [key, value] = split_type_arguments(mapping)[..] else {
+        return None
+    };
+    match key
+        .strip_prefix('(')
+        .and_then(|keys| keys.strip_suffix(')'))
+        .map(split_type_arguments)
+    {
+        Some(keys) if keys.len() > 2 => {
+            Some(format!("Mapping<({}), {value}>", keys[1..].join(", ")))
         }
+        Some(keys) => Some(format!("Mapping<{}, {value}>", keys.get(1)?)),
+        None => Some(value.to_owned()),
+    }
+}
 
-        let regex_logical = Regex::new(
-            r#"(?x)
-            ^\s*(?P<left>.+?)
-            \s*(?P<operation>[|&]+)\s*
-            (?P<right>.+)
-            \s*$"#,
-        )
-        .unwrap();
-        if regex_logical.is_match(raw) {
-            let left_raw = capture_regex(&regex_logical, raw, "left").unwrap();
-            let operation_raw = capture_regex(&regex_logical, raw, "operation").unwrap();
-            let right_raw = capture_regex(&regex_logical, raw, "right").unwrap();
-            let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
-            let operation = *OPERATIONS.get(&operation_raw).unwrap();
-            let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
-
-            return Expression::Logical(bx!(left), operation, bx!(right))
-        }
-
-        if REGEX_TERNARY.is_match(raw) {
-            return self.parse_ternary(raw, constructor, enclosed_expressions)
-        }
-
-        if REGEX_BOOLEAN.is_match(raw) {
-            let condition = self.parse_condition(raw, constructor, false, enclosed_expressions);
-            return Expression::Condition(bx!(condition))
-        }
-
-        let regex_with_selector =
-            Regex::new(r#"(?x)^\s*(?P<left>.+?)\.(?P<right>.+?);*\s*$"#).unwrap();
-        if regex_with_selector.is_match(raw) {
-            let left_raw = capture_regex(&regex_with_selector, raw, "left").unwrap();
-            let right_raw = capture_regex(&regex_with_selector, raw, "right").unwrap();
-            let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
-            let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
-
-            match &right {
-                Expression::FunctionCall(function_name, expressions, _, external) => {
-                    return Expression::WithSelector(
-                        bx!(left),
-                        bx!(Expression::FunctionCall(
-                            function_name.clone(),
-                            expressions.clone(),
-                            None,
-                            *external,
-                        )),
-                    )
-                }
-                Expression::Member(member_name, _) => {
-                    return Expression::WithSelector(
-                        bx!(left),
-                        bx!(Expression::Member(member_name.clone(), None)),
-                    )
-                }
-                _ => {}
-            };
-
-            return Expression::WithSelector(bx!(left), bx!(right))
+/// returns the arguments of the generic ink! type separated by the top level commas,
+/// eg. `AccountId` and `Vec<(u8, u8)>` of `AccountId, Vec<(u8, u8)>`
+fn split_type_arguments(arguments: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, char) in arguments.char_indices() {
+        match char {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                split.push(arguments[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
         }
+    }
+    split.push(arguments[start..].trim());
+    split
+}
 
-        let regex_mapping = Regex::new(
-            r#"(?x)
-            ^\s*(?P<mapping_name>.+?)\s*
-            (?P<index>(\[\s*.+\s*\]))+?
-            \s*$"#,
-        )
-        .unwrap();
-        if regex_mapping.is_match(raw) {
-            let mapping_raw = capture_regex(&regex_mapping, raw, "mapping_name").unwrap();
-            let mapping =
-                self.parse_expression(&mapping_raw, constructor, enclosed_expressions.clone());
-            let indices_raw = capture_regex(&regex_mapping, raw, "index").unwrap();
-            let mut indices = Vec::<Expression>::new();
-            let mut buffer = String::new();
-            let mut open_braces = 0;
-            let mut close_braces = 0;
-
-            for ch in indices_raw.chars() {
-                match ch {
-                    BRACKET_OPEN => {
-                        if open_braces > close_braces {
-                            buffer.push(ch)
-                        }
-                        open_braces += 1;
-                    }
-                    BRACKET_CLOSE => {
-                        close_braces += 1;
-                        if open_braces == close_braces {
-                            indices.push(self.parse_expression(
-                                &buffer,
-                                constructor,
-                                enclosed_expressions.clone(),
-                            ));
-                            buffer.clear();
-                        } else {
-                            buffer.push(ch)
-                        }
-                    }
-                    _ => buffer.push(ch),
-                }
-            }
+/// returns the types of the keys and the type of the values of the ink! mapping,
+/// the keys of the nested mappings are flattened, or None if the type is not a mapping
+fn mapping_types(mapping: &str) -> Option<(Vec<String>, String)> {
+    let mapping = mapping.strip_prefix("Mapping<")?.strip_suffix('>')?;
+    let [key, value] = split_type_arguments(mapping)[..] else {
+        return None
+    };
+    let keys = match key.strip_prefix('(').and_then(|keys| keys.strip_suffix(')')) {
+        Some(keys) => split_type_arguments(keys),
+        None => vec![key],
+    };
+    Some((keys.into_iter().map(str::to_owned).collect(), value.to_owned()))
+}
 
-            return Expression::Mapping(bx!(mapping), indices, None)
-        }
+/// returns true if any of the types is the type `name` or contains it, eg. `Vec<Order>`
+fn names_type(types: &[String], name: &str) -> bool {
+    types.iter().any(|used_type| {
+        used_type
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| word == name)
+    })
+}
 
-        if REGEX_BINARY_SUFFIX.is_match(raw) {
-            let statement = self.parse_binary_operation(
-                raw,
-                constructor,
-                &REGEX_BINARY_SUFFIX,
-                enclosed_expressions.clone(),
-            );
-            if let Statement::Assign(member, _, _) = statement {
-                return member
+/// Marks the structs which are stored in the contract or exposed by its messages, events
+/// and errors, together with the structs nested in them
+///
+/// `structs` the structs known to the contract
+/// `stored` the types of the fields of the contract, extended by the types of the fields
+/// of the stored structs
+/// `exposed` the types of the parameters and the return values of the messages and of the
+/// fields of the events and the errors, extended by the types of the fields of the exposed structs
+fn mark_struct_usage(
+    structs: &mut [&mut Struct],
+    stored: &mut Vec<String>,
+    exposed: &mut Vec<String>,
+) {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for structure in structs.iter_mut() {
+            let field_types = structure.fields.iter().map(|field| field.field_type.clone());
+            if !structure.stored && names_type(stored, &structure.name) {
+                structure.stored = true;
+                stored.extend(field_types.clone());
+                changed = true;
+            }
+            if !structure.exposed && names_type(exposed, &structure.name) {
+                structure.exposed = true;
+                exposed.extend(field_types);
+                changed = true;
             }
         }
+    }
+}
 
-        if REGEX_BINARY_PREFIX.is_match(raw) {
-            let statement = self.parse_binary_operation(
-                raw,
-                constructor,
-                &REGEX_BINARY_PREFIX,
-                enclosed_expressions,
-            );
-            if let Statement::Assign(member, _, _) = statement {
-                return member
-            }
+/// Splits the array or the struct stored in a mapping from the access of its elements or its
+/// members, eg. of `owned[a][i]` or of `proposals[id].votes`
+///
+/// `local` the name of the local variable holding the value read from the mapping
+///
+/// returns the mapping read, eg. `owned[a]`, and the expression reading the value from
+/// the local variable, or None if the value is not stored in a mapping
+fn take_mapping(expression: &Expression, local: &str) -> Option<(Expression, Expression)> {
+    match expression {
+        Expression::Mapping(_, _, None) => {
+            Some((expression.clone(), Expression::Literal(local.to_owned())))
         }
+        Expression::Index(array, index) => {
+            let (mapping, array) = take_mapping(array, local)?;
+            Some((mapping, Expression::Index(bx!(array), index.clone())))
+        }
+        Expression::WithSelector(base, member)
+            if matches!(member.as_ref(), Expression::Member(..)) =>
+        {
+            let (mapping, base) = take_mapping(base, local)?;
+            Some((mapping, Expression::WithSelector(bx!(base), member.clone())))
+        }
+        _ => None,
+    }
+}
 
-        if let Some(contract_field) = self.storage.get(raw) {
-            if contract_field.constant {
-                return Expression::Constant(contract_field.name.clone())
-            }
+/// Stores the array or the struct read from a mapping back to the mapping after the assignment
+/// of its element or its member, eg. `owned[a][i] = value` or `proposals[id].votes = value`,
+/// other statements are returned unchanged
+fn store_entry(statement: Statement) -> Statement {
+    let Statement::Assign(left, value, operation) = statement else {
+        return statement
+    };
+    // the arrays are read into `array`, the structs into `entry`
+    let local = match &left {
+        Expression::Index(..) => "array",
+        _ => "entry",
+    };
+    match take_mapping(&left, local) {
+        Some((Expression::Mapping(mapping, indices, None), local_left))
+            if !matches!(local_left, Expression::Literal(_)) =>
+        {
+            let read = Expression::Mapping(mapping.clone(), indices.clone(), None);
+            let entry = Expression::Literal(local.to_owned());
+            Statement::Group(vec![
+                Statement::FunctionCall(Expression::Builtin(
+                    format!("let mut {local} = $0"),
+                    vec![read],
+                )),
+                Statement::Assign(local_left, value, operation),
+                Statement::FunctionCall(Expression::Mapping(mapping, indices, Some(bx!(entry)))),
+            ])
         }
+        _ => Statement::Assign(left, value, operation),
+    }
+}
 
-        let selector = self.get_selector(constructor, raw);
+/// The value of a constant expression, folded by the transpiler
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Constant {
+    Bool(bool),
+    // the absolute value and if the integer is negative
+    Integer(U256, bool),
+    Bytes(Vec<u8>),
+}
 
-        Expression::Member(raw.clone(), selector)
-    }
+/// The reason why a constant expression could not be folded
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum FoldError {
+    /// the value is not known to the transpiler, eg. the expression calls a function
+    Unknown,
+    /// the expression does not compile with solc, eg. it divides by zero
+    Invalid(String),
+}
 
-    /// Extracts enclosed expression from expression
-    ///
-    /// The function replaces all enclosed expressions with an expression identifier
-    /// and maps the enclosed expressions to a parsed expression
-    /// Then we can parse all the expressions in the correct order
-    ///
-    /// ```
-    /// let extracted = self.extract_parentheses("((1 + 2) + 3) + 4", false);
-    /// assert_eq!(extracted.0, String::from("___0___ + 4"));
-    /// assert_eq!(extracted.1, 1);
-    /// assert_eq!(extracted.2.get("___0___"), Some(
-    /// Expression::Arithmetic(Expression::Enclosed(Expression::Arithmetic(
-    /// Expression::Arithmetic(1, 2, Operation::Add)), 3, Operation::Add), 4, Operation::Add)
-    /// ));
-    /// ```
-    ///
-    /// `raw` the raw representation of the expression
-    /// `constructor` if the expression is inside a constructor
-    ///
-    /// Return `0` the statement without brackets (enclosed expressions are substituted with identifiers)
-    /// Return `1` the number of returned enclosed expressions
-    /// Return `2` the map of expression_identifier => Expression
-    fn extract_parentheses(
-        &mut self,
-        raw: &str,
-        constructor: bool,
-        square_brackets: bool,
-    ) -> (String, i32, HashMap<String, Expression>) {
-        let mut buffer_out = String::new();
-        let mut buffer_tmp = String::new();
-        let mut open_parentheses = 0;
-        let mut close_parentheses = 0;
-        let mut map = HashMap::<String, Expression>::new();
-        let mut args = 0;
-        let mut group_possible = true;
-        let mut group = false;
-
-        for ch in raw.chars() {
-            match ch {
-                PARENTHESIS_CLOSE if !square_brackets => {
-                    close_parentheses += 1;
-                }
-                PARENTHESIS_OPEN if !square_brackets => {
-                    open_parentheses += 1;
-                }
-                BRACKET_CLOSE if square_brackets => {
-                    close_parentheses += 1;
-                }
-                BRACKET_OPEN if square_brackets => {
-                    open_parentheses += 1;
-                }
-                _ => {}
+/// Folds the constant expression to its value, eg. `10**18`, `keccak256("MINTER_ROLE")`
+/// or `type(uint64).max`
+///
+/// `expression` the syntax tree of the expression
+/// `constants` the values of the constants defined before
+///
+/// returns `FoldError::Unknown` if the expression is not constant or its value is not known,
+/// `FoldError::Invalid` if the operation overflows or divides by zero
+fn fold_constant(
+    expression: &ast::Expression,
+    constants: &HashMap<String, Constant>,
+) -> Result<Constant, FoldError> {
+    let fold = |expression| fold_constant(expression, constants);
+    let known = |constant: Option<Constant>| constant.ok_or(FoldError::Unknown);
+    let overflow = || FoldError::Invalid(String::from("overflow of `uint256`"));
+    match expression {
+        ast::Expression::BoolLiteral(value, _) => Ok(Constant::Bool(*value)),
+        ast::Expression::NumberLiteral(number, None, _) if number.starts_with("0x") => {
+            let digits = number[2..].replace('_', "");
+            known(
+                U256::from_str_radix(&digits, 16)
+                    .ok()
+                    .map(|value| Constant::Integer(value, false)),
+            )
+        }
+        ast::Expression::NumberLiteral(number, unit, _) => {
+            let number = convert_number(number, unit.as_deref());
+            if !is_integer(&number) {
+                return Err(FoldError::Unknown)
             }
-            match ch {
-                ASTERISK | SLASH | EQUALS | PLUS | MINUS | AMPERSAND | PIPE | PERCENT
-                | EXCLAMAITON
-                    if open_parentheses == close_parentheses =>
-                {
-                    group_possible = true;
-                    buffer_out.push(ch);
+            U256::from_dec_str(&number)
+                .map(|value| Constant::Integer(value, false))
+                .map_err(|_| overflow())
+        }
+        ast::Expression::HexLiteral(content, _) => known(decode_hex(content).map(Constant::Bytes)),
+        ast::Expression::StringLiteral(content, _) => {
+            let content = &content[1..content.len() - 1];
+            known((!content.contains('\\')).then(|| Constant::Bytes(content.as_bytes().to_vec())))
+        }
+        ast::Expression::Identifier(name, _) => known(constants.get(name).cloned()),
+        ast::Expression::Parenthesis(inner, _) => fold(inner),
+        ast::Expression::Prefix(operation, operand, _) => {
+            match (operation.as_str(), fold(operand)?) {
+                ("-", Constant::Integer(value, negative)) => {
+                    Ok(Constant::Integer(value, !negative && !value.is_zero()))
                 }
-                SPACE if group_possible => {
-                    buffer_out.push(ch);
+                ("!", Constant::Bool(value)) => Ok(Constant::Bool(!value)),
+                _ => Err(FoldError::Unknown),
+            }
+        }
+        ast::Expression::Binary(left, operation, right, _) => {
+            fold_binary(fold(left)?, operation, fold(right)?)
+        }
+        // `type(uint64).max`
+        ast::Expression::Member(base, member, _) => {
+            let name = match base.as_ref() {
+                ast::Expression::Call(callee, args, _) if callee.to_string() == "type" => {
+                    match args.as_slice() {
+                        [ast::Expression::Identifier(name, _)] => name,
+                        _ => return Err(FoldError::Unknown),
+                    }
                 }
-                PARENTHESIS_OPEN if group_possible && !square_brackets => {
-                    group = true;
-                    group_possible = false;
+                _ => return Err(FoldError::Unknown),
+            };
+            let size = integer_size(name).ok_or(FoldError::Unknown)?;
+            let signed = !name.starts_with('u');
+            let bits = if signed { size - 1 } else { size };
+            let max = if bits == 256 {
+                U256::MAX
+            } else {
+                (U256::one() << bits) - 1
+            };
+            match member.as_str() {
+                "max" => Ok(Constant::Integer(max, false)),
+                "min" if signed => Ok(Constant::Integer(max + 1, true)),
+                "min" => Ok(Constant::Integer(U256::zero(), false)),
+                _ => Err(FoldError::Unknown),
+            }
+        }
+        ast::Expression::Call(callee, args, _) => {
+            let name = callee.to_string();
+            match (name.as_str(), args.as_slice()) {
+                ("keccak256", [arg]) => {
+                    let Constant::Bytes(data) = fold(arg)? else {
+                        return Err(FoldError::Unknown)
+                    };
+                    let mut hash = [0u8; 32];
+                    let mut keccak = Keccak::v256();
+                    keccak.update(&data);
+                    keccak.finalize(&mut hash);
+                    Ok(Constant::Bytes(hash.to_vec()))
                 }
-                BRACKET_OPEN if square_brackets && !group => {
-                    group = true;
-                    buffer_out.push(ch);
+                ("sha256", [arg]) => {
+                    let Constant::Bytes(data) = fold(arg)? else {
+                        return Err(FoldError::Unknown)
+                    };
+                    Ok(Constant::Bytes(Sha256::digest(data).to_vec()))
                 }
-                PARENTHESIS_CLOSE
-                    if group && open_parentheses == close_parentheses && !square_brackets =>
-                {
-                    group = false;
-                    let arg_name = format!("___{args}___");
-                    let expression = self.parse_expression(&buffer_tmp, constructor, None);
-                    map.insert(arg_name.clone(), expression);
-                    buffer_out.push_str(&arg_name);
-                    buffer_tmp.clear();
-                    args += 1;
-                }
-                BRACKET_CLOSE
-                    if group && open_parentheses == close_parentheses && square_brackets =>
-                {
-                    group = false;
-                    if !buffer_tmp.is_empty() {
-                        let arg_name = format!("___0{args}___");
-                        let expression = self.parse_expression(&buffer_tmp, constructor, None);
-                        map.insert(arg_name.clone(), expression);
-                        buffer_out.push_str(&arg_name);
-                        args += 1;
+                ("abi.encodePacked", args) => {
+                    let mut packed = Vec::new();
+                    for arg in args.iter() {
+                        packed.extend(pack_constant(arg, constants)?);
                     }
-                    buffer_out.push(ch);
-                    buffer_tmp.clear();
+                    Ok(Constant::Bytes(packed))
                 }
-                _ => {
-                    if group_possible {
-                        group_possible = false;
-                    }
-                    if group {
-                        buffer_tmp.push(ch);
-                    } else {
-                        buffer_out.push(ch);
-                    }
+                // the casts keep the value, it is checked against the type of the constant
+                (name, [arg])
+                    if integer_size(name).is_some()
+                        || name.strip_prefix("bytes").is_some_and(is_integer)
+                        || matches!(name, "address" | "payable") =>
+                {
+                    fold(arg)
                 }
+                _ => Err(FoldError::Unknown),
             }
         }
-
-        (buffer_out, args, map)
+        _ => Err(FoldError::Unknown),
     }
+}
 
-    /// Parses a solidity ternary expression
-    ///
-    /// `raw` the raw representation of the expression
-    /// `constructor` if the expression is inside a constructor
-    /// `enclosed_expressions` the previously parsed enclosed expressions
-    ///
-    /// Returns the expression as `Expression::Ternary`
-    fn parse_ternary(
-        &mut self,
-        raw: &str,
-        constructor: bool,
-        enclosed_expressions: Option<HashMap<String, Expression>>,
-    ) -> Expression {
-        let condition_raw = capture_regex(&REGEX_TERNARY, raw, "condition").unwrap();
-        let if_true_raw = capture_regex(&REGEX_TERNARY, raw, "if_true").unwrap();
-        let if_false_raw = capture_regex(&REGEX_TERNARY, raw, "if_false").unwrap();
-
-        let condition = self.parse_condition(
-            &condition_raw,
-            constructor,
-            false,
-            enclosed_expressions.clone(),
-        );
-        let if_true =
-            self.parse_expression(&if_true_raw, constructor, enclosed_expressions.clone());
-        let if_false = self.parse_expression(&if_false_raw, constructor, enclosed_expressions);
-        Expression::Ternary(bx!(condition), bx!(if_true), bx!(if_false))
+/// Packs the folded argument of `abi.encodePacked` as solc does, the integers are packed
+/// with the size of the type they are cast to, eg. `uint16(1)` is packed to `[0x00, 0x01]`
+///
+/// `arg` the syntax tree of the argument
+/// `constants` the values of the constants defined before
+fn pack_constant(
+    arg: &ast::Expression,
+    constants: &HashMap<String, Constant>,
+) -> Result<Vec<u8>, FoldError> {
+    let size = match arg {
+        ast::Expression::Call(callee, args, _) if args.len() == 1 => {
+            integer_size(&callee.to_string()).filter(|_| is_integer_type(&callee.to_string()))
+        }
+        _ => None,
+    };
+    match (fold_constant(arg, constants)?, size) {
+        (Constant::Bytes(data), _) => Ok(data),
+        (Constant::Bool(value), _) => Ok(vec![value as u8]),
+        (Constant::Integer(value, negative), Some(size)) => {
+            // the negative integers are packed in two's complement
+            let value = if negative {
+                (!value).overflowing_add(U256::one()).0
+            } else {
+                value
+            };
+            let mut bytes = [0u8; 32];
+            value.to_big_endian(&mut bytes);
+            Ok(bytes[32 - size as usize / 8..].to_vec())
+        }
+        // the literals have no size, solc does not pack them
+        (Constant::Integer(..), None) => Err(FoldError::Unknown),
     }
+}
 
-    /// Parses a solidity binary operation
-    ///
-    /// `line` the solidity representation of the binary operation statement
-    /// `constructor` if the statement is inside a constructor
-    /// `regex` the regex we use (suffix or prefix)
-    /// `enclosed_expressions` the previously parsed enclosed expressions
-    ///
-    /// Return the statement in form of `Statement::Assign`
-    fn parse_binary_operation(
-        &mut self,
-        line: &str,
-        constructor: bool,
-        regex: &Regex,
-        enclosed_expressions: Option<HashMap<String, Expression>>,
-    ) -> Statement {
-        let member_raw = capture_regex(regex, line, "value").unwrap();
-        let operation_raw =
-            capture_regex(regex, line, "operation").unwrap_or_else(|| String::from("="));
-
-        let member = self.parse_expression(&member_raw, constructor, enclosed_expressions);
-        let original_operation = *OPERATIONS.get(&operation_raw).unwrap();
-        let operation = match original_operation {
-            Operation::AddOne => Operation::AddAssign,
-            Operation::SubtractOne => Operation::SubtractAssign,
-            _ => original_operation,
-        };
-
-        Statement::Assign(member, Expression::Literal(String::from("1")), operation)
+/// returns true if the integer fits the integer type of the size
+///
+/// `value` the absolute value of the integer
+/// `negative` if the integer is negative
+/// `bits` the size of the type
+/// `signed` if the type is signed
+fn fits_integer(value: &U256, negative: bool, bits: usize, signed: bool) -> bool {
+    let limit = if signed { bits - 1 } else { bits };
+    match (signed, negative) {
+        (false, true) => false,
+        (true, true) => value.bits() <= limit || *value == U256::one() << limit,
+        _ => value.bits() <= limit,
     }
+}
 
-    /// Parses a solidity function call
-    ///
-    /// `line` the solidity representation of the function call
-    /// `constructor` if the expression is inside a constructor
-    /// `enclosed_expressions` the previously parsed enclosed expressions
-    ///
-    /// Return the expression in form of `Expression::FunctionCall`
-    fn parse_function_call(
-        &mut self,
-        line: &str,
-        constructor: bool,
-        enclosed_expressions: Option<HashMap<String, Expression>>,
-    ) -> Expression {
-        let function_name_raw = capture_regex(&REGEX_FUNCTION_CALL, line, "function_name").unwrap();
-        let args_raw = capture_regex(&REGEX_FUNCTION_CALL, line, "args").unwrap();
-        let mut args = Vec::<Expression>::new();
-        let mut buffer = String::new();
-        let mut open_parentheses = 0;
-        let mut close_parenthesis = 0;
-
-        if TYPES.contains_key(&function_name_raw.as_str()) {
-            let the_type = TYPES.get(&function_name_raw.as_str()).unwrap();
-            if let Some(unique_cast) = the_type.1 {
-                return Expression::Cast(
-                    true,
-                    unique_cast.to_string(),
-                    bx!(self.parse_expression(&args_raw, constructor, enclosed_expressions)),
-                )
-            } else {
-                return Expression::Cast(
-                    false,
-                    the_type.0.to_string(),
-                    bx!(self.parse_expression(&args_raw, constructor, enclosed_expressions)),
-                )
+/// Folds the binary operation of constant operands
+///
+/// returns `FoldError::Unknown` if the operation is not supported, `FoldError::Invalid`
+/// if it overflows `uint256` or divides by zero
+fn fold_binary(left: Constant, operation: &str, right: Constant) -> Result<Constant, FoldError> {
+    let (left, left_negative, right, right_negative) = match (left, right) {
+        (Constant::Integer(left, left_negative), Constant::Integer(right, right_negative)) => {
+            (left, left_negative, right, right_negative)
+        }
+        (Constant::Bool(left), Constant::Bool(right)) => {
+            return match operation {
+                "&&" => Ok(Constant::Bool(left && right)),
+                "||" => Ok(Constant::Bool(left || right)),
+                "==" => Ok(Constant::Bool(left == right)),
+                "!=" => Ok(Constant::Bool(left != right)),
+                _ => Err(FoldError::Unknown),
             }
         }
+        _ => return Err(FoldError::Unknown),
+    };
+    let integer = |value: Option<U256>, negative: bool| {
+        let value = value.ok_or_else(|| {
+            FoldError::Invalid(String::from("overflow of `uint256`"))
+        })?;
+        Ok(Constant::Integer(value, negative && !value.is_zero()))
+    };
+    // the sum of the absolute values with their signs
+    let add = |left: U256, left_negative: bool, right: U256, right_negative: bool| {
+        if left_negative == right_negative {
+            integer(left.checked_add(right), left_negative)
+        } else if left >= right {
+            integer(Some(left - right), left_negative)
+        } else {
+            integer(Some(right - left), right_negative)
+        }
+    };
+    if matches!(operation, "/" | "%") && right.is_zero() {
+        return Err(FoldError::Invalid(String::from("division by zero")))
+    }
+    let unsigned = !left_negative && !right_negative;
+    match operation {
+        "+" => add(left, left_negative, right, right_negative),
+        "-" => add(left, left_negative, right, !right_negative),
+        "*" => integer(left.checked_mul(right), left_negative != right_negative),
+        "/" => integer(left.checked_div(right), left_negative != right_negative),
+        "%" => integer(left.checked_rem(right), left_negative),
+        "**" if !right_negative => {
+            let (value, overflow) = left.overflowing_pow(right);
+            integer((!overflow).then_some(value), left_negative && right.bit(0))
+        }
+        "<<" if unsigned && right < U256::from(256) => {
+            let value = left << right.as_usize();
+            integer(((value >> right.as_usize()) == left).then_some(value), false)
+        }
+        ">>" if unsigned && right < U256::from(256) => {
+            integer(Some(left >> right.as_usize()), false)
+        }
+        ">>" if unsigned => integer(Some(U256::zero()), false),
+        "&" if unsigned => integer(Some(left & right), false),
+        "|" if unsigned => integer(Some(left | right), false),
+        "^" if unsigned => integer(Some(left ^ right), false),
+        _ => {
+            // the comparison of the signed values
+            let ordering = match (left_negative, right_negative) {
+                (false, false) => left.cmp(&right),
+                (true, true) => right.cmp(&left),
+                (false, true) => std::cmp::Ordering::Greater,
+                (true, false) => std::cmp::Ordering::Less,
+            };
+            let result = match operation {
+                "==" => ordering.is_eq(),
+                "!=" => ordering.is_ne(),
+                "<" => ordering.is_lt(),
+                "<=" => ordering.is_le(),
+                ">" => ordering.is_gt(),
+                ">=" => ordering.is_ge(),
+                _ => return Err(FoldError::Unknown),
+            };
+            Ok(Constant::Bool(result))
+        }
+    }
+}
 
-        for ch in args_raw.chars() {
-            match ch {
-                PARENTHESIS_OPEN => {
-                    open_parentheses += 1;
-                    buffer.push(ch)
-                }
-                PARENTHESIS_CLOSE => {
-                    close_parenthesis += 1;
-                    buffer.push(ch)
-                }
-                COMMA => {
-                    if open_parentheses == close_parenthesis {
-                        args.push(self.parse_expression(
-                            &trim(&buffer),
-                            constructor,
-                            enclosed_expressions.clone(),
-                        ));
-                        buffer.clear();
-                    } else {
-                        buffer.push(ch)
-                    }
+/// returns the Rust literal of the folded constant of the ink! type, or None if the value
+/// does not fit the type
+fn render_constant(constant: &Constant, field_type: &str) -> Option<String> {
+    let bytes_size = field_type
+        .strip_prefix("[u8; ")
+        .and_then(|size| size.strip_suffix(']'))
+        .and_then(|size| size.parse::<usize>().ok());
+    match (constant, field_type) {
+        (Constant::Bool(value), "bool") => Some(value.to_string()),
+        (Constant::Integer(value, false), "U256") => {
+            Some(format!("U256([{}, {}, {}, {}])", value.0[0], value.0[1], value.0[2], value.0[3]))
+        }
+        // the constant addresses are stored as their bytes, `AccountId::from` is not `const`
+        (Constant::Integer(value, false), "AccountId") if value.is_zero() => {
+            Some(render_bytes(&[0; 32]))
+        }
+        (Constant::Integer(value, negative), _) => {
+            if let Some(size) = bytes_size {
+                // the hex number of the size of the bytes, eg. `bytes4 ID = 0x01ffc9a7`
+                if *negative || value.bits() > 8 * size {
+                    return None
                 }
-                _ => buffer.push(ch),
+                let mut bytes = [0u8; 32];
+                value.to_big_endian(&mut bytes);
+                return Some(render_bytes(&bytes[32 - size..]))
             }
+            let bits = field_type
+                .strip_prefix(['u', 'i'])
+                .and_then(|bits| bits.parse::<usize>().ok())
+                .filter(|bits| matches!(bits, 8 | 16 | 32 | 64 | 128))?;
+            let signed = field_type.starts_with('i');
+            let sign = if *negative { "-" } else { "" };
+            fits_integer(value, *negative, bits, signed).then(|| format!("{sign}{value}"))
         }
-
-        if !trim(&buffer).is_empty() {
-            args.push(self.parse_expression(&buffer, constructor, enclosed_expressions));
+        // the constant strings are `&'static str`
+        (Constant::Bytes(data), "String") => {
+            String::from_utf8(data.clone())
+                .ok()
+                .map(|string| format!("{string:?}"))
         }
+        // the strings are aligned to the left of the bytes, eg. `bytes32 NAME = "name"`
+        (Constant::Bytes(data), _) if bytes_size.is_some_and(|size| data.len() <= size) => {
+            let mut bytes = data.clone();
+            bytes.resize(bytes_size.unwrap_or_default(), 0);
+            Some(render_bytes(&bytes))
+        }
+        _ => None,
+    }
+}
 
-        let selector = if self.functions.get(&function_name_raw).is_some() {
-            Some(selector!(constructor))
-        } else {
-            None
-        };
+/// returns the Rust array literal of the bytes, eg. `[0x01, 0xff]`
+fn render_bytes(bytes: &[u8]) -> String {
+    if bytes.iter().all(|byte| *byte == 0) {
+        return format!("[0; {}]", bytes.len())
+    }
+    let bytes = bytes
+        .iter()
+        .map(|byte| format!("0x{byte:02x}"))
+        .collect::<Vec<_>>();
+    format!("[{}]", bytes.join(", "))
+}
 
-        return Expression::FunctionCall(
-            function_name_raw.clone(),
-            args,
-            selector,
-            *self.functions.get(&function_name_raw).unwrap_or(&true),
-        )
+/// returns the bytes of the hex string, or None if it is not valid
+fn decode_hex(content: &str) -> Option<Vec<u8>> {
+    let digits = content.replace('_', "");
+    if !digits.len().is_multiple_of(2) {
+        return None
     }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok())
+        .collect()
+}
 
-    /// Converts solidity variable type to ink! variable type (eg. address -> AccountId, uint -> u128, ...)
-    ///
-    /// `arg_type` solidity argument type
-    /// `imports` the set of imports of the contract
-    ///
-    /// return the converted type
-    fn convert_variable_type(&mut self, arg_type: String) -> String {
-        // removes array braces from the type
-        let (no_array_arg_type, is_vec) =
-            if arg_type.substring(arg_type.len() - 2, arg_type.len()) == "[]" {
-                (arg_type.substring(0, arg_type.len() - 2), true)
-            } else {
-                (arg_type.as_str(), false)
-            };
-        let regex_mapping: Regex = Regex::new(
-            r#"(?x)^\s*mapping\(
-                (?P<type_from>.+?)=>
-                (?P<type_to>.+)
-                \)\s*$"#,
-        )
-        .unwrap();
-        if regex_mapping.is_match(&arg_type) {
-            self.imports
-                .insert(String::from("use openbrush::storage::Mapping;\n"));
-            let mut from_raw = capture_regex(&regex_mapping, &arg_type, "type_from").unwrap();
-            let mut to_raw = capture_regex(&regex_mapping, &arg_type, "type_to").unwrap();
+/// returns `todo!` with the Solidity code of the expression, so the generated code still compiles
+fn todo(expression: &ast::Expression) -> Expression {
+    // the braces would be taken for the arguments of the format string
+    let code = expression.to_string().replace('{', "{{").replace('}', "}}");
+    let message = format!("Sol2Ink Not Implemented yet: {code}");
+    Expression::Literal(format!("todo!({message:?})"))
+}
+
+/// returns true if the values of the Solidity type are `Copy` in ink!, so they are read
+/// from the storage without cloning
+///
+/// `abi_types` the types of the user defined types in the ABI, structs are tuples
+fn is_copy(type_name: &ast::TypeName, abi_types: &HashMap<String, String>) -> bool {
+    match type_name {
+        ast::TypeName::Elementary(name) => name != "string" && name != "bytes",
+        ast::TypeName::UserDefined(path) => {
+            !path
+                .last()
+                .and_then(|name| abi_types.get(name))
+                .is_some_and(|abi_type| abi_type.starts_with('('))
+        }
+        ast::TypeName::Array(element, Some(_)) => is_copy(element, abi_types),
+        ast::TypeName::Array(_, None) | ast::TypeName::Mapping(..) => false,
+        ast::TypeName::Function(..) => true,
+    }
+}
+
+/// returns the type of the Solidity type in the ABI, eg. `uint256` of `uint`
+///
+/// `abi_types` the types of the user defined types in the ABI
+fn abi_type(type_name: &ast::TypeName, abi_types: &HashMap<String, String>) -> String {
+    match type_name {
+        ast::TypeName::Elementary(name) => {
+            match name.as_str() {
+                "uint" => String::from("uint256"),
+                "int" => String::from("int256"),
+                "byte" => String::from("bytes1"),
+                _ => name.clone(),
+            }
+        }
+        ast::TypeName::UserDefined(path) => {
+            let name = path.last().cloned().unwrap_or_default();
+            abi_types.get(&name).cloned().unwrap_or(name)
+        }
+        ast::TypeName::Array(element, size) => {
+            let size = size.as_ref().map(|size| size.to_string()).unwrap_or_default();
+            format!("{}[{size}]", abi_type(element, abi_types))
+        }
+        ast::TypeName::Mapping(..) => String::from("mapping"),
+        ast::TypeName::Function(..) => String::from("function"),
+    }
+}
 
-            let mut from_vec = vec![self.convert_variable_type(from_raw)];
-            while regex_mapping.is_match(&to_raw) {
-                from_raw = capture_regex(&regex_mapping, &to_raw, "type_from").unwrap();
-                to_raw = capture_regex(&regex_mapping, &to_raw, "type_to").unwrap();
-                from_vec.push(self.convert_variable_type(from_raw));
+/// returns the types of the parameters in the Solidity signature,
+/// eg. `address` and `uint256` of `transfer(address,uint256)`
+fn signature_params(signature: &str) -> Vec<&str> {
+    let params = match signature.split_once('(') {
+        Some((_, params)) => params.strip_suffix(')').unwrap_or(params),
+        None => return Vec::new(),
+    };
+    let mut out = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    // tuples of structs contain commas
+    for (i, char) in params.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                out.push(&params[start..i]);
+                start = i + 1;
             }
+            _ => {}
+        }
+    }
+    if !params.is_empty() {
+        out.push(&params[start..]);
+    }
+    out
+}
 
-            let to = self.convert_variable_type(to_raw);
+/// returns the selector of the Solidity function, the first four bytes of the keccak hash
+/// of its signature
+fn solidity_selector(signature: &str) -> u32 {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(signature.as_bytes());
+    keccak.finalize(&mut hash);
+    u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
 
-            let from = if from_vec.len() > 1 {
-                format!("({})", from_vec.join(", "))
-            } else {
-                from_vec[0].to_owned()
-            };
-            return format!("Mapping<{}, {}>", from, to)
+/// Renames the overloaded functions after their parameters, Rust does not support overloading
+///
+/// The functions with the same signature, eg. a function and the function overriding it,
+/// get the same name, and the overload without parameters keeps its name
+///
+/// `headers` the headers of the functions defined in the same scope
+/// `mangling` the naming of the overloads
+/// `selectors` the overloaded messages get the selectors of their signatures with
+/// `Selectors::Overloads`, all functions but `receive` and `fallback` with `Selectors::Solidity`
+fn mangle_overloads<'h>(
+    headers: impl IntoIterator<Item = &'h mut FunctionHeader>,
+    mangling: Mangling,
+    selectors: Selectors,
+) {
+    let mut headers = headers.into_iter().collect::<Vec<_>>();
+    let mut signatures = HashMap::<String, Vec<String>>::new();
+    for header in headers.iter() {
+        let overloads = signatures
+            .entry(header.solidity_name().to_owned())
+            .or_default();
+        if !overloads.contains(&header.signature) {
+            overloads.push(header.signature.clone());
         }
-        let output_type = match convert_int(no_array_arg_type.to_string()).as_str() {
-            str if str.contains("uint") => str.replace("uint", "u"),
-            str if str.contains("int") => str.replace("int", "i"),
-            str if TYPES.contains_key(str) => {
-                let the_type = TYPES.get(str).unwrap();
-                if let Some(import) = the_type.2 {
-                    self.imports.insert(format!("use {import};\n"));
-                }
-                the_type.0.to_string()
-            }
-            _ => no_array_arg_type.to_owned(),
-        };
-        return if is_vec {
-            self.imports
-                .insert(String::from("use ink_prelude::vec::Vec;\n"));
-            format!("Vec<{}>", output_type)
+    }
+    for header in headers.iter_mut() {
+        // `receive` and `fallback` are keywords, they have no selector in Solidity
+        if selectors == Selectors::Solidity
+            && !matches!(header.solidity_name(), "receive" | "fallback")
+        {
+            header.selector = Some(solidity_selector(&header.signature));
+        }
+        let overloads = &signatures[header.solidity_name()];
+        if overloads.len() < 2 {
+            continue
+        }
+        let arity = header.params.len();
+        let same_arity = overloads
+            .iter()
+            .filter(|signature| signature_params(signature).len() == arity)
+            .count();
+        let name = header.solidity_name();
+        header.name = if arity == 0 {
+            name.to_owned()
+        } else if mangling == Mangling::Arity && same_arity == 1 {
+            format!("{name}_{arity}")
         } else {
-            output_type
+            // `uint256[2]` is named `uint256_array2`
+            let types = header
+                .params
+                .iter()
+                .map(|param| {
+                    param
+                        .solidity_type
+                        .replace('[', "_array")
+                        .split(|char: char| !char.is_ascii_alphanumeric())
+                        .filter(|part| !part.is_empty())
+                        .collect::<Vec<_>>()
+                        .join("_")
+                })
+                .collect::<Vec<_>>();
+            format!("{name}_{}", types.join("_"))
+        };
+        if selectors == Selectors::Overloads {
+            header.selector = Some(solidity_selector(&header.signature));
         }
     }
+}
 
-    /// returns the selctor of the field (function or a variable)
-    ///
-    /// `constructor` if we access this field from a constructor
-    /// `field_name` the name of the field
-    ///
-    /// returns Some if the field needs a selector, None otherwise
-    fn get_selector(&self, constructor: bool, field_name: &String) -> Option<String> {
-        if self.storage.contains_key(field_name) {
-            Some(selector!(constructor))
-        } else {
-            None
+/// returns the overloads of the overloaded functions by their Solidity name,
+/// every signature once
+fn collect_overloads<'h>(
+    headers: impl IntoIterator<Item = &'h FunctionHeader>,
+) -> HashMap<String, Vec<FunctionHeader>> {
+    let mut overloads = HashMap::<String, Vec<FunctionHeader>>::new();
+    for header in headers {
+        let same_name = overloads
+            .entry(header.solidity_name().to_owned())
+            .or_default();
+        if !same_name
+            .iter()
+            .any(|overload| overload.signature == header.signature)
+        {
+            same_name.push(header.clone());
         }
     }
+    overloads.retain(|_, same_name| same_name.len() > 1);
+    overloads
+}
+
+/// returns the Solidity names of the functions of the library
+fn library_functions(library: &Library) -> HashSet<String> {
+    library
+        .functions
+        .iter()
+        .map(|function| function.header.solidity_name().to_owned())
+        .collect()
 }
 
-/// Captures a regex group and returns it
+/// returns how the library functions take their arguments by the names of the functions
+/// prefixed with the name of the library, `&mut ` or `&` for the storage parameters
+/// and an empty string for the other parameters
 ///
-/// `regex` the regex to use
-/// `line` the string on which we will use the regex
-/// `capture_name` the name of the group to capture
-#[inline]
-fn capture_regex(regex: &Regex, line: &str, capture_name: &str) -> Option<String> {
-    regex.captures(line).and_then(|cap| {
-        cap.name(capture_name)
-            .map(|value| value.as_str().to_string())
-    })
+/// `library` the name of the library
+/// `headers` the headers of the functions of the library
+fn library_references<'h>(
+    library: &str,
+    headers: impl IntoIterator<Item = &'h FunctionHeader>,
+) -> Vec<(String, Vec<&'static str>)> {
+    headers
+        .into_iter()
+        .map(|header| {
+            let references = header
+                .params
+                .iter()
+                .map(|param| {
+                    match (param.storage, header.view) {
+                        (false, _) => "",
+                        (true, true) => "&",
+                        (true, false) => "&mut ",
+                    }
+                })
+                .collect();
+            (format!("{library}.{}", header.name), references)
+        })
+        .collect()
+}
+
+/// returns true if the ink! type is an integer, which an integer literal can be passed as
+fn is_integer_param(param_type: &str) -> bool {
+    param_type == "U256"
+        || (param_type.len() > 1
+            && matches!(&param_type[..1], "u" | "i")
+            && param_type[1..].chars().all(|char| char.is_ascii_digit()))
+}
+
+/// returns true if the arguments are a single zero, as in `address(0)`
+fn is_zero(args: &[ast::Expression]) -> bool {
+    matches!(
+        args,
+        [ast::Expression::NumberLiteral(number, None, _)] if number == "0" || number == "0x0"
+    )
 }
 
-/// Returns true if expression passed is a literal
+/// returns true if the expression is a call with options, eg. `to.call{value: amount}("")`
+fn is_value_call(expression: &ast::Expression) -> bool {
+    matches!(
+        expression,
+        ast::Expression::Call(callee, ..)
+            if matches!(callee.as_ref(), ast::Expression::CallOptions(..))
+    )
+}
+
+/// returns the statement which increments or decrements `value` by one
 ///
-/// `expression` the expression to check
-fn is_literal(expression: &String) -> bool {
-    let string_regex = Regex::new(r#"^\s*".*"\s*$"#).unwrap();
-    let char_regex = Regex::new(r#"^\s*'.*'\s*$"#).unwrap();
-    expression.parse::<i32>().is_ok()
-        || string_regex.is_match(expression)
-        || char_regex.is_match(expression)
-        || expression == "true"
-        || expression == "false"
-}
-
-/// Parses attributes of a function like payable, external, view
+/// `operation` either `++` or `--`
+/// returns true if the arithmetic operation can overflow or divide by zero
+fn overflows(operation: Operation) -> bool {
+    matches!(
+        operation,
+        Operation::Add
+            | Operation::Subtract
+            | Operation::Mul
+            | Operation::Div
+            | Operation::Modulo
+            | Operation::Pow
+    )
+}
+
+/// returns true if the arithmetic of the Solidity version reverts on overflow
 ///
-/// `attributes` the raw representation of the attributes of the function
+/// The lowest version allowed by the requirement decides, eg. `>=0.7.0 <0.9.0` is not checked
 ///
-/// returns 0. external 1. view 2. payable
-fn parse_function_attributes(attributes: &str) -> (bool, bool, bool) {
-    let external = attributes.contains("external") || attributes.contains("public");
-    let view = attributes.contains("view") || attributes.contains("pure");
-    let payable = attributes.contains("payable");
+/// `requirement` the version requirement of `pragma solidity`, eg. `^0.8.0`
+fn is_checked_version(requirement: &str) -> bool {
+    let lowest = requirement
+        .split_whitespace()
+        .filter(|constraint| !constraint.starts_with('<'))
+        .filter_map(|constraint| {
+            let version = constraint.trim_start_matches(['^', '~', '=', '>']);
+            let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+            Some((parts.next()??, parts.next().flatten().unwrap_or_default()))
+        })
+        .min();
+    match lowest {
+        Some(version) => version >= (0, 8),
+        None => true,
+    }
+}
 
-    (external, view, payable)
+/// returns the header of a try or catch clause without the data locations of the parameters
+fn remove_locations(header: &str) -> String {
+    let mut header = header.to_owned();
+    for location in [" memory ", " storage ", " calldata "] {
+        header = header.replace(location, " ");
+    }
+    header
 }
 
-/// Parses the modifiers of a function
+/// Converts a Solidity number literal to an integer literal of Rust
 ///
-/// `attributes` the raw representation of the attributes of the function
+/// Underscores are removed, the scientific notation is expanded and the units are applied,
+/// eg. `1.5 ether` is converted to `1500000000000000000`
 ///
-/// returns the modifiers of the function in a vec of expressions
-fn parse_modifiers(attributes: &str) -> Vec<Expression> {
-    let mut adjusted = attributes.to_owned();
-    adjusted.remove_matches("payable");
-    adjusted.remove_matches("external");
-    adjusted.remove_matches("internal");
-    adjusted.remove_matches("virtual");
-    adjusted.remove_matches("override");
-    adjusted.remove_matches("public");
-    adjusted.remove_matches("private");
-    adjusted.remove_matches("view");
-    adjusted.remove_matches("pure");
-    adjusted = trim(&adjusted);
-    adjusted = adjusted.replace(", ", ",");
-    if adjusted.is_empty() {
-        Vec::default()
-    } else {
-        split(&adjusted, " ", None)
-            .iter()
-            .map(|raw_modifier| Expression::Modifier(raw_modifier.to_owned()))
-            .collect()
+/// `number` the number as written in the source
+/// `unit` the unit following the number, eg. `ether` or `days`
+///
+/// returns the original number if it can not be represented as an integer
+fn convert_number(number: &str, unit: Option<&str>) -> String {
+    let number = number.replace('_', "");
+    let (mantissa, exponent) = match number.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().unwrap_or_default()),
+        None => (number.as_str(), 0),
+    };
+    let (exponent, factor) = match unit {
+        Some("gwei") => (exponent + 9, 1),
+        Some("ether") => (exponent + 18, 1),
+        Some("minutes") => (exponent, 60),
+        Some("hours") => (exponent, 3600),
+        Some("days") => (exponent, 86400),
+        Some("weeks") => (exponent, 604800),
+        Some("years") => (exponent, 31536000),
+        _ => (exponent, 1),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let zeros = exponent - fraction.len() as i64;
+    if zeros < 0 {
+        return number
+    }
+
+    let digits = format!("{integer}{fraction}{}", "0".repeat(zeros as usize));
+    let digits = match digits.trim_start_matches('0') {
+        "" => String::from("0"),
+        digits => digits.to_owned(),
+    };
+    if factor == 1 {
+        return digits
+    }
+    digits
+        .parse::<u128>()
+        .ok()
+        .and_then(|value| value.checked_mul(factor))
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| format!("{digits} * {factor}"))
+}
+
+/// returns the size of the solidity integer type in bits, eg. 256 for `uint`
+fn integer_size(name: &str) -> Option<u32> {
+    match name.strip_prefix('u').unwrap_or(name).strip_prefix("int")? {
+        "" => Some(256),
+        size => size.parse().ok(),
+    }
+}
+
+/// returns the name of the interface without the `I` prefix, eg. `ERC20` of `IERC20`,
+/// the names without the prefix are kept, eg. `Oracle`
+fn interface_name(name: &str) -> String {
+    match name.strip_prefix('I') {
+        Some(rest) if rest.starts_with(|first: char| first.is_ascii_uppercase()) => rest.to_owned(),
+        _ => name.to_owned(),
+    }
+}
+
+/// returns the size of the fixed size bytes, eg. 4 of `bytes4` or of its ink! type `[u8; 4]`
+fn fixed_bytes_size(name: &str) -> Option<usize> {
+    name.strip_prefix("bytes")
+        .or_else(|| name.strip_prefix("[u8; ")?.strip_suffix(']'))?
+        .parse()
+        .ok()
+        .filter(|size| (1..=32).contains(size))
+}
+
+/// returns whether the ink! integer type is signed and its size in bits, eg. `(false, 8)` of `u8`
+fn rust_integer_size(name: &str) -> Option<(bool, u32)> {
+    let (signed, size) = match name.split_at_checked(1)? {
+        ("u", size) => (false, size),
+        ("i", size) => (true, size),
+        _ => return None,
+    };
+    size.parse().ok().map(|size| (signed, size))
+}
+
+/// returns true if the solidity type is a signed or unsigned integer, eg. `int8` or `uint`
+fn is_integer_type(name: &str) -> bool {
+    integer_size(name).is_some() && TYPES.contains_key(name)
+}
+
+/// returns true if the converted number is a decimal integer
+fn is_integer(number: &str) -> bool {
+    !number.is_empty() && number.chars().all(|ch| ch.is_ascii_digit())
+}
+
+/// Converts the expression of a narrower integer type to `U256`
+///
+/// The literals of constant operations are converted, so the operation is evaluated
+/// with `U256`, other expressions are converted with `U256::from`
+///
+/// `expression` the converted expression
+fn widen(expression: Expression) -> Expression {
+    match expression {
+        Expression::Literal(number) if is_integer(&number) => {
+            Expression::Cast(true, String::from("U256::from"), bx!(Expression::Literal(number)))
+        }
+        // the exponent of `U256` is a `U256` too
+        Expression::Arithmetic(left, right, Operation::Pow)
+            if matches!(left.as_ref(), Expression::Literal(number) if is_integer(number)) =>
+        {
+            Expression::Arithmetic(
+                bx!(widen(*left)),
+                bx!(Expression::Cast(true, String::from("U256::from"), right)),
+                Operation::Pow,
+            )
+        }
+        Expression::Arithmetic(left, right, operation @ (Operation::ShiftLeft | Operation::ShiftRight)) => {
+            Expression::Arithmetic(bx!(widen(*left)), right, operation)
+        }
+        Expression::Arithmetic(left, right, operation) => {
+            Expression::Arithmetic(bx!(widen(*left)), bx!(widen(*right)), operation)
+        }
+        Expression::Enclosed(inner) => Expression::Enclosed(bx!(widen(*inner))),
+        Expression::Ternary(condition, if_true, if_false) => {
+            Expression::Ternary(condition, bx!(widen(*if_true)), bx!(widen(*if_false)))
+        }
+        Expression::Cast(true, ref function, _) if function == "U256::from" => expression,
+        expression => Expression::Cast(true, String::from("U256::from"), bx!(expression)),
     }
 }
 
@@ -2658,9 +6346,8 @@ fn convert_int(arg_type: String) -> String {
     )
     .unwrap();
 
-    let int_type_raw = capture_regex(&regex_int, &arg_type, "int_type");
-    if let Some(int_type) = int_type_raw {
-        let int_size_raw = capture_regex(&regex_int, &arg_type, "int_size").unwrap();
+    if let Some(capture) = regex_int.captures(&arg_type) {
+        let int_size_raw = &capture["int_size"];
         let int_size_original = if int_size_raw.is_empty() {
             128
         } else {
@@ -2674,32 +6361,25 @@ fn convert_int(arg_type: String) -> String {
             _ => 128,
         };
 
-        return format!("{int_type}{int_size}")
+        return format!("{}{int_size}", &capture["int_type"])
     }
 
     arg_type
 }
 
-/// Skips the characters in a char array until one of characters specified is found
-///
-/// `chars` the char array where to skip the characters
-/// `until` the vector of characters which we want to find, the function ends when it matches one of the characters
+/// Adds the members which are not yet in `members`, members with the same name are replaced
 ///
-/// Returns the chars skipped as a String
-fn read_until(chars: &mut Chars, until: Vec<char>) -> String {
-    let mut buffer = String::new();
-    for ch in chars.by_ref() {
-        if until.contains(&ch) {
-            break
-        }
-        match ch {
-            NEW_LINE => {
-                buffer.push(SPACE);
-            }
-            _ => {
-                buffer.push(ch);
-            }
+/// `members` the members we merge into
+/// `new_members` the members of a more derived contract
+/// `name` returns the name of a member
+fn merge_by_name<T: Clone>(members: &mut Vec<T>, new_members: &[T], name: fn(&T) -> &String) {
+    for new_member in new_members.iter() {
+        match members
+            .iter()
+            .position(|member| name(member) == name(new_member))
+        {
+            Some(index) => members[index] = new_member.clone(),
+            None => members.push(new_member.clone()),
         }
     }
-    trim(&buffer)
 }
@@ -0,0 +1,309 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    ast::{
+        ImportDirective,
+        SourcePart,
+    },
+    diagnostics::Diagnostics,
+    file_utils,
+    parser::ParserError,
+    syntax,
+};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+};
+
+/// Replaces the prefix of an import path, eg. `@openzeppelin/=node_modules/@openzeppelin/`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping {
+    pub prefix: String,
+    pub target: String,
+}
+
+impl Remapping {
+    /// Parses a remapping in the `prefix=target` form used by solc
+    ///
+    /// returns None if `raw` is not a remapping
+    pub fn parse(raw: &str) -> Option<Remapping> {
+        let (prefix, target) = raw.trim().split_once('=')?;
+        if prefix.is_empty() {
+            return None
+        }
+        Some(Remapping {
+            prefix: prefix.to_owned(),
+            target: target.to_owned(),
+        })
+    }
+}
+
+/// Returns the directory against which non-relative imports and `remappings.txt` are resolved
+///
+/// It is the first directory passed, or the directory of the first file passed
+pub fn project_root(inputs: &[String]) -> PathBuf {
+    let first = inputs
+        .iter()
+        .find(|input| Path::new(input).is_dir())
+        .or_else(|| inputs.first());
+    match first {
+        Some(input) if Path::new(input).is_dir() => PathBuf::from(input),
+        Some(input) => {
+            Path::new(input)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        }
+        None => PathBuf::new(),
+    }
+}
+
+/// Expands the glob patterns to the paths matching them, other inputs are kept as they are
+///
+/// returns `ParserError::FileError` if a pattern is invalid or matches no path
+pub fn expand_globs(inputs: &[String]) -> Result<Vec<String>, ParserError> {
+    let mut expanded = Vec::new();
+
+    for input in inputs.iter() {
+        if !input.contains(['*', '?', '[']) {
+            expanded.push(input.clone());
+            continue
+        }
+        let paths = glob::glob(input)
+            .map_err(|error| ParserError::FileError(format!("invalid pattern `{input}`: {error}")))?
+            .filter_map(Result::ok)
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>();
+        if paths.is_empty() {
+            return Err(ParserError::FileError(format!("no file matches `{input}`")))
+        }
+        expanded.extend(paths);
+    }
+
+    Ok(expanded)
+}
+
+/// Returns the deepest directory containing all of the files
+pub fn common_directory(files: &[PathBuf]) -> PathBuf {
+    let mut directories = files
+        .iter()
+        .map(|file| normalize(file.parent().unwrap_or(file)));
+    let first = directories.next().unwrap_or_default();
+
+    directories.fold(first, |common, directory| {
+        common
+            .components()
+            .zip(directory.components())
+            .take_while(|(a, b)| a == b)
+            .map(|(component, _)| component)
+            .collect()
+    })
+}
+
+/// Reads the remappings from the `remappings.txt` file in the root of the project, if it exists
+pub fn read_remappings(root: &Path) -> Result<Vec<Remapping>, ParserError> {
+    let path = root.join("remappings.txt");
+    if !path.is_file() {
+        return Ok(Vec::default())
+    }
+    let content = file_utils::read_file(&path.display().to_string())?;
+
+    Ok(content.lines().filter_map(Remapping::parse).collect())
+}
+
+/// Collects the Solidity files in the directory and its subdirectories
+///
+/// Dependencies in `node_modules` are only transpiled when they are imported
+pub fn collect_sources(directory: &Path) -> Result<Vec<PathBuf>, ParserError> {
+    let mut sources = Vec::new();
+    let mut entries = fs::read_dir(directory)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<PathBuf>, std::io::Error>>()
+        })
+        .map_err(|error| {
+            ParserError::FileError(format!("cannot read `{}`: {error}", directory.display()))
+        })?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != "node_modules") {
+                sources.append(&mut collect_sources(&path)?);
+            }
+        } else if path.extension().is_some_and(|extension| extension == "sol") {
+            // the sources are compared with the normalized paths of the imported files
+            sources.push(normalize(&path));
+        }
+    }
+
+    Ok(sources)
+}
+
+/// Returns the `import` statements of the Solidity source
+///
+/// The syntax errors of the source are reported when the file itself is transpiled
+pub fn parse_imports(content: &str) -> Vec<ImportDirective> {
+    syntax::parse(content, &mut Diagnostics::default())
+        .parts
+        .into_iter()
+        .filter_map(|part| {
+            match part {
+                SourcePart::Import(import) => Some(import),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Resolves the import to the path of the imported file
+///
+/// Relative imports are resolved against the importing file, other imports are remapped
+/// and resolved against the root of the project
+///
+/// returns None if the imported file does not exist
+pub fn resolve_import(
+    file: &Path,
+    import: &str,
+    root: &Path,
+    remappings: &[Remapping],
+) -> Option<PathBuf> {
+    let candidates = if import.starts_with("./") || import.starts_with("../") {
+        vec![file.parent().unwrap_or(root).join(import)]
+    } else {
+        // the longest matching prefix wins
+        let remapped = remappings
+            .iter()
+            .filter(|remapping| import.starts_with(&remapping.prefix))
+            .max_by_key(|remapping| remapping.prefix.len())
+            .map(|remapping| format!("{}{}", remapping.target, &import[remapping.prefix.len()..]))
+            .unwrap_or_else(|| import.to_owned());
+        vec![root.join(&remapped), PathBuf::from(remapped)]
+    };
+
+    candidates
+        .into_iter()
+        .map(|candidate| normalize(&candidate))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Orders the source files and the files they import, so each file comes after its imports
+///
+/// Imports which can not be resolved are skipped with a warning
+///
+/// `entries` the source files of the project
+///
+/// returns the ordered files with the warnings about their unresolved imports
+pub fn order_sources(
+    entries: &[PathBuf],
+    root: &Path,
+    remappings: &[Remapping],
+) -> Result<Vec<(PathBuf, Diagnostics)>, ParserError> {
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::new();
+
+    for entry in entries.iter() {
+        visit(&normalize(entry), root, remappings, &mut visited, &mut ordered)?;
+    }
+
+    Ok(ordered)
+}
+
+fn visit(
+    file: &Path,
+    root: &Path,
+    remappings: &[Remapping],
+    visited: &mut HashSet<PathBuf>,
+    ordered: &mut Vec<(PathBuf, Diagnostics)>,
+) -> Result<(), ParserError> {
+    // cyclic imports are allowed in Solidity, the file is visited only once
+    if !visited.insert(file.to_path_buf()) {
+        return Ok(())
+    }
+
+    let content = file_utils::read_file(&file.display().to_string())?;
+    let mut diagnostics = Diagnostics::default();
+    for import in parse_imports(&content) {
+        match resolve_import(file, &import.path, root, remappings) {
+            Some(imported) => visit(&imported, root, remappings, visited, ordered)?,
+            None => {
+                diagnostics.warning(
+                    format!("imported file `{}` was not found, skipping", import.path),
+                    Some(import.span),
+                )
+            }
+        }
+    }
+    ordered.push((file.to_path_buf(), diagnostics));
+
+    Ok(())
+}
+
+/// Returns the relative path from the directory `from` to `to`
+pub fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from = normalize(from);
+    let to = normalize(to);
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut path = PathBuf::new();
+    for _ in from.components().skip(common) {
+        path.push("..");
+    }
+    for component in to.components().skip(common) {
+        path.push(component);
+    }
+
+    path
+}
+
+/// Removes the `.` and `..` components of the path without accessing the file system
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if normalized
+                    .components()
+                    .next_back()
+                    .is_some_and(|last| matches!(last, Component::Normal(_))) =>
+            {
+                normalized.pop();
+            }
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
@@ -0,0 +1,126 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use serde::Serialize;
+use std::fmt;
+
+/// The kind of code which was not faithfully translated
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Assembly,
+    Builtin,
+    Constant,
+    Fallback,
+    FreeFunction,
+    MissingBaseContract,
+    Modifier,
+    StorageArray,
+    TruncatedInteger,
+    TryCatch,
+    U256Storage,
+    UndefinedError,
+    UndefinedEvent,
+    UnknownType,
+    UnsupportedDefinition,
+    UnsupportedExpression,
+    UnsupportedStatement,
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category = match self {
+            Category::Assembly => "assembly",
+            Category::Builtin => "builtin",
+            Category::Constant => "constant",
+            Category::Fallback => "fallback",
+            Category::FreeFunction => "free_function",
+            Category::MissingBaseContract => "missing_base_contract",
+            Category::Modifier => "modifier",
+            Category::StorageArray => "storage_array",
+            Category::TruncatedInteger => "truncated_integer",
+            Category::TryCatch => "try_catch",
+            Category::U256Storage => "u256_storage",
+            Category::UndefinedError => "undefined_error",
+            Category::UndefinedEvent => "undefined_event",
+            Category::UnknownType => "unknown_type",
+            Category::UnsupportedDefinition => "unsupported_definition",
+            Category::UnsupportedExpression => "unsupported_expression",
+            Category::UnsupportedStatement => "unsupported_statement",
+        };
+        write!(f, "{category}")
+    }
+}
+
+/// A spot of the Solidity code which was not faithfully translated and needs a manual review
+///
+/// `contract` the contract, library or interface defining the code, or None for the code
+/// outside of contracts
+/// `line` and `column` locate the code in the file defining `contract`
+/// `code` the first line of the code, if it is defined in the transpiled file
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ReportEntry {
+    pub category: Category,
+    pub reason: String,
+    pub contract: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub code: Option<String>,
+}
+
+/// The transpilation report of one output crate
+///
+/// `file` the transpiled Solidity file
+/// `name` the name of the transpiled contract, interface or the libraries of the file
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub file: String,
+    pub name: String,
+    pub entries: Vec<ReportEntry>,
+}
+
+impl Report {
+    /// returns the report as pretty printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Summarizes the report for the user, one line per entry
+    ///
+    /// returns an empty string if everything was translated
+    pub fn summary(&self) -> String {
+        let mut output = String::new();
+        for entry in self.entries.iter() {
+            let location = match &entry.contract {
+                Some(contract) if contract != &self.name => {
+                    format!("{contract} {}:{}", entry.line, entry.column)
+                }
+                _ => format!("{}:{}", entry.line, entry.column),
+            };
+            output.push_str(&format!(
+                "  - {location} [{}] {}\n",
+                entry.category, entry.reason
+            ));
+        }
+        output
+    }
+}
@@ -20,6 +20,11 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::{
+    ast,
+    lexer::Span,
+    report::ReportEntry,
+};
 use std::collections::HashSet;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -28,27 +33,65 @@ pub enum ContractType {
     CONTRACT,
 }
 
+#[derive(Clone)]
 pub struct Contract {
     pub name: String,
+    pub base: Vec<BaseContract>,
+    pub is_abstract: bool,
     pub fields: Vec<ContractField>,
     pub constructor: Function,
     pub events: Vec<Event>,
+    pub errors: Vec<CustomError>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
     pub functions: Vec<Function>,
     pub imports: HashSet<String>,
     pub contract_doc: Vec<String>,
     pub modifiers: Vec<Modifier>,
+    pub libraries: Vec<Library>,
+    pub using: Vec<String>,
+    // the interfaces called by the contract through their wrappers
+    pub interfaces: Vec<Interface>,
+    // the code of the contract which was not faithfully translated
+    pub report: Vec<ReportEntry>,
+}
+
+/// A contract inherited by another contract
+///
+/// `args` are the arguments passed to the constructor of the base contract
+/// `span` is the position of the base contract in the inheritance list
+#[derive(Clone)]
+pub struct BaseContract {
+    pub name: String,
+    pub args: Vec<ast::Expression>,
+    pub span: Span,
 }
 
+#[derive(Clone)]
 pub struct Interface {
     pub name: String,
     pub events: Vec<Event>,
+    pub errors: Vec<CustomError>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
     pub function_headers: Vec<FunctionHeader>,
     pub imports: HashSet<String>,
     pub comments: Vec<String>,
+    pub report: Vec<ReportEntry>,
+}
+
+#[derive(Clone)]
+pub struct Library {
+    pub name: String,
+    pub fields: Vec<ContractField>,
+    pub events: Vec<Event>,
+    pub errors: Vec<CustomError>,
+    pub enums: Vec<Enum>,
+    pub structs: Vec<Struct>,
+    pub functions: Vec<Function>,
+    pub imports: HashSet<String>,
+    pub comments: Vec<String>,
+    pub report: Vec<ReportEntry>,
 }
 
 #[derive(Clone)]
@@ -58,8 +101,12 @@ pub struct ContractField {
     pub comments: Vec<String>,
     pub initial_value: Option<Expression>,
     pub constant: bool,
+    // stored once by the constructor
+    pub immutable: bool,
+    pub visibility: Visibility,
 }
 
+#[derive(Clone)]
 pub struct Modifier {
     pub header: FunctionHeader,
     pub statements: Vec<Statement>,
@@ -73,6 +120,14 @@ pub struct Event {
     pub comments: Vec<String>,
 }
 
+/// A Solidity custom error, transpiled to a variant of the `Error` of the contract
+#[derive(Clone)]
+pub struct CustomError {
+    pub name: String,
+    pub fields: Vec<FunctionParam>,
+    pub comments: Vec<String>,
+}
+
 #[derive(Clone)]
 pub struct EventField {
     pub indexed: bool,
@@ -80,10 +135,13 @@ pub struct EventField {
     pub name: String,
 }
 
+#[derive(Clone)]
 pub struct Enum {
     pub name: String,
     pub values: Vec<String>,
     pub comments: Vec<String>,
+    // a part of the storage of a contract
+    pub stored: bool,
 }
 
 #[derive(Clone)]
@@ -91,6 +149,10 @@ pub struct Struct {
     pub name: String,
     pub fields: Vec<StructField>,
     pub comments: Vec<String>,
+    // a part of the storage of a contract
+    pub stored: bool,
+    // a parameter or a return value of a message, or a field of an event or an error
+    pub exposed: bool,
 }
 
 #[derive(Default, Clone)]
@@ -105,11 +167,37 @@ pub struct Function {
     pub body: Vec<Statement>,
 }
 
+/// The visibility of a function or a state variable
+///
+/// The public and external functions are messages of the contract, the public state variables
+/// get a getter message
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Visibility {
+    External,
+    Public,
+    #[default]
+    Internal,
+    Private,
+}
+
+impl Visibility {
+    /// returns true if the function is a message of the contract
+    pub fn is_message(&self) -> bool {
+        matches!(self, Visibility::External | Visibility::Public)
+    }
+}
+
+/// `name` the name of the generated function, overloaded functions are renamed after their
+/// parameters
+/// `signature` the Solidity signature of the function, eg. `transfer(address,uint256)`
+/// `selector` the explicit selector of the message, None for the default selector of ink!
 #[derive(Default, Clone)]
 pub struct FunctionHeader {
     pub name: String,
+    pub signature: String,
+    pub selector: Option<u32>,
     pub params: Vec<FunctionParam>,
-    pub external: bool,
+    pub visibility: Visibility,
     pub view: bool,
     pub payable: bool,
     pub return_params: Vec<FunctionParam>,
@@ -117,18 +205,31 @@ pub struct FunctionHeader {
     pub modifiers: Vec<Expression>,
 }
 
+impl FunctionHeader {
+    /// returns the name of the function in the Solidity code, shared by its overloads
+    pub fn solidity_name(&self) -> &str {
+        match self.signature.split_once('(') {
+            Some((name, _)) => name,
+            None => &self.name,
+        }
+    }
+}
+
+/// `solidity_type` the type of the parameter as declared in Solidity, with the elementary
+/// types in their canonical form, eg. `uint256` of `uint`
+/// `storage` if the parameter is a reference to storage, only libraries take such parameters
 #[derive(Clone, Debug)]
 pub struct FunctionParam {
     pub name: String,
     pub param_type: String,
+    pub solidity_type: String,
+    pub storage: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Statement {
-    AssemblyEnd,
     Assign(Expression, Expression, Operation),
     Catch(Vec<Statement>),
-    CatchEnd,
     Comment(String),
     Declaration(String, String, Option<Expression>),
     Loop(
@@ -143,21 +244,22 @@ pub enum Statement {
     FunctionCall(Expression),
     Group(Vec<Statement>),
     If(Condition, Vec<Statement>),
-    IfEnd,
+    // the matched expression and the arms with their patterns
+    Match(Expression, Vec<(Expression, Vec<Statement>)>),
     ModifierBody,
-    Raw(String),
+    Raw(ast::Statement),
     Require(Condition, String),
     Return(Expression),
+    // returns the error, or panics inside of a constructor
+    Revert(Expression, bool),
     Ternary(Condition, Box<Statement>, Box<Statement>),
     Try(Vec<Statement>),
-    TryEnd,
     While(
         Option<Box<Statement>>,
         Expression,
         Option<Box<Statement>>,
         Vec<Statement>,
     ),
-    WhileEnd,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -188,6 +290,7 @@ pub enum Operation {
     Modulo,
     Mul,
     MulAssign,
+    Neg,
     Not,
     NotEqual,
     OrAssign,
@@ -224,34 +327,38 @@ impl Operation {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Expression {
     Arithmetic(Box<Expression>, Box<Expression>, Operation),
+    // the ink! code of a Solidity builtin, `$0`, `$1`, ... are replaced by the arguments
+    // and `$args` by all arguments
+    Builtin(String, Vec<Expression>),
     Cast(bool, String, Box<Expression>),
+    // returns the error of the contract on overflow, or panics inside of a constructor
+    Checked(Box<Expression>, Box<Expression>, Operation, bool),
     Condition(Box<Condition>),
     Constant(String),
+    // a variant of the `Error` of the contract with its fields
+    CustomError(String, Vec<Expression>),
     Enclosed(Box<Expression>),
     EnvCaller(Option<String>),
+    // a call through the OpenBrush wrapper of an interface, with the wrapper, the function,
+    // the address of the called contract, the arguments and if the call builder is returned
+    ExternalCall(String, String, Box<Expression>, Vec<Expression>, bool),
     FunctionCall(String, Vec<Expression>, Option<String>, bool),
+    // an element of an array and its index converted to `usize`
+    Index(Box<Expression>, Box<Expression>),
     IsZero(Box<Expression>),
+    LibraryCall(String, String, Vec<Expression>),
     Literal(String),
     Logical(Box<Expression>, Operation, Box<Expression>),
     Member(String, Option<String>),
     Mapping(Box<Expression>, Vec<Expression>, Option<Box<Expression>>),
-    Modifier(String),
+    Raw(ast::Expression),
     NewArray(String, Box<Expression>),
     StructArg(String, Box<Expression>),
     StructInit(String, Vec<Expression>),
     Ternary(Box<Condition>, Box<Expression>, Box<Expression>),
     TransferredValue(Option<String>),
     WithSelector(Box<Expression>, Box<Expression>),
+    // wraps around on overflow, the operands are `U256` if the bool is true
+    Wrapping(Box<Expression>, Box<Expression>, Operation, bool),
     ZeroAddressInto,
 }
-
-pub enum Block {
-    Assembly,
-    Catch,
-    Else,
-    ElseIf,
-    If,
-    Try,
-    Unchecked,
-    While,
-}
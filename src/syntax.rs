@@ -0,0 +1,1443 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{
+    ast::*,
+    diagnostics::{
+        Diagnostic,
+        Diagnostics,
+    },
+    formatter::trim,
+    lexer::{
+        tokenize,
+        Token,
+        TokenKind,
+    },
+};
+
+type ParseResult<T> = Result<T, Diagnostic>;
+
+macro_rules! bx {
+    ($e:expr) => {
+        Box::new($e)
+    };
+}
+
+const ASSIGNMENT_OPERATORS: [&str; 12] = [
+    "=", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", "<<=", ">>=", ">>>=",
+];
+const DATA_LOCATIONS: [&str; 3] = ["memory", "storage", "calldata"];
+// the visibility and the state mutability of a function type, eg. `function() external view`
+const FUNCTION_TYPE_ATTRIBUTES: [&str; 5] = ["external", "internal", "payable", "pure", "view"];
+const NUMBER_UNITS: [&str; 9] = [
+    "wei", "gwei", "ether", "seconds", "minutes", "hours", "days", "weeks", "years",
+];
+const PREFIX_OPERATORS: [&str; 6] = ["!", "-", "~", "++", "--", "delete"];
+const VARIABLE_ATTRIBUTES: [&str; 8] = [
+    "constant",
+    "external",
+    "immutable",
+    "internal",
+    "override",
+    "private",
+    "public",
+    "transient",
+];
+
+/// Parses the Solidity source into its syntax tree
+///
+/// Syntax errors are reported to `diagnostics`, the parser then skips the erroneous
+/// statement or definition and continues with the next one.
+///
+/// `source` the content of the Solidity file
+/// `diagnostics` the diagnostics of the file
+///
+/// returns the syntax tree of the file without the erroneous statements and definitions
+pub fn parse(source: &str, diagnostics: &mut Diagnostics) -> SourceUnit {
+    let tokens = tokenize(source, diagnostics);
+    let mut parser = SyntaxParser {
+        source,
+        tokens,
+        position: 0,
+        last_span: Span::default(),
+        comments: Vec::new(),
+        diagnostics,
+    };
+    parser.parse_source_unit()
+}
+
+/// A recursive descent parser of Solidity
+struct SyntaxParser<'a> {
+    source: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+    // the span of the last consumed token
+    last_span: Span,
+    // the comments read since they were last taken, they document the next definition
+    // or they are transpiled as comments inside of functions
+    comments: Vec<Token>,
+    diagnostics: &'a mut Diagnostics,
+}
+
+impl<'a> SyntaxParser<'a> {
+    fn parse_source_unit(&mut self) -> SourceUnit {
+        let mut parts = Vec::new();
+        let mut solidity = None;
+
+        loop {
+            let token = self.peek().clone();
+            if token.kind == TokenKind::Eof {
+                break
+            }
+            let part = match token.text.as_str() {
+                // the comments before pragmas and imports document the next definition
+                "pragma" if self.peek_nth(1).text == "solidity" => {
+                    self.parse_pragma().map(|version| {
+                        solidity = Some(version);
+                        None
+                    })
+                }
+                "pragma" => self.skip_until(";").map(|_| None),
+                "import" => self.parse_import().map(|part| Some(SourcePart::Import(part))),
+                "abstract" | "contract" | "interface" | "library" => {
+                    self.parse_contract().map(|part| Some(SourcePart::Contract(part)))
+                }
+                "enum" => self.parse_enum().map(|part| Some(SourcePart::Enum(part))),
+                "error" => self.parse_error().map(|part| Some(SourcePart::Error(part))),
+                "event" => self.parse_event().map(|part| Some(SourcePart::Event(part))),
+                "function" => self.parse_function().map(|part| Some(SourcePart::Function(part))),
+                "struct" => self.parse_struct().map(|part| Some(SourcePart::Struct(part))),
+                "type" if self.peek_nth(2).text == "is" => {
+                    self.parse_type_definition().map(|part| Some(SourcePart::Type(part)))
+                }
+                "using" => self.parse_using().map(|part| Some(SourcePart::Using(part))),
+                // constants of the file
+                _ => {
+                    self.parse_variable_definition()
+                        .map(|part| Some(SourcePart::Variable(part)))
+                }
+            };
+            match part {
+                Ok(part) => parts.extend(part),
+                Err(error) => self.recover(error),
+            }
+        }
+
+        SourceUnit { parts, solidity }
+    }
+
+    /// Parses `pragma solidity`
+    ///
+    /// returns the version requirement as written in the source
+    fn parse_pragma(&mut self) -> ParseResult<String> {
+        self.next();
+        self.next();
+        let start = self.peek().span;
+        let mut end = start;
+        while !self.is(";") {
+            if self.peek().kind == TokenKind::Eof {
+                return Err(self.unexpected("`;`"))
+            }
+            end = self.next().span;
+        }
+        self.next();
+        Ok(self.source[start.start..end.end].to_owned())
+    }
+
+    /// Parses an import in any of its forms, eg. `import "./A.sol";`,
+    /// `import * as A from "./A.sol";` or `import {A, B as C} from "./A.sol";`
+    fn parse_import(&mut self) -> ParseResult<ImportDirective> {
+        let start = self.next().span;
+        let mut path = None;
+        while !self.eat(";") {
+            let token = self.next();
+            match token.kind {
+                TokenKind::String if path.is_none() => {
+                    path = Some(token.text[1..token.text.len() - 1].to_owned())
+                }
+                TokenKind::Eof => return Err(self.unexpected("`;`")),
+                _ => {}
+            }
+        }
+
+        match path {
+            Some(path) => {
+                Ok(ImportDirective {
+                    path,
+                    span: start.to(&self.last_span),
+                })
+            }
+            None => Err(self.error_at_last("expected the path of the imported file")),
+        }
+    }
+
+    /// Parses a contract, an interface or a library
+    fn parse_contract(&mut self) -> ParseResult<ContractDefinition> {
+        let comments = self.take_comments();
+        let start = self.peek().span;
+        let is_abstract = self.eat("abstract");
+        let kind = match self.next().text.as_str() {
+            "contract" => ContractKind::Contract,
+            "interface" => ContractKind::Interface,
+            "library" => ContractKind::Library,
+            _ => return Err(self.error_at_last("expected `contract`")),
+        };
+        let (name, _) = self.expect_identifier()?;
+
+        let mut base = Vec::new();
+        if self.eat("is") {
+            loop {
+                let base_start = self.peek().span;
+                let name = self.parse_path()?.join(".");
+                let args = if self.is("(") {
+                    self.parse_call_arguments()?
+                } else {
+                    Vec::new()
+                };
+                base.push(InheritanceSpecifier {
+                    name,
+                    args,
+                    span: base_start.to(&self.last_span),
+                });
+                if !self.eat(",") {
+                    break
+                }
+            }
+        }
+
+        self.expect("{")?;
+        let mut parts = Vec::new();
+        while !self.eat("}") {
+            if self.peek().kind == TokenKind::Eof {
+                return Err(self.unexpected("`}`"))
+            }
+            match self.parse_contract_part() {
+                Ok(part) => parts.push(part),
+                Err(error) => self.recover(error),
+            }
+        }
+        // comments at the end of the contract do not document anything
+        self.comments.clear();
+
+        Ok(ContractDefinition {
+            kind,
+            is_abstract,
+            name,
+            base,
+            parts,
+            comments,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    fn parse_contract_part(&mut self) -> ParseResult<ContractPart> {
+        let token = self.peek().clone();
+        let part = match token.text.as_str() {
+            // `function(uint256) returns (uint256) callback;` is a state variable of a function type
+            "function" if self.peek_nth(1).text == "(" => {
+                ContractPart::Variable(self.parse_variable_definition()?)
+            }
+            "function" | "constructor" | "modifier" => ContractPart::Function(self.parse_function()?),
+            "receive" | "fallback" if self.peek_nth(1).text == "(" => {
+                ContractPart::Function(self.parse_function()?)
+            }
+            "enum" => ContractPart::Enum(self.parse_enum()?),
+            "error" if self.peek_nth(2).text == "(" => ContractPart::Error(self.parse_error()?),
+            "event" => ContractPart::Event(self.parse_event()?),
+            "struct" => ContractPart::Struct(self.parse_struct()?),
+            "using" => ContractPart::Using(self.parse_using()?),
+            _ => ContractPart::Variable(self.parse_variable_definition()?),
+        };
+
+        Ok(part)
+    }
+
+    /// Parses a state variable or a constant
+    fn parse_variable_definition(&mut self) -> ParseResult<VariableDefinition> {
+        let comments = self.take_comments();
+        let start = self.peek().span;
+        let type_name = self.parse_type_name()?;
+        let mut constant = false;
+        let mut immutable = false;
+        let mut visibility = None;
+
+        while VARIABLE_ATTRIBUTES.contains(&self.peek().text.as_str()) {
+            match self.next().text.as_str() {
+                "constant" => constant = true,
+                "immutable" => immutable = true,
+                "external" => visibility = Some(Visibility::External),
+                "internal" => visibility = Some(Visibility::Internal),
+                "private" => visibility = Some(Visibility::Private),
+                "public" => visibility = Some(Visibility::Public),
+                "override" => self.skip_override_list()?,
+                _ => {}
+            }
+        }
+
+        let (name, _) = self.expect_identifier()?;
+        let value = if self.eat("=") {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+        self.expect(";")?;
+
+        Ok(VariableDefinition {
+            type_name,
+            name,
+            constant,
+            immutable,
+            visibility,
+            value,
+            comments,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    /// Parses a function, a constructor, a modifier or the receive and fallback functions
+    fn parse_function(&mut self) -> ParseResult<FunctionDefinition> {
+        let comments = self.take_comments();
+        let keyword = self.next();
+        let kind = match keyword.text.as_str() {
+            "constructor" => FunctionKind::Constructor,
+            "modifier" => FunctionKind::Modifier,
+            "receive" => FunctionKind::Receive,
+            "fallback" => FunctionKind::Fallback,
+            _ => FunctionKind::Function,
+        };
+        let name = match kind {
+            FunctionKind::Function | FunctionKind::Modifier
+                if self.peek().kind == TokenKind::Identifier =>
+            {
+                self.next().text
+            }
+            FunctionKind::Receive | FunctionKind::Fallback => keyword.text,
+            _ => String::new(),
+        };
+        // modifiers without parameters can omit the parentheses
+        let params = if self.is("(") {
+            self.parse_parameters()?
+        } else {
+            Vec::new()
+        };
+
+        let mut returns = Vec::new();
+        let mut visibility = None;
+        let mut mutability = None;
+        let mut is_virtual = false;
+        let mut is_override = false;
+        let mut modifiers = Vec::new();
+        loop {
+            let token = self.peek().clone();
+            match token.text.as_str() {
+                "{" | ";" if token.kind == TokenKind::Punctuation => break,
+                "external" => visibility = Some(Visibility::External),
+                "internal" => visibility = Some(Visibility::Internal),
+                "private" => visibility = Some(Visibility::Private),
+                "public" => visibility = Some(Visibility::Public),
+                "payable" => mutability = Some(Mutability::Payable),
+                "pure" => mutability = Some(Mutability::Pure),
+                "view" | "constant" => mutability = Some(Mutability::View),
+                "virtual" => is_virtual = true,
+                "override" => {
+                    self.next();
+                    self.skip_override_list()?;
+                    is_override = true;
+                    continue
+                }
+                "returns" => {
+                    self.next();
+                    returns = self.parse_parameters()?;
+                    continue
+                }
+                _ if token.kind == TokenKind::Identifier => {
+                    modifiers.push(self.parse_modifier_invocation()?);
+                    continue
+                }
+                _ => return Err(self.unexpected("function attribute, `{` or `;`")),
+            }
+            self.next();
+        }
+
+        let body = if self.eat(";") {
+            None
+        } else {
+            Some(self.parse_block()?)
+        };
+
+        Ok(FunctionDefinition {
+            kind,
+            name,
+            params,
+            returns,
+            visibility,
+            mutability,
+            is_virtual,
+            is_override,
+            modifiers,
+            body,
+            comments,
+            span: keyword.span.to(&self.last_span),
+        })
+    }
+
+    fn parse_modifier_invocation(&mut self) -> ParseResult<ModifierInvocation> {
+        let start = self.peek().span;
+        let name = self.parse_path()?.join(".");
+        let args = if self.is("(") {
+            Some(self.parse_call_arguments()?)
+        } else {
+            None
+        };
+
+        Ok(ModifierInvocation {
+            name,
+            args,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    /// Skips the optional list of overridden contracts after the `override` keyword
+    fn skip_override_list(&mut self) -> ParseResult<()> {
+        if self.eat("(") {
+            self.skip_until(")")?;
+        }
+        Ok(())
+    }
+
+    fn parse_event(&mut self) -> ParseResult<EventDefinition> {
+        let comments = self.take_comments();
+        let start = self.next().span;
+        let (name, _) = self.expect_identifier()?;
+        let mut params = Vec::new();
+
+        self.expect("(")?;
+        while !self.eat(")") {
+            let param_start = self.peek().span;
+            let type_name = self.parse_type_name()?;
+            let indexed = self.eat("indexed");
+            let name = self.parse_parameter_name();
+            params.push(EventParameter {
+                type_name,
+                indexed,
+                name,
+                span: param_start.to(&self.last_span),
+            });
+            if !self.eat(",") {
+                self.expect(")")?;
+                break
+            }
+        }
+        self.eat("anonymous");
+        self.expect(";")?;
+
+        Ok(EventDefinition {
+            name,
+            params,
+            comments,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    fn parse_enum(&mut self) -> ParseResult<EnumDefinition> {
+        let comments = self.take_comments();
+        let start = self.next().span;
+        let (name, _) = self.expect_identifier()?;
+        let mut values = Vec::new();
+
+        self.expect("{")?;
+        while !self.eat("}") {
+            values.push(self.expect_identifier()?.0);
+            if !self.eat(",") {
+                self.expect("}")?;
+                break
+            }
+        }
+        self.comments.clear();
+
+        Ok(EnumDefinition {
+            name,
+            values,
+            comments,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    fn parse_struct(&mut self) -> ParseResult<StructDefinition> {
+        let comments = self.take_comments();
+        let start = self.next().span;
+        let (name, _) = self.expect_identifier()?;
+        let mut fields = Vec::new();
+
+        self.expect("{")?;
+        while !self.eat("}") {
+            let field_start = self.peek().span;
+            let type_name = self.parse_type_name()?;
+            let (field_name, _) = self.expect_identifier()?;
+            self.expect(";")?;
+            fields.push(Parameter {
+                type_name,
+                name: Some(field_name),
+                storage: false,
+                span: field_start.to(&self.last_span),
+            });
+        }
+        self.comments.clear();
+
+        Ok(StructDefinition {
+            name,
+            fields,
+            comments,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    fn parse_error(&mut self) -> ParseResult<ErrorDefinition> {
+        let comments = self.take_comments();
+        let start = self.next().span;
+        let (name, _) = self.expect_identifier()?;
+        let params = self.parse_parameters()?;
+        self.expect(";")?;
+
+        Ok(ErrorDefinition {
+            name,
+            params,
+            comments,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    /// Parses a user defined value type, eg. `type Price is uint128;`
+    fn parse_type_definition(&mut self) -> ParseResult<TypeDefinition> {
+        self.comments.clear();
+        let start = self.next().span;
+        let (name, _) = self.expect_identifier()?;
+        self.expect("is")?;
+        let type_name = self.parse_type_name()?;
+        self.expect(";")?;
+
+        Ok(TypeDefinition {
+            name,
+            type_name,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    fn parse_using(&mut self) -> ParseResult<UsingDirective> {
+        let start = self.next().span;
+        let library = if self.is("{") {
+            None
+        } else {
+            Some(self.parse_path()?.join("."))
+        };
+        self.skip_until(";")?;
+
+        Ok(UsingDirective {
+            library,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    /// Parses parameters of a function, a modifier or an error, including the parentheses
+    fn parse_parameters(&mut self) -> ParseResult<Vec<Parameter>> {
+        let mut params = Vec::new();
+
+        self.expect("(")?;
+        while !self.eat(")") {
+            let start = self.peek().span;
+            let type_name = self.parse_type_name()?;
+            let storage = self.is("storage");
+            if DATA_LOCATIONS.contains(&self.peek().text.as_str()) {
+                self.next();
+            }
+            let name = self.parse_parameter_name();
+            params.push(Parameter {
+                type_name,
+                name,
+                storage,
+                span: start.to(&self.last_span),
+            });
+            if !self.eat(",") {
+                self.expect(")")?;
+                break
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// Parses the parameters of a function type, including the parentheses
+    ///
+    /// returns the types of the parameters, their names are only documentation
+    fn parse_function_type_parameters(&mut self) -> ParseResult<Vec<TypeName>> {
+        Ok(self
+            .parse_parameters()?
+            .into_iter()
+            .map(|param| param.type_name)
+            .collect())
+    }
+
+    /// returns the name of a parameter, or None if the parameter is unnamed
+    fn parse_parameter_name(&mut self) -> Option<String> {
+        if self.peek().kind == TokenKind::Identifier {
+            Some(self.next().text)
+        } else {
+            None
+        }
+    }
+
+    fn parse_type_name(&mut self) -> ParseResult<TypeName> {
+        let mut type_name = if self.eat("mapping") {
+            self.expect("(")?;
+            let from = self.parse_type_name()?;
+            // keys and values of mappings can be named
+            self.parse_parameter_name();
+            self.expect("=>")?;
+            let to = self.parse_type_name()?;
+            self.parse_parameter_name();
+            self.expect(")")?;
+            TypeName::Mapping(bx!(from), bx!(to))
+        } else if self.eat("function") {
+            let params = self.parse_function_type_parameters()?;
+            let mut returns = Vec::new();
+            while FUNCTION_TYPE_ATTRIBUTES.contains(&self.peek().text.as_str()) {
+                self.next();
+            }
+            if self.eat("returns") {
+                returns = self.parse_function_type_parameters()?;
+            }
+            TypeName::Function(params, returns)
+        } else {
+            let path = self.parse_path()?;
+            if path.len() == 1 && is_elementary_type(&path[0]) {
+                if path[0] == "address" {
+                    self.eat("payable");
+                }
+                TypeName::Elementary(path[0].clone())
+            } else {
+                TypeName::UserDefined(path)
+            }
+        };
+
+        while self.eat("[") {
+            let size = if self.is("]") {
+                None
+            } else {
+                Some(bx!(self.parse_expression()?))
+            };
+            self.expect("]")?;
+            type_name = TypeName::Array(bx!(type_name), size);
+        }
+
+        Ok(type_name)
+    }
+
+    /// Parses identifiers separated by dots, eg. `Library.Struct`
+    fn parse_path(&mut self) -> ParseResult<Vec<String>> {
+        let mut path = vec![self.expect_identifier()?.0];
+        while self.is(".") && self.peek_nth(1).kind == TokenKind::Identifier {
+            self.next();
+            path.push(self.next().text);
+        }
+        Ok(path)
+    }
+
+    /// Parses a block of statements, including the curly braces
+    fn parse_block(&mut self) -> ParseResult<Vec<Statement>> {
+        let mut statements = Vec::new();
+
+        self.expect("{")?;
+        loop {
+            let end = self.eat("}");
+            statements.append(&mut self.take_comment_statements());
+            if end {
+                break
+            }
+            if self.peek().kind == TokenKind::Eof {
+                return Err(self.unexpected("`}`"))
+            }
+            let statement = match self.parse_statement() {
+                Ok(statement) => statement,
+                Err(error) => {
+                    self.recover(error);
+                    continue
+                }
+            };
+            // comments inside of the statement are placed before it, the comments read
+            // while looking for the continuation of the statement, eg. `else`, after it
+            let end = statement.span().end;
+            let (mut after, mut inside): (Vec<_>, Vec<_>) = self
+                .take_comment_statements()
+                .into_iter()
+                .partition(|comment| comment.span().start >= end);
+            statements.append(&mut inside);
+            statements.push(statement);
+            statements.append(&mut after);
+        }
+
+        Ok(statements)
+    }
+
+    /// Parses the body of a control statement, which is either a block or a single statement
+    fn parse_body(&mut self) -> ParseResult<Vec<Statement>> {
+        if self.is("{") {
+            self.parse_block()
+        } else {
+            Ok(vec![self.parse_statement()?])
+        }
+    }
+
+    fn parse_statement(&mut self) -> ParseResult<Statement> {
+        let token = self.peek().clone();
+        let start = token.span;
+        if token.kind != TokenKind::Identifier && token.text != "{" {
+            return self.parse_simple_statement()
+        }
+
+        let statement = match token.text.as_str() {
+            "{" => Statement::Block(self.parse_block()?, start.to(&self.last_span)),
+            "if" => {
+                self.next();
+                let condition = self.parse_condition()?;
+                let body = self.parse_body()?;
+                let else_body = if self.eat("else") {
+                    Some(self.parse_body()?)
+                } else {
+                    None
+                };
+                Statement::If(condition, body, else_body, start.to(&self.last_span))
+            }
+            "for" => {
+                self.next();
+                self.expect("(")?;
+                let init = if self.eat(";") {
+                    None
+                } else {
+                    Some(bx!(self.parse_simple_statement()?))
+                };
+                let condition = if self.is(";") {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+                self.expect(";")?;
+                let next = if self.is(")") {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+                self.expect(")")?;
+                let body = self.parse_body()?;
+                Statement::For(init, condition, next, body, start.to(&self.last_span))
+            }
+            "while" => {
+                self.next();
+                let condition = self.parse_condition()?;
+                let body = self.parse_body()?;
+                Statement::While(condition, body, start.to(&self.last_span))
+            }
+            "do" => {
+                self.next();
+                let body = self.parse_body()?;
+                self.expect("while")?;
+                let condition = self.parse_condition()?;
+                self.expect(";")?;
+                Statement::DoWhile(body, condition, start.to(&self.last_span))
+            }
+            "try" => self.parse_try()?,
+            "return" => {
+                self.next();
+                let value = if self.is(";") {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+                self.expect(";")?;
+                Statement::Return(value, start.to(&self.last_span))
+            }
+            "emit" => {
+                self.next();
+                let event = self.parse_expression()?;
+                self.expect(";")?;
+                Statement::Emit(event, start.to(&self.last_span))
+            }
+            "break" | "continue" => {
+                self.next();
+                self.expect(";")?;
+                if token.text == "break" {
+                    Statement::Break(start.to(&self.last_span))
+                } else {
+                    Statement::Continue(start.to(&self.last_span))
+                }
+            }
+            "unchecked" if self.peek_nth(1).text == "{" => {
+                self.next();
+                Statement::Unchecked(self.parse_block()?, start.to(&self.last_span))
+            }
+            "assembly" => self.parse_assembly()?,
+            "_" if self.peek_nth(1).text == ";" => {
+                self.next();
+                self.next();
+                Statement::ModifierBody(start.to(&self.last_span))
+            }
+            // reverting with a custom error, eg. `revert Unauthorized(caller);`
+            "revert" if self.peek_nth(1).kind == TokenKind::Identifier => {
+                self.next();
+                let error = self.parse_expression()?;
+                self.expect(";")?;
+                let span = start.to(&self.last_span);
+                let revert = Expression::Identifier(token.text, token.span);
+                Statement::Expression(Expression::Call(bx!(revert), vec![error], span), span)
+            }
+            _ => self.parse_simple_statement()?,
+        };
+
+        Ok(statement)
+    }
+
+    /// Parses the condition of an if or a while statement, including the parentheses
+    fn parse_condition(&mut self) -> ParseResult<Expression> {
+        self.expect("(")?;
+        let condition = self.parse_expression()?;
+        self.expect(")")?;
+        Ok(condition)
+    }
+
+    /// Parses a variable declaration or an expression statement, including the semicolon
+    fn parse_simple_statement(&mut self) -> ParseResult<Statement> {
+        let start = self.peek().span;
+
+        // `delete values;` is not a declaration of `values` of the type `delete`
+        let declarations = if self.is("delete") {
+            None
+        } else if self.is("(") {
+            self.speculate(Self::parse_tuple_declaration)
+        } else {
+            self.speculate(Self::parse_single_declaration)
+        };
+        if let Some(declarations) = declarations {
+            let value = if self.eat("=") {
+                Some(self.parse_expression()?)
+            } else {
+                None
+            };
+            self.expect(";")?;
+            return Ok(Statement::VariableDeclaration(
+                declarations,
+                value,
+                start.to(&self.last_span),
+            ))
+        }
+
+        let expression = self.parse_expression()?;
+        self.expect(";")?;
+        Ok(Statement::Expression(expression, start.to(&self.last_span)))
+    }
+
+    /// Parses the declaration of a variable, the declaration must be followed by `=` or `;`
+    fn parse_single_declaration(&mut self) -> ParseResult<Vec<Option<VariableDeclaration>>> {
+        let declaration = self.parse_variable_declaration()?;
+        if !self.is("=") && !self.is(";") {
+            return Err(self.unexpected("`=` or `;`"))
+        }
+        Ok(vec![Some(declaration)])
+    }
+
+    /// Parses the declaration of multiple variables, eg. `(uint a, , bool c)`,
+    /// the declaration must be followed by `=`
+    fn parse_tuple_declaration(&mut self) -> ParseResult<Vec<Option<VariableDeclaration>>> {
+        let mut declarations = Vec::new();
+
+        self.expect("(")?;
+        loop {
+            if self.is(",") || self.is(")") {
+                declarations.push(None);
+            } else {
+                declarations.push(Some(self.parse_variable_declaration()?));
+            }
+            if !self.eat(",") {
+                break
+            }
+        }
+        self.expect(")")?;
+        if !self.is("=") {
+            return Err(self.unexpected("`=`"))
+        }
+
+        Ok(declarations)
+    }
+
+    fn parse_variable_declaration(&mut self) -> ParseResult<VariableDeclaration> {
+        let start = self.peek().span;
+        let type_name = self.parse_type_name()?;
+        let storage = self.is("storage");
+        if DATA_LOCATIONS.contains(&self.peek().text.as_str()) {
+            self.next();
+        }
+        let (name, _) = self.expect_identifier()?;
+
+        Ok(VariableDeclaration {
+            type_name,
+            name,
+            storage,
+            span: start.to(&self.last_span),
+        })
+    }
+
+    /// Parses a try statement with its catch clauses
+    fn parse_try(&mut self) -> ParseResult<Statement> {
+        let start = self.next().span;
+        let expression = self.parse_expression()?;
+        let returns = if self.eat("returns") {
+            self.parse_parameters()?
+        } else {
+            Vec::new()
+        };
+        let header = self.header(start);
+        let body = self.parse_block()?;
+
+        let mut clauses = Vec::new();
+        while self.is("catch") {
+            let clause_start = self.next().span;
+            let kind = if self.peek().kind == TokenKind::Identifier {
+                Some(self.next().text)
+            } else {
+                None
+            };
+            let parameters = if self.is("(") {
+                self.parse_parameters()?
+            } else {
+                Vec::new()
+            };
+            let header = self.header(clause_start);
+            let body = self.parse_block()?;
+            clauses.push(CatchClause {
+                header,
+                kind,
+                parameters,
+                body,
+                span: clause_start.to(&self.last_span),
+            });
+        }
+
+        Ok(Statement::Try(
+            expression,
+            header,
+            returns,
+            body,
+            clauses,
+            start.to(&self.last_span),
+        ))
+    }
+
+    /// Parses an assembly block, the assembly is kept as lines of source code
+    fn parse_assembly(&mut self) -> ParseResult<Statement> {
+        let start = self.next().span;
+        // the dialect and the flags of the block, eg. `assembly ("memory-safe") {`
+        while !self.is("{") {
+            if self.peek().kind == TokenKind::Eof {
+                return Err(self.unexpected("`{`"))
+            }
+            self.next();
+        }
+        let open = self.next().span;
+
+        let mut depth = 1;
+        while depth > 0 {
+            let token = self.next_including_comments();
+            match token.text.as_str() {
+                _ if token.kind == TokenKind::Eof => return Err(self.unexpected("`}`")),
+                "{" if token.kind == TokenKind::Punctuation => depth += 1,
+                "}" if token.kind == TokenKind::Punctuation => depth -= 1,
+                _ => {}
+            }
+        }
+        let lines = self.source[open.end..self.last_span.start]
+            .lines()
+            .map(trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        Ok(Statement::Assembly(lines, start.to(&self.last_span)))
+    }
+
+    fn parse_expression(&mut self) -> ParseResult<Expression> {
+        let left = self.parse_conditional()?;
+        let token = self.peek().clone();
+        if token.kind == TokenKind::Punctuation
+            && ASSIGNMENT_OPERATORS.contains(&token.text.as_str())
+        {
+            self.next();
+            let right = self.parse_expression()?;
+            let span = left.span().to(&right.span());
+            return Ok(Expression::Assign(bx!(left), token.text, bx!(right), span))
+        }
+
+        Ok(left)
+    }
+
+    fn parse_conditional(&mut self) -> ParseResult<Expression> {
+        let condition = self.parse_binary(1)?;
+        if self.eat("?") {
+            let if_true = self.parse_expression()?;
+            self.expect(":")?;
+            let if_false = self.parse_expression()?;
+            let span = condition.span().to(&if_false.span());
+            return Ok(Expression::Conditional(
+                bx!(condition),
+                bx!(if_true),
+                bx!(if_false),
+                span,
+            ))
+        }
+
+        Ok(condition)
+    }
+
+    /// Parses binary operations with precedence climbing
+    ///
+    /// `min_precedence` the lowest precedence of an operator which is parsed
+    fn parse_binary(&mut self, min_precedence: u8) -> ParseResult<Expression> {
+        let mut left = self.parse_unary()?;
+
+        loop {
+            let token = self.peek().clone();
+            let precedence = match binary_precedence(&token) {
+                Some(precedence) if precedence >= min_precedence => precedence,
+                _ => break,
+            };
+            self.next();
+            // exponentiation is right associative
+            let right = if token.text == "**" {
+                self.parse_binary(precedence)?
+            } else {
+                self.parse_binary(precedence + 1)?
+            };
+            let span = left.span().to(&right.span());
+            left = Expression::Binary(bx!(left), token.text, bx!(right), span);
+        }
+
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> ParseResult<Expression> {
+        let token = self.peek().clone();
+        if token.kind != TokenKind::String && PREFIX_OPERATORS.contains(&token.text.as_str()) {
+            self.next();
+            let expression = self.parse_unary()?;
+            let span = token.span.to(&expression.span());
+            return Ok(Expression::Prefix(token.text, bx!(expression), span))
+        }
+
+        self.parse_postfix()
+    }
+
+    /// Parses member accesses, index accesses, function calls and postfix operators
+    fn parse_postfix(&mut self) -> ParseResult<Expression> {
+        let mut expression = self.parse_primary()?;
+
+        loop {
+            let start = expression.span();
+            let token = self.peek().clone();
+            if token.kind != TokenKind::Punctuation {
+                break
+            }
+            expression = match token.text.as_str() {
+                "." => {
+                    self.next();
+                    let (member, _) = self.expect_identifier()?;
+                    Expression::Member(bx!(expression), member, start.to(&self.last_span))
+                }
+                "[" => {
+                    self.next();
+                    let index = if self.is("]") {
+                        None
+                    } else {
+                        Some(bx!(self.parse_expression()?))
+                    };
+                    self.expect("]")?;
+                    Expression::Index(bx!(expression), index, start.to(&self.last_span))
+                }
+                "(" if self.peek_nth(1).text == "{" => {
+                    self.next();
+                    let args = self.parse_named_arguments()?;
+                    self.expect(")")?;
+                    Expression::NamedCall(bx!(expression), args, start.to(&self.last_span))
+                }
+                "(" => {
+                    let args = self.parse_call_arguments()?;
+                    Expression::Call(bx!(expression), args, start.to(&self.last_span))
+                }
+                // call options, eg. `to.call{value: amount}("")`
+                "{" if self.peek_nth(1).kind == TokenKind::Identifier
+                    && self.peek_nth(2).text == ":" =>
+                {
+                    let options = self.parse_named_arguments()?;
+                    Expression::CallOptions(bx!(expression), options, start.to(&self.last_span))
+                }
+                "++" | "--" => {
+                    self.next();
+                    Expression::Postfix(bx!(expression), token.text, start.to(&self.last_span))
+                }
+                _ => break,
+            };
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<Expression> {
+        let token = self.peek().clone();
+        let start = token.span;
+
+        let expression = match token.kind {
+            TokenKind::Number => {
+                self.next();
+                let unit = if NUMBER_UNITS.contains(&self.peek().text.as_str()) {
+                    Some(self.next().text)
+                } else {
+                    None
+                };
+                Expression::NumberLiteral(token.text, unit, start.to(&self.last_span))
+            }
+            TokenKind::String => {
+                self.next();
+                Expression::StringLiteral(token.text, start)
+            }
+            TokenKind::HexString => {
+                self.next();
+                Expression::HexLiteral(token.text, start)
+            }
+            TokenKind::Identifier if token.text == "true" || token.text == "false" => {
+                self.next();
+                Expression::BoolLiteral(token.text == "true", start)
+            }
+            TokenKind::Identifier if token.text == "new" => {
+                self.next();
+                let type_name = self.parse_type_name()?;
+                Expression::New(type_name, start.to(&self.last_span))
+            }
+            TokenKind::Identifier => {
+                self.next();
+                Expression::Identifier(token.text, start)
+            }
+            TokenKind::Punctuation if token.text == "(" => {
+                self.next();
+                let mut components = Vec::new();
+                if !self.is(")") {
+                    loop {
+                        if self.is(",") || self.is(")") {
+                            components.push(None);
+                        } else {
+                            components.push(Some(self.parse_expression()?));
+                        }
+                        if !self.eat(",") {
+                            break
+                        }
+                    }
+                }
+                self.expect(")")?;
+                let span = start.to(&self.last_span);
+                match components.as_slice() {
+                    [Some(expression)] => Expression::Parenthesis(bx!(expression.clone()), span),
+                    _ => Expression::Tuple(components, span),
+                }
+            }
+            TokenKind::Punctuation if token.text == "[" => {
+                self.next();
+                let mut expressions = Vec::new();
+                while !self.eat("]") {
+                    expressions.push(self.parse_expression()?);
+                    if !self.eat(",") {
+                        self.expect("]")?;
+                        break
+                    }
+                }
+                Expression::ArrayLiteral(expressions, start.to(&self.last_span))
+            }
+            _ => return Err(self.unexpected("expression")),
+        };
+
+        Ok(expression)
+    }
+
+    /// Parses the arguments of a function call, including the parentheses
+    fn parse_call_arguments(&mut self) -> ParseResult<Vec<Expression>> {
+        let mut args = Vec::new();
+
+        self.expect("(")?;
+        while !self.eat(")") {
+            args.push(self.parse_expression()?);
+            if !self.eat(",") {
+                self.expect(")")?;
+                break
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Parses named arguments, eg. `{to: receiver, amount: 1}`, including the curly braces
+    fn parse_named_arguments(&mut self) -> ParseResult<Vec<NamedArgument>> {
+        let mut args = Vec::new();
+
+        self.expect("{")?;
+        while !self.eat("}") {
+            let (name, start) = self.expect_identifier()?;
+            self.expect(":")?;
+            let value = self.parse_expression()?;
+            args.push(NamedArgument {
+                name,
+                value,
+                span: start.to(&self.last_span),
+            });
+            if !self.eat(",") {
+                self.expect("}")?;
+                break
+            }
+        }
+
+        Ok(args)
+    }
+
+    /// Runs `parse`, the parser is moved back to the current token if `parse` fails
+    fn speculate<T>(&mut self, parse: fn(&mut Self) -> ParseResult<T>) -> Option<T> {
+        let position = self.position;
+        let comments = self.comments.len();
+        let last_span = self.last_span;
+
+        match parse(self) {
+            Ok(output) => Some(output),
+            Err(_) => {
+                self.position = position;
+                self.comments.truncate(comments);
+                self.last_span = last_span;
+                None
+            }
+        }
+    }
+
+    /// Moves the comments at the current position to `self.comments`
+    ///
+    /// returns the current token
+    fn peek(&mut self) -> &Token {
+        while self.tokens[self.position].kind == TokenKind::Comment {
+            self.comments.push(self.tokens[self.position].clone());
+            self.position += 1;
+        }
+        &self.tokens[self.position]
+    }
+
+    /// returns the `n`-th token after the current token, comments are skipped
+    fn peek_nth(&mut self, n: usize) -> &Token {
+        self.peek();
+        let mut position = self.position;
+        for _ in 0..n {
+            if self.tokens[position].kind == TokenKind::Eof {
+                break
+            }
+            position += 1;
+            while self.tokens[position].kind == TokenKind::Comment {
+                position += 1;
+            }
+        }
+        &self.tokens[position]
+    }
+
+    /// returns true if the current token is the keyword or the punctuation `text`
+    fn is(&mut self, text: &str) -> bool {
+        let token = self.peek();
+        matches!(
+            token.kind,
+            TokenKind::Identifier | TokenKind::Punctuation
+        ) && token.text == text
+    }
+
+    /// Consumes the current token
+    fn next(&mut self) -> Token {
+        self.peek();
+        self.next_including_comments()
+    }
+
+    /// Consumes the current token, even if it is a comment
+    fn next_including_comments(&mut self) -> Token {
+        let token = self.tokens[self.position].clone();
+        if token.kind != TokenKind::Eof {
+            self.position += 1;
+            self.last_span = token.span;
+        }
+        token
+    }
+
+    /// Consumes the current token if it is `text`
+    ///
+    /// returns true if the token was consumed
+    fn eat(&mut self, text: &str) -> bool {
+        if self.is(text) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, text: &str) -> ParseResult<Span> {
+        if self.is(text) {
+            Ok(self.next().span)
+        } else {
+            Err(self.unexpected(&format!("`{text}`")))
+        }
+    }
+
+    fn expect_identifier(&mut self) -> ParseResult<(String, Span)> {
+        if self.peek().kind == TokenKind::Identifier {
+            let token = self.next();
+            Ok((token.text, token.span))
+        } else {
+            Err(self.unexpected("identifier"))
+        }
+    }
+
+    /// Consumes the tokens until `text` is consumed
+    fn skip_until(&mut self, text: &str) -> ParseResult<()> {
+        while !self.eat(text) {
+            if self.peek().kind == TokenKind::Eof {
+                return Err(self.unexpected(&format!("`{text}`")))
+            }
+            self.next();
+        }
+        Ok(())
+    }
+
+    /// returns the comments read since the last call, they document the next definition
+    fn take_comments(&mut self) -> Vec<String> {
+        self.peek();
+        self.comments
+            .drain(..)
+            .map(|comment| comment.text)
+            .collect()
+    }
+
+    /// returns the comments read since the last call as comment statements
+    fn take_comment_statements(&mut self) -> Vec<Statement> {
+        self.comments
+            .drain(..)
+            .map(|comment| Statement::Comment(trim(&comment.text), comment.span))
+            .collect()
+    }
+
+    /// returns the source code from `start` until the end of the current token,
+    /// with whitespace collapsed to single spaces
+    fn header(&mut self, start: Span) -> String {
+        let end = self.peek().span.end;
+        trim(&self.source[start.start..end])
+    }
+
+    /// Reports the syntax error and skips the erroneous statement or definition
+    ///
+    /// The tokens are skipped until the `;` ending the statement or until the `}` closing
+    /// a block opened by the statement. The `}` closing the enclosing block is not skipped.
+    fn recover(&mut self, error: Diagnostic) {
+        self.diagnostics.push(error);
+        self.comments.clear();
+
+        let mut depth = 0usize;
+        loop {
+            let token = self.peek().clone();
+            if token.kind == TokenKind::Eof {
+                break
+            }
+            if token.kind == TokenKind::Punctuation {
+                match token.text.as_str() {
+                    "(" | "[" | "{" => depth += 1,
+                    ")" | "]" => depth = depth.saturating_sub(1),
+                    "}" if depth == 0 => break,
+                    "}" => {
+                        depth -= 1;
+                        if depth == 0 {
+                            self.next();
+                            break
+                        }
+                    }
+                    ";" if depth == 0 => {
+                        self.next();
+                        break
+                    }
+                    _ => {}
+                }
+            }
+            self.next();
+        }
+        self.comments.clear();
+    }
+
+    /// returns the error describing that `expected` was expected instead of the current token
+    fn unexpected(&mut self, expected: &str) -> Diagnostic {
+        let token = self.peek().clone();
+        let found = if token.kind == TokenKind::Eof {
+            String::from("end of file")
+        } else {
+            format!("`{}`", token.text)
+        };
+        Diagnostic::error(
+            format!("expected {expected}, found {found}"),
+            Some(token.span),
+        )
+    }
+
+    /// returns the error `message` located at the last consumed token
+    fn error_at_last(&self, message: &str) -> Diagnostic {
+        Diagnostic::error(message.to_owned(), Some(self.last_span))
+    }
+}
+
+/// returns the precedence of a binary operator, higher binds stronger,
+/// or None if the token is not a binary operator
+fn binary_precedence(token: &Token) -> Option<u8> {
+    if token.kind != TokenKind::Punctuation {
+        return None
+    }
+    let precedence = match token.text.as_str() {
+        "||" => 1,
+        "&&" => 2,
+        "==" | "!=" => 3,
+        "<" | ">" | "<=" | ">=" => 4,
+        "|" => 5,
+        "^" => 6,
+        "&" => 7,
+        "<<" | ">>" | ">>>" => 8,
+        "+" | "-" => 9,
+        "*" | "/" | "%" => 10,
+        "**" => 11,
+        _ => return None,
+    };
+    Some(precedence)
+}
+
+/// returns true if `name` is a value type of Solidity, eg. `address`, `uint256` or `bytes32`
+fn is_elementary_type(name: &str) -> bool {
+    let sized = |prefix: &str| {
+        name.strip_prefix(prefix)
+            .is_some_and(|size| size.chars().all(|ch| ch.is_ascii_digit()))
+    };
+    matches!(name, "address" | "bool" | "string" | "byte") || sized("bytes") || sized("int") || sized("uint")
+}
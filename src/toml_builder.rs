@@ -20,62 +20,316 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::assembler::Backend;
+use convert_case::{
+    Case::Snake,
+    Casing,
+};
+
 const INK_VERSION: &str = "~3.3.0";
 const OPENBRUSH_VERSION: &str = "2.2.0";
+const INK_4_VERSION: &str = "4.2.1";
+const OPENBRUSH_3_VERSION: &str = "3.1.1";
+const PRIMITIVE_TYPES_VERSION: &str = "0.12";
+const EDITIONS: [&str; 4] = ["2015", "2018", "2021", "2024"];
+
+/// The settings of the generated Cargo.toml files
+///
+/// `backend` the version of ink! targeted by the generated code
+/// `ink_version` and `openbrush_version` the version requirements of the dependencies
+/// `package_prefix` the prefix of the names of the generated packages, the names are derived
+/// from the names of the contracts
+/// `authors` the authors of the generated packages
+/// `edition` the Rust edition of the generated packages
+/// `u256` if the unsigned integers wider than 128 bits are transpiled to `U256` of the
+/// `primitive-types` crate instead of `u128`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Manifest {
+    pub backend: Backend,
+    pub ink_version: String,
+    pub openbrush_version: String,
+    pub package_prefix: Option<String>,
+    pub authors: Vec<String>,
+    pub edition: String,
+    pub u256: bool,
+}
+
+impl Default for Manifest {
+    fn default() -> Self {
+        Manifest::new(Backend::default())
+    }
+}
+
+impl Manifest {
+    /// returns the default settings of the crates generated by the backend
+    pub fn new(backend: Backend) -> Self {
+        let (ink_version, openbrush_version) = match backend {
+            Backend::Ink3 => (INK_VERSION, OPENBRUSH_VERSION),
+            Backend::Ink4 => (INK_4_VERSION, OPENBRUSH_3_VERSION),
+        };
+        Manifest {
+            backend,
+            ink_version: String::from(ink_version),
+            openbrush_version: String::from(openbrush_version),
+            package_prefix: None,
+            authors: vec![String::from("Sol2Ink")],
+            edition: String::from("2021"),
+            u256: false,
+        }
+    }
+
+    /// Checks that the generated crates can be built with the chosen versions and edition
+    ///
+    /// ink! 3 is supported by OpenBrush 2 and ink! 4 by OpenBrush 3
+    ///
+    /// returns the reason if the settings are not consistent
+    pub fn validate(&self) -> Result<(), String> {
+        let (ink_major, openbrush_major) = match self.backend {
+            Backend::Ink3 => (3, 2),
+            Backend::Ink4 => (4, 3),
+        };
+        match major_version(&self.ink_version) {
+            Some(major) if major == ink_major => {}
+            Some(_) => {
+                return Err(format!(
+                    "ink! version `{}` is not supported by the `{}` backend, use ink! {ink_major}",
+                    self.ink_version, self.backend
+                ))
+            }
+            None => return Err(format!("invalid ink! version `{}`", self.ink_version)),
+        }
+        match major_version(&self.openbrush_version) {
+            Some(major) if major == openbrush_major => {}
+            Some(_) => {
+                return Err(format!(
+                    "OpenBrush version `{}` does not support ink! `{}`, use OpenBrush {openbrush_major}",
+                    self.openbrush_version, self.ink_version
+                ))
+            }
+            None => return Err(format!("invalid OpenBrush version `{}`", self.openbrush_version)),
+        }
+        if let Some(prefix) = &self.package_prefix {
+            let valid = prefix
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-');
+            if prefix.is_empty() || !valid {
+                return Err(format!("invalid package prefix `{prefix}`"))
+            }
+        }
+        if !EDITIONS.contains(&self.edition.as_str()) {
+            return Err(format!("invalid edition `{}`", self.edition))
+        }
+        Ok(())
+    }
+
+    /// returns the name of the package of the contract, eg. `erc_20` or `prefix_erc_20`
+    ///
+    /// `name` the name of the contract, interface or file the package is generated from
+    pub fn package_name(&self, name: &str) -> String {
+        match &self.package_prefix {
+            Some(prefix) => format!("{prefix}_{}", name.to_case(Snake)),
+            None => name.to_case(Snake),
+        }
+    }
+}
+
+/// A crate of an interface called by the contract
+///
+/// `name` the name under which the contract uses the crate
+/// `package` the name of the package of the crate
+/// `path` the path to the crate from the crate of the contract
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dependency {
+    pub name: String,
+    pub package: String,
+    pub path: String,
+}
+
+/// returns the name under which a contract uses the crate of the interface
+///
+/// `interface` the name of the interface without the `I` prefix, eg. `ERC20`
+pub fn dependency_name(interface: &str) -> String {
+    interface.to_case(Snake)
+}
+
+/// returns the major version of the version requirement, eg. 3 for `~3.3.0`
+fn major_version(requirement: &str) -> Option<u64> {
+    requirement
+        .trim_start_matches(['~', '^', '=', '>', '<', ' '])
+        .split('.')
+        .next()?
+        .parse()
+        .ok()
+}
 
-pub fn generate_cargo_toml() -> String {
+/// Generates the Cargo.toml of a transpiled contract
+///
+/// `name` the name of the contract, interface or file the package is generated from
+/// `common_path` the path to the crate with the types shared by the contracts, if any
+/// `dependencies` the crates of the interfaces called by the contract
+/// `manifest` the settings of the generated Cargo.toml
+pub fn generate_cargo_toml(
+    name: &str,
+    common_path: Option<String>,
+    dependencies: &[Dependency],
+    manifest: &Manifest,
+) -> String {
+    let package_name = manifest.package_name(name);
     let mut out = String::new();
 
-    out.push_str("[package]\n");
-    out.push_str("name = \"sol_2_ink_generated\"\n");
-    out.push_str("version = \"0.1.0\"\n");
-    out.push_str("edition = \"2021\"\n");
-    out.push_str("authors = [\"Sol2Ink\"]\n");
+    out.push_str(&generate_package(&package_name, manifest));
     out.push('\n');
     out.push_str("[dependencies]\n");
-    out.push_str(generate_ink_dependency("ink_primitives", false, false).as_str());
-    out.push_str(generate_ink_dependency("ink_metadata", true, true).as_str());
-    out.push_str(generate_ink_dependency("ink_env", false, false).as_str());
-    out.push_str(generate_ink_dependency("ink_storage", false, false).as_str());
-    out.push_str(generate_ink_dependency("ink_lang", false, false).as_str());
-    out.push_str(generate_ink_dependency("ink_prelude", false, false).as_str());
-    out.push_str(generate_ink_dependency("ink_engine", false, true).as_str());
-    out.push_str("scale = { package = \"parity-scale-codec\", version = \"3\", default-features = false, features = [\"derive\"] }\n");
-    out.push_str("scale-info = { version = \"2\", default-features = false, features = [\"derive\"], optional = true }\n");
-    out.push_str("openbrush = { version = \"");
-    out.push_str(OPENBRUSH_VERSION);
-    out.push_str("\", default-features = false }\n");
+    out.push_str(generate_dependencies(manifest).as_str());
+    if let Some(path) = &common_path {
+        let common = manifest.package_name("common");
+        out.push_str("common = { ");
+        if common != "common" {
+            out.push_str(&format!("package = \"{common}\", "));
+        }
+        out.push_str(&format!("path = \"{path}\", default-features = false }}\n"));
+    }
+    for dependency in dependencies.iter() {
+        out.push_str(&format!(
+            "{} = {{ package = \"{}\", path = \"{}\", default-features = false }}\n",
+            dependency.name, dependency.package, dependency.path
+        ));
+    }
     out.push('\n');
     out.push_str("[lib]\n");
-    out.push_str("name = \"sol_2_ink_generated\"\n");
+    out.push_str(&format!("name = \"{}\"\n", package_name.replace('-', "_")));
     out.push_str("path = \"lib.rs\"\n");
-    out.push_str("crate-type = [\"cdylib\"]\n");
+    if manifest.backend == Backend::Ink3 {
+        out.push_str("crate-type = [\"cdylib\"]\n");
+    }
     out.push('\n');
+    out.push_str(generate_features(common_path.is_some(), dependencies, manifest).as_str());
+
+    out
+}
+
+/// Generates the Cargo.toml of the crate with the types shared by the contracts
+///
+/// `manifest` the settings of the generated Cargo.toml, the crate is named `common`
+/// with the package prefix
+pub fn generate_common_cargo_toml(manifest: &Manifest) -> String {
+    let package_name = manifest.package_name("common");
+    let mut out = String::new();
+
+    out.push_str(&generate_package(&package_name, manifest));
+    out.push('\n');
+    out.push_str("[dependencies]\n");
+    out.push_str(generate_dependencies(manifest).as_str());
+    out.push('\n');
+    out.push_str("[lib]\n");
+    out.push_str(&format!("name = \"{}\"\n", package_name.replace('-', "_")));
+    out.push_str("path = \"lib.rs\"\n");
+    out.push_str("crate-type = [\"rlib\"]\n");
+    out.push('\n');
+    out.push_str(generate_features(false, &[], manifest).as_str());
+
+    out
+}
+
+fn generate_package(package_name: &str, manifest: &Manifest) -> String {
+    let mut out = String::new();
+
+    out.push_str("[package]\n");
+    out.push_str(&format!("name = \"{package_name}\"\n"));
+    out.push_str("version = \"0.1.0\"\n");
+    out.push_str(&format!("edition = \"{}\"\n", manifest.edition));
+    let authors = manifest
+        .authors
+        .iter()
+        .map(|author| format!("{author:?}"))
+        .collect::<Vec<_>>();
+    out.push_str(&format!("authors = [{}]\n", authors.join(", ")));
+
+    out
+}
+
+fn generate_dependencies(manifest: &Manifest) -> String {
+    let mut out = String::new();
+    let ink = &manifest.ink_version;
+
+    match manifest.backend {
+        Backend::Ink3 => {
+            out.push_str(generate_ink_dependency(ink, "ink_primitives", false, false).as_str());
+            out.push_str(generate_ink_dependency(ink, "ink_metadata", true, true).as_str());
+            out.push_str(generate_ink_dependency(ink, "ink_env", false, false).as_str());
+            out.push_str(generate_ink_dependency(ink, "ink_storage", false, false).as_str());
+            out.push_str(generate_ink_dependency(ink, "ink_lang", false, false).as_str());
+            out.push_str(generate_ink_dependency(ink, "ink_prelude", false, false).as_str());
+            out.push_str(generate_ink_dependency(ink, "ink_engine", false, true).as_str());
+        }
+        Backend::Ink4 => {
+            out.push_str(generate_ink_dependency(ink, "ink", false, false).as_str());
+        }
+    }
+    out.push_str("scale = { package = \"parity-scale-codec\", version = \"3\", default-features = false, features = [\"derive\"] }\n");
+    out.push_str("scale-info = { version = \"2\", default-features = false, features = [\"derive\"], optional = true }\n");
+    out.push_str("openbrush = { version = \"");
+    out.push_str(&manifest.openbrush_version);
+    out.push_str("\", default-features = false }\n");
+    if manifest.u256 {
+        out.push_str("primitive-types = { version = \"");
+        out.push_str(PRIMITIVE_TYPES_VERSION);
+        out.push_str("\", default-features = false, features = [\"codec\", \"scale-info\"] }\n");
+    }
+
+    out
+}
+
+fn generate_features(common: bool, dependencies: &[Dependency], manifest: &Manifest) -> String {
+    let mut out = String::new();
+
     out.push_str("[features]\n");
     out.push_str("default = [\"std\"]\n");
     out.push_str("std = [\n");
-    out.push_str("\"ink_primitives/std\",\n");
-    out.push_str("\"ink_metadata\",\n");
-    out.push_str("\"ink_metadata/std\",\n");
-    out.push_str("\"ink_env/std\",\n");
-    out.push_str("\"ink_storage/std\",\n");
-    out.push_str("\"ink_lang/std\",\n");
+    match manifest.backend {
+        Backend::Ink3 => {
+            out.push_str("\"ink_primitives/std\",\n");
+            out.push_str("\"ink_metadata\",\n");
+            out.push_str("\"ink_metadata/std\",\n");
+            out.push_str("\"ink_env/std\",\n");
+            out.push_str("\"ink_storage/std\",\n");
+            out.push_str("\"ink_lang/std\",\n");
+        }
+        Backend::Ink4 => out.push_str("\"ink/std\",\n"),
+    }
     out.push_str("\"scale/std\",\n");
     out.push_str("\"scale-info\",\n");
     out.push_str("\"scale-info/std\",\n");
     out.push_str("\"openbrush/std\",\n");
+    if manifest.u256 {
+        out.push_str("\"primitive-types/std\",\n");
+    }
+    if common {
+        out.push_str("\"common/std\",\n");
+    }
+    for dependency in dependencies.iter() {
+        out.push_str(&format!("\"{}/std\",\n", dependency.name));
+    }
     out.push_str("]\n");
+    if manifest.backend == Backend::Ink4 {
+        out.push_str("ink-as-dependency = []\n");
+    }
     out.push('\n');
 
     out
 }
 
-fn generate_ink_dependency(crate_name: &str, derive: bool, optional: bool) -> String {
+fn generate_ink_dependency(
+    version: &str,
+    crate_name: &str,
+    derive: bool,
+    optional: bool,
+) -> String {
     let mut out = String::new();
 
     out.push_str(crate_name);
     out.push_str(" = { version = \"");
-    out.push_str(INK_VERSION);
+    out.push_str(version);
     out.push_str("\", default-features = false");
 
     if derive {
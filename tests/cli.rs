@@ -0,0 +1,153 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Runs the `sol_to_ink` binary on files in temporary directories
+
+use std::{
+    fs,
+    path::Path,
+    process::{
+        Command,
+        Output,
+    },
+};
+use tempfile::TempDir;
+
+const FLIPPER: &str = include_str!("../examples/contracts/Flipper/flipper.sol");
+const SYNTAX_ERRORS: &str = include_str!("../examples/contracts/SyntaxErrors/SyntaxErrors.sol");
+
+/// Creates a temporary directory with the Solidity files
+fn project(files: &[(&str, &str)]) -> TempDir {
+    let directory = tempfile::tempdir().unwrap();
+    for (name, content) in files {
+        fs::write(directory.path().join(name), content).unwrap();
+    }
+    directory
+}
+
+/// Runs `sol_to_ink` with the arguments in the directory
+fn sol2ink(directory: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sol_to_ink"))
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .expect("sol_to_ink is executed")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).to_string()
+}
+
+#[test]
+fn writes_crate() {
+    let directory = project(&[("flipper.sol", FLIPPER)]);
+
+    let output = sol2ink(directory.path(), &["flipper.sol"]);
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Wrote 1 crate, 0 spots to review"));
+    for file in ["lib.rs", "Cargo.toml", "report.json"] {
+        assert!(directory.path().join("flipper").join(file).is_file());
+    }
+}
+
+#[test]
+fn dry_run_writes_nothing() {
+    let directory = project(&[("flipper.sol", FLIPPER)]);
+
+    let output = sol2ink(directory.path(), &["flipper.sol", "--dry-run"]);
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Would write 1 crate"));
+    assert!(!directory.path().join("flipper").exists());
+}
+
+#[test]
+fn overwrites_only_with_force() {
+    let directory = project(&[("flipper.sol", FLIPPER)]);
+    let lib_rs = directory.path().join("flipper").join("lib.rs");
+
+    assert_eq!(sol2ink(directory.path(), &["flipper.sol"]).status.code(), Some(0));
+    fs::write(&lib_rs, "// edited by hand").unwrap();
+
+    let output = sol2ink(directory.path(), &["flipper.sol"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr(&output).contains("already exists, pass `--force` to overwrite it"));
+    assert_eq!(fs::read_to_string(&lib_rs).unwrap(), "// edited by hand");
+
+    let output = sol2ink(directory.path(), &["flipper.sol", "--force"]);
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert!(fs::read_to_string(&lib_rs).unwrap().contains("#[ink(storage)]"));
+}
+
+#[test]
+fn writes_into_output_dir() {
+    let directory = project(&[("flipper.sol", FLIPPER)]);
+
+    let output = sol2ink(directory.path(), &["flipper.sol", "-o", "out", "-q"]);
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert!(stdout(&output).is_empty());
+    assert!(directory.path().join("out/flipper/lib.rs").is_file());
+    assert!(!directory.path().join("flipper").exists());
+}
+
+#[test]
+fn invalid_files_exit_with_1() {
+    let directory = project(&[("flipper.sol", FLIPPER), ("SyntaxErrors.sol", SYNTAX_ERRORS)]);
+
+    let output = sol2ink(directory.path(), &["."]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("error: could not transpile `SyntaxErrors.sol`"));
+    assert!(stderr(&output).contains("error: 1 file could not be transpiled"));
+    // the valid files are still transpiled
+    assert!(directory.path().join("flipper/lib.rs").is_file());
+}
+
+#[test]
+fn usage_errors_exit_with_2() {
+    let directory = project(&[("flipper.sol", FLIPPER)]);
+
+    for args in [
+        &[][..],
+        &["flipper.sol", "--unknown"],
+        &["flipper.sol", "--target", "ink5"],
+        &["flipper.sol", "--ink-version", "2.1.0"],
+    ] {
+        let output = sol2ink(directory.path(), args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+    }
+    assert!(!directory.path().join("flipper").exists());
+}
+
+#[test]
+fn io_errors_exit_with_3() {
+    let directory = project(&[]);
+
+    let output = sol2ink(directory.path(), &["missing.sol"]);
+    assert_eq!(output.status.code(), Some(3));
+
+    let output = sol2ink(directory.path(), &["*.sol"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr(&output).contains("no file matches `*.sol`"));
+}
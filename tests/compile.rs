@@ -0,0 +1,244 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Builds the transpiled crates with ink! and runs tests against them
+//!
+//! The tests download and build ink! and OpenBrush, so they are ignored by default,
+//! run them with `cargo test --test compile -- --ignored`. The transpiled crates are built
+//! for `wasm32-unknown-unknown` without `std`, the way the contracts are deployed.
+//! The ink! 3 crates are built only with the toolchain passed by `SOL2INK_INK3_TOOLCHAIN`,
+//! eg. `SOL2INK_INK3_TOOLCHAIN=nightly-2023-01-01`, as OpenBrush 2 does not build
+//! with the recent toolchains
+
+use sol_to_ink::{
+    assembler::Backend,
+    toml_builder::Manifest,
+    transpile,
+    TranspileOptions,
+};
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::Command,
+};
+
+/// The fixtures transpiled with the ink! 4 backend whose expected crates build
+const INK4_FIXTURES: &[&str] = &[
+    "arithmetic_ink4",
+    "erc20_ink4",
+    "modifiers_ink4",
+    "u256_ink4",
+];
+
+/// The fixtures transpiled with the ink! 3 backend whose expected crates build
+const INK3_FIXTURES: &[&str] = &["arithmetic", "constants", "u256"];
+
+/// The environment variable with the toolchain building the ink! 3 crates
+const INK3_TOOLCHAIN: &str = "SOL2INK_INK3_TOOLCHAIN";
+
+/// The arguments of the build of a fixture, the contracts are deployed as `no_std` Wasm
+const BUILD: &[&str] = &[
+    "build",
+    "--target",
+    "wasm32-unknown-unknown",
+    "--no-default-features",
+];
+
+/// The manifest of a transpiled library, libraries do not use OpenBrush
+const LIBRARY_MANIFEST: &str = r#"[package]
+name = "library"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+ink = { version = "4.2.1", default-features = false }
+scale = { package = "parity-scale-codec", version = "3", default-features = false, features = ["derive"] }
+scale-info = { version = "2", default-features = false, features = ["derive"], optional = true }
+
+[lib]
+path = "lib.rs"
+
+[features]
+default = ["std"]
+std = ["ink/std", "scale/std", "scale-info", "scale-info/std"]
+
+[workspace]
+"#;
+
+/// Transpiles the Solidity library with the ink! 4 backend and returns its `lib.rs`
+fn transpile_library(source: &str) -> String {
+    let options = TranspileOptions {
+        manifest: Manifest::new(Backend::Ink4),
+        ..TranspileOptions::default()
+    };
+    let output = transpile(source, &options).expect("the library is transpiled");
+    output.crates[0].lib_rs.clone()
+}
+
+/// returns the directory of the built crates
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("compile")
+}
+
+/// Runs the cargo command with the manifest
+///
+/// The crates built by a toolchain share their target directory, so ink! is built only once
+///
+/// `toolchain` the rustup toolchain running cargo, or None for the cargo running the tests
+///
+/// returns the errors printed by cargo if the command fails
+fn cargo(toolchain: Option<&str>, args: &[&str], manifest: &Path) -> Result<(), String> {
+    let (mut command, target) = match toolchain {
+        Some(toolchain) => {
+            let mut command = Command::new("rustup");
+            command.args(["run", toolchain, "cargo"]);
+            (command, format!("target-{toolchain}"))
+        }
+        None => (Command::new(env!("CARGO")), String::from("target")),
+    };
+    let output = command
+        .args(args)
+        .arg("--manifest-path")
+        .arg(manifest)
+        .env("CARGO_TARGET_DIR", root().join(target))
+        .output()
+        .expect("cargo is executed");
+    if output.status.success() {
+        return Ok(())
+    }
+    Err(String::from_utf8_lossy(&output.stderr).to_string())
+}
+
+/// Runs `cargo test` in a crate with the manifest, `lib.rs` and the tests appended to `lib.rs`
+fn cargo_test(name: &str, manifest: &str, lib_rs: &str, tests: &str) {
+    let directory = root().join(name);
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join("Cargo.toml"), manifest).unwrap();
+    fs::write(directory.join("lib.rs"), format!("{lib_rs}\n{tests}")).unwrap();
+
+    if let Err(errors) = cargo(None, &["test"], &directory.join("Cargo.toml")) {
+        panic!("{lib_rs}\n{errors}")
+    }
+}
+
+/// Builds the expected crates of the fixture in `tests/fixtures` in one workspace
+///
+/// `toolchain` the rustup toolchain building the crates, or None for the cargo running the tests
+fn cargo_build_fixture(name: &str, toolchain: Option<&str>) {
+    let expected = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+        .join("expected");
+    let directory = root().join(name);
+    let _ = fs::remove_dir_all(&directory);
+    let mut members = Vec::new();
+    for entry in fs::read_dir(expected).unwrap() {
+        let path = entry.unwrap().path();
+        if !path.is_dir() {
+            continue
+        }
+        let member = path.file_name().unwrap().to_string_lossy().to_string();
+        fs::create_dir_all(directory.join(&member)).unwrap();
+        for file in ["lib.rs", "Cargo.toml"] {
+            fs::copy(path.join(file), directory.join(&member).join(file)).unwrap();
+        }
+        members.push(format!("{member:?}"));
+    }
+    let manifest = format!(
+        "[workspace]\nmembers = [{}]\nresolver = \"2\"\n",
+        members.join(", ")
+    );
+    fs::write(directory.join("Cargo.toml"), manifest).unwrap();
+
+    if let Err(errors) = cargo(toolchain, BUILD, &directory.join("Cargo.toml")) {
+        panic!("the crates of `{name}` do not build\n{errors}")
+    }
+}
+
+#[test]
+#[ignore = "builds ink!"]
+fn library_writes_back_storage() {
+    let lib_rs = transpile_library(
+        r#"
+        pragma solidity ^0.8.0;
+
+        library Counters {
+            struct Counter {
+                uint256 value;
+            }
+
+            function current(Counter storage counter) internal view returns (uint256) {
+                return counter.value;
+            }
+
+            function increment(Counter storage counter) internal {
+                counter.value += 1;
+            }
+
+            function incrementTwice(Counter storage counter) internal {
+                increment(counter);
+                increment(counter);
+            }
+        }
+        "#,
+    );
+
+    cargo_test(
+        "library_writes_back_storage",
+        LIBRARY_MANIFEST,
+        &lib_rs,
+        r#"
+        #[test]
+        fn writes_back() {
+            let mut counter = counters::Counter::default();
+            counters::increment(&mut counter).unwrap();
+            counters::increment_twice(&mut counter).unwrap();
+            assert_eq!(counter.value, 3);
+            assert_eq!(counters::current(&counter), Ok(3));
+        }
+        "#,
+    );
+}
+
+#[test]
+#[ignore = "builds ink! and OpenBrush"]
+fn ink4_fixtures_build() {
+    for name in INK4_FIXTURES {
+        cargo_build_fixture(name, None);
+    }
+}
+
+#[test]
+#[ignore = "builds ink! 3 and OpenBrush 2"]
+fn ink3_fixtures_build() {
+    let Ok(toolchain) = env::var(INK3_TOOLCHAIN) else {
+        eprintln!("the ink! 3 fixtures are not built, `{INK3_TOOLCHAIN}` is not set");
+        return
+    };
+    for name in INK3_FIXTURES {
+        cargo_build_fixture(name, Some(&toolchain));
+    }
+}
@@ -0,0 +1,241 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Transpiles the fixtures in `tests/fixtures` with the `sol_to_ink` binary and compares
+//! the written crates and the printed diagnostics with the expected outputs
+//!
+//! A fixture is a directory with a Solidity file named after the directory, the first line
+//! of the file may pass arguments to the binary, eg. `// sol2ink: --target ink4`.
+//! A fixture with a `project` directory instead transpiles the whole directory as a project.
+//! The expected outputs are in the `expected` directory of the fixture, the diagnostics
+//! in its `stderr` file. Run `SOL2INK_BLESS=1 cargo test --test fixtures` to overwrite
+//! the expected outputs after a change of the generated code.
+
+use std::{
+    collections::BTreeSet,
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::Command,
+};
+use tempfile::TempDir;
+
+/// The environment variable which makes the tests overwrite the expected outputs
+const BLESS: &str = "SOL2INK_BLESS";
+
+/// returns the directory with the fixtures
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// returns the arguments passed to the binary by the first line of the Solidity file
+fn arguments(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("// sol2ink:"))
+        .map(|line| line.split_whitespace().map(String::from).collect())
+        .unwrap_or_default()
+}
+
+/// Copies the directory and its subdirectories to `target`
+fn copy_directory(directory: &Path, target: &Path) {
+    for file in files(directory) {
+        fs::create_dir_all(target.join(&file).parent().unwrap()).unwrap();
+        fs::copy(directory.join(&file), target.join(&file)).unwrap();
+    }
+}
+
+/// Transpiles the fixture in a temporary directory
+///
+/// returns the directory, the crates and the `stderr` file are in its `out` directory
+fn transpile(name: &str) -> TempDir {
+    let directory = tempfile::tempdir().unwrap();
+    let project = fixtures().join(name).join("project");
+    let (input, arguments) = if project.is_dir() {
+        copy_directory(&project, &directory.path().join("project"));
+        (String::from("project"), Vec::new())
+    } else {
+        let file = format!("{name}.sol");
+        let source = fs::read_to_string(fixtures().join(name).join(&file)).unwrap();
+        fs::write(directory.path().join(&file), &source).unwrap();
+        (file, arguments(&source))
+    };
+
+    let output = Command::new(env!("CARGO_BIN_EXE_sol_to_ink"))
+        .arg(&input)
+        .args(["-o", "out"])
+        .args(arguments)
+        .current_dir(directory.path())
+        .output()
+        .expect("sol_to_ink is executed");
+    let out = directory.path().join("out");
+    fs::create_dir_all(&out).unwrap();
+    if !output.stderr.is_empty() {
+        fs::write(out.join("stderr"), &output.stderr).unwrap();
+    }
+    directory
+}
+
+/// returns the paths of the files in the directory and its subdirectories,
+/// relative to the directory
+fn files(directory: &Path) -> BTreeSet<PathBuf> {
+    let mut files = BTreeSet::new();
+    let mut directories = vec![directory.to_path_buf()];
+    while let Some(current) = directories.pop() {
+        let Ok(entries) = fs::read_dir(&current) else {
+            continue
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                directories.push(path);
+            } else {
+                files.insert(path.strip_prefix(directory).unwrap().to_path_buf());
+            }
+        }
+    }
+    files
+}
+
+/// returns the first line which differs between the expected and the actual output
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => break,
+            (expected, actual) if expected != actual => {
+                return format!(
+                    "line {line}\n- {}\n+ {}",
+                    expected.unwrap_or("<end of file>"),
+                    actual.unwrap_or("<end of file>")
+                )
+            }
+            _ => {}
+        }
+    }
+    String::from("the line endings")
+}
+
+/// Transpiles the fixture and compares the outputs with the expected outputs,
+/// or overwrites the expected outputs if `SOL2INK_BLESS` is set
+fn check(name: &str) {
+    let directory = transpile(name);
+    let actual = directory.path().join("out");
+    let expected = fixtures().join(name).join("expected");
+
+    if env::var_os(BLESS).is_some() {
+        let _ = fs::remove_dir_all(&expected);
+        copy_directory(&actual, &expected);
+        return
+    }
+
+    assert_eq!(
+        files(&expected),
+        files(&actual),
+        "`{name}` writes other files than expected, run with `{BLESS}=1` to update them"
+    );
+    for file in files(&expected) {
+        let expected = fs::read_to_string(expected.join(&file)).unwrap();
+        let actual = fs::read_to_string(actual.join(&file)).unwrap();
+        assert!(
+            expected == actual,
+            "`{name}/{}` differs from the expected output at {}\nrun with `{BLESS}=1` to update it",
+            file.display(),
+            first_difference(&expected, &actual)
+        );
+    }
+}
+
+macro_rules! fixtures {
+    ($($name:ident),* $(,)?) => {
+        /// The names of the tested fixtures
+        const FIXTURES: &[&str] = &[$(stringify!($name)),*];
+
+        $(
+            #[test]
+            fn $name() {
+                check(stringify!($name));
+            }
+        )*
+    };
+}
+
+fixtures!(
+    address_casts,
+    arithmetic,
+    arithmetic_ink4,
+    arithmetic_wrapping,
+    arrays,
+    builtins,
+    builtins_ink4,
+    bytes_casts,
+    constants,
+    custom_errors,
+    enums,
+    erc20_ink4,
+    function_types,
+    inheritance,
+    inheritance_diamond,
+    interface_calls,
+    interface_names,
+    invalid_constants,
+    keywords,
+    manifest,
+    modifiers,
+    modifiers_ink4,
+    overloads,
+    overloads_arity,
+    project,
+    public_getters,
+    solidity_selectors,
+    solidity_selectors_interface,
+    storage_pointers,
+    structs,
+    syntax_errors,
+    truncated_integers,
+    try_catch,
+    try_catch_ink4,
+    u256,
+    u256_ink4,
+    unsupported_definitions,
+    unsupported_expressions,
+    value_transfers,
+);
+
+#[test]
+fn every_fixture_is_tested() {
+    let mut untested = fs::read_dir(fixtures())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| !FIXTURES.contains(&name.as_str()))
+        .collect::<Vec<_>>();
+    untested.sort();
+    assert!(
+        untested.is_empty(),
+        "add the fixtures {untested:?} to `fixtures!`"
+    );
+}
@@ -0,0 +1,12 @@
+contract Registry {
+    address owner;
+    uint256 id;
+
+    function register(uint256 seed) public {
+        id = uint256(uint160(msg.sender));
+        owner = address(uint160(seed));
+        address first = address(1);
+        uint160 key = uint160(owner);
+        address zero = address(0);
+    }
+}
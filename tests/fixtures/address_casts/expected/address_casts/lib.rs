@@ -0,0 +1,56 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod registry {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub id: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Registry {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Registry {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn register(&mut self, seed: u128) -> Result<(), Error> {
+            self.data.id = (todo!("Sol2Ink Not Implemented yet: uint160(msg.sender)") as u128);
+            self.data.owner = todo!("Sol2Ink Not Implemented yet: address(uint160(seed))");
+            let first: AccountId = todo!("Sol2Ink Not Implemented yet: address(1)");
+            let key: u128 = todo!("Sol2Ink Not Implemented yet: uint160(owner)");
+            let zero: AccountId = ZERO_ADDRESS.into();
+            Ok(())
+        }
+
+    }
+}
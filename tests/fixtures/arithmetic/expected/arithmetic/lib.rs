@@ -0,0 +1,109 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod counter {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub count: u128,
+        pub delta: i128,
+        pub total: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Counter {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new(start: u128, change: i128, exponent: u128) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.count = start
+                    .checked_mul(
+                        2_u128
+                            .checked_pow(u32::try_from(exponent).expect("Arithmetic overflow"))
+                            .expect("Arithmetic overflow"),
+                    )
+                    .expect("Arithmetic overflow");
+                instance.data.delta = change.checked_neg().expect("Arithmetic overflow");
+            })
+        }
+
+        #[ink(message)]
+        pub fn step(&mut self, amount: u128) -> Result<(), Error> {
+            self.data.count = self
+                .data
+                .count
+                .checked_add(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.count = self.data.count.wrapping_sub(amount);
+            self.data.delta = self.data.delta.wrapping_neg();
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn scale(&mut self, exponent: u128) -> Result<(), Error> {
+            self.data.count = self
+                .data
+                .count
+                .checked_pow(
+                    u32::try_from(exponent)
+                        .map_err(|_| Error::Custom(String::from("Arithmetic overflow")))?,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.count = self
+                .data
+                .count
+                .checked_pow(3)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.delta = self
+                .data
+                .delta
+                .checked_neg()
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn accumulate(&mut self, small: u8) -> Result<u128, Error> {
+            self.data.total = self
+                .data
+                .total
+                .checked_add((small as u128))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.total = self
+                .data
+                .total
+                .checked_add((small as u128))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            let last: u128 = (small as u128);
+            if self.data.total > (small as u128) {
+                self.data.total = (small as u128);
+            }
+            return Ok(last);
+        }
+
+    }
+}
@@ -0,0 +1,25 @@
+pragma solidity >=0.6.0 <0.8.0;
+
+contract Counter {
+    uint256 count;
+    int256 delta;
+
+    constructor(uint256 start, int256 change, uint256 exponent) {
+        count = start * 2 ** exponent;
+        delta = -change;
+    }
+
+    function step(uint256 amount) public {
+        count += amount;
+        unchecked {
+            count = count - amount;
+            delta = -delta;
+        }
+    }
+
+    function scale(uint256 exponent) public {
+        count = count ** exponent;
+        count = count ** 3;
+        delta = -delta;
+    }
+}
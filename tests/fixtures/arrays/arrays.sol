@@ -0,0 +1,54 @@
+contract Arrays {
+    uint256[] values;
+    uint256[5] fixedValues;
+    bytes data;
+    mapping(address => uint256[]) owned;
+    bytes32[] hashes;
+    string name;
+
+    struct Proposal {
+        uint256 votes;
+        mapping(address => bool) voted;
+    }
+
+    Proposal[] proposals;
+
+    function add(uint256 value) public {
+        values.push(value);
+        data.push(0x01);
+        owned[msg.sender].push(value);
+        hashes.push(bytes32(0));
+    }
+
+    function clear(uint256 i) public returns (uint256) {
+        delete fixedValues[i];
+        owned[msg.sender][i] = 1;
+        values.pop();
+        return values.length;
+    }
+
+    function first() public returns (bytes1) {
+        bytes1 head = data[0];
+        bytes1 tail;
+        tail = bytes1(data[data.length - 1]);
+        data[1] = head;
+        return head;
+    }
+
+    function initial() public view returns (bytes1) {
+        bytes1 letter = bytes(name)[0];
+        return letter;
+    }
+
+    function reset() public {
+        uint256[] storage all = values;
+        all.push(1);
+        delete values;
+    }
+
+    function vote(uint256 id) public {
+        proposals[id].voted[msg.sender] = true;
+        Proposal storage proposal = proposals[id];
+        proposal.votes += 1;
+    }
+}
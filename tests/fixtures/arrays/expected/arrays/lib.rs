@@ -0,0 +1,173 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod arrays {
+    use ink_prelude::string::String;
+    use ink_prelude::vec::Vec;
+    use ink_storage::traits::PackedLayout;
+    use ink_storage::traits::SpreadAllocate;
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    #[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
+    pub struct Proposal {
+        pub votes: u128,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub values: Mapping<u32, u128>,
+        /// The length of the `values`
+        pub values_length: u32,
+        pub fixed_values: [u128; 5],
+        pub data: Vec<u8>,
+        pub owned: Mapping<AccountId, Vec<u128>>,
+        pub hashes: Mapping<u32, [u8; 32]>,
+        /// The length of the `hashes`
+        pub hashes_length: u32,
+        pub name: String,
+        pub proposals: Mapping<u32, Proposal>,
+        /// The `voted` of the `proposals`
+        pub proposals_voted: Mapping<(u32, AccountId), bool>,
+        /// The length of the `proposals`
+        pub proposals_length: u32,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Arrays {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Arrays {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn add(&mut self, value: u128) -> Result<(), Error> {
+            {
+                self.data.values.insert(&self.data.values_length, &(value));
+                self.data.values_length += 1;
+            };
+            self.data.data.push(1);
+            {
+                let mut array = self
+                    .data
+                    .owned
+                    .get(&self.env().caller())
+                    .unwrap_or_default();
+                array.push(value);
+                self.data.owned.insert(&self.env().caller(), &(array));
+            };
+            {
+                self.data
+                    .hashes
+                    .insert(&self.data.hashes_length, &([0; 32]));
+                self.data.hashes_length += 1;
+            };
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn clear(&mut self, i: u128) -> Result<u128, Error> {
+            self.data.fixed_values[(i as usize)] = Default::default();
+            let mut array = self
+                .data
+                .owned
+                .get(&self.env().caller())
+                .unwrap_or_default();
+            array[(i as usize)] = 1;
+            self.data.owned.insert(&self.env().caller(), &(array));
+            {
+                self.data.values_length = self
+                    .data
+                    .values_length
+                    .checked_sub(1)
+                    .ok_or(Error::Custom(String::from("Pop from an empty array")))?;
+                self.data.values.remove(&self.data.values_length);
+            };
+            return Ok((self.data.values_length as u128));
+        }
+
+        #[ink(message)]
+        pub fn first(&mut self) -> Result<[u8; 1], Error> {
+            let head: [u8; 1] = [self.data.data[0]];
+            let tail: [u8; 1];
+            tail = [self.data.data[((self.data.data.len() as u128)
+                .checked_sub(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                as usize)]];
+            self.data.data[1] = head[0];
+            return Ok(head);
+        }
+
+        #[ink(message)]
+        pub fn initial(&self) -> Result<[u8; 1], Error> {
+            let letter: [u8; 1] = [Vec::<u8>::from(self.data.name.clone())[0]];
+            return Ok(letter);
+        }
+
+        #[ink(message)]
+        pub fn reset(&mut self) -> Result<(), Error> {
+            {
+                self.data.values.insert(&self.data.values_length, &(1));
+                self.data.values_length += 1;
+            };
+            {
+                for index in 0..self.data.values_length {
+                    self.data.values.remove(&index);
+                }
+                self.data.values_length = 0;
+            };
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn vote(&mut self, id: u128) -> Result<(), Error> {
+            self.data
+                .proposals_voted
+                .insert(&((id as u32), self.env().caller()), &(true));
+            let proposal_key: u128 = id;
+            let mut entry = self
+                .data
+                .proposals
+                .get(&(proposal_key as u32))
+                .unwrap_or_default();
+            entry.votes = self
+                .data
+                .proposals
+                .get(&(proposal_key as u32))
+                .unwrap_or_default()
+                .votes
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.proposals.insert(&(proposal_key as u32), &(entry));
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,23 @@
+contract Clock {
+    uint256 start;
+
+    constructor(bytes memory data) {
+        start = abi.decode(data, (uint256));
+    }
+
+    function check(uint256 amount) public returns (bytes32) {
+        require(block.timestamp >= start, "too early");
+        require(tx.origin == msg.sender, "only accounts");
+        uint256 balance = address(this).balance;
+        uint256 gas = gasleft();
+        bytes memory data = abi.encode(amount, address(this));
+        uint256 decoded = abi.decode(data, (uint256));
+        return keccak256("CLOCK");
+    }
+
+    function signer(bytes32 hash, uint8 v, bytes32 r, bytes32 s) public view returns (address) {
+        bytes32 previous = blockhash(block.number - 1);
+        address origin = tx.origin;
+        return ecrecover(hash, v, r, s);
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod clock {
+    use ink_prelude::string::String;
+    use ink_prelude::vec::Vec;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub start: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Clock {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Clock {
+        #[ink(constructor)]
+        pub fn new(data: Vec<u8>) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.start = <(u128)>::decode(&mut &data[..]).expect("Decoding failed");
+            })
+        }
+
+        #[ink(message)]
+        pub fn check(&mut self, amount: u128) -> Result<[u8; 32], Error> {
+            if (self.env().block_timestamp() as u128 / 1000) < self.data.start {
+                return Err(Error::Custom(String::from("too early")));
+            }
+            if todo!("Sol2Ink Not Implemented yet: tx.origin") != self.env().caller() {
+                return Err(Error::Custom(String::from("only accounts")));
+            }
+            let balance: u128 = self.env().balance();
+            let gas: u128 = (self.env().gas_left() as u128);
+            let data: Vec<u8> = (amount, self.env().account_id()).encode();
+            let decoded: u128 = <(u128)>::decode(&mut &data[..])
+                .map_err(|_| Error::Custom(String::from("Decoding failed")))?;
+            return Ok({
+                let mut output = [0u8; 32];
+                ink_env::hash_bytes::<ink_env::hash::Keccak256>(&"CLOCK".as_bytes(), &mut output);
+                output
+            });
+        }
+
+        #[ink(message)]
+        pub fn signer(
+            &self,
+            hash: [u8; 32],
+            v: u8,
+            r: [u8; 32],
+            s: [u8; 32],
+        ) -> Result<AccountId, Error> {
+            let previous: [u8; 32] =
+                todo!("Sol2Ink Not Implemented yet: blockhash(block.number - 1)");
+            let origin: AccountId = todo!("Sol2Ink Not Implemented yet: tx.origin");
+            return Ok({
+                let mut signature = [0u8; 65];
+                signature[..32].copy_from_slice(&r);
+                signature[32..64].copy_from_slice(&s);
+                signature[64] = v;
+                let mut key = [0u8; 33];
+                match ink_env::ecdsa_recover(&signature, &hash, &mut key) {
+                    Ok(()) => {
+                        let mut account = [0u8; 32];
+                        ink_env::hash_bytes::<ink_env::hash::Blake2x256>(&key, &mut account);
+                        AccountId::from(account)
+                    }
+                    Err(_) => AccountId::from([0u8; 32]),
+                }
+            });
+        }
+
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///sol2ink: --target ink4
+#[ink::contract]
+pub mod clock {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub start: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Clock {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Clock {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            instance
+        }
+
+        #[ink(message)]
+        pub fn check(&mut self, amount: u128) -> Result<[u8; 32], Error> {
+            if (Self::env().block_timestamp() as u128 / 1000) < self.data.start {
+                return Err(Error::Custom(String::from("too early")));
+            }
+            if todo!("Sol2Ink Not Implemented yet: tx.origin") != Self::env().caller() {
+                return Err(Error::Custom(String::from("only accounts")));
+            }
+            let balance: u128 = Self::env().balance();
+            let gas: u128 = (Self::env().gas_left() as u128);
+            let data: Vec<u8> = (amount, Self::env().account_id()).encode();
+            let decoded: u128 = <(u128)>::decode(&mut &data[..])
+                .map_err(|_| Error::Custom(String::from("Decoding failed")))?;
+            return Ok({
+                let mut output = [0u8; 32];
+                ink::env::hash_bytes::<ink::env::hash::Keccak256>(&"CLOCK".as_bytes(), &mut output);
+                output
+            });
+        }
+
+        #[ink(message)]
+        pub fn signer(
+            &self,
+            hash: [u8; 32],
+            v: u8,
+            r: [u8; 32],
+            s: [u8; 32],
+        ) -> Result<AccountId, Error> {
+            let previous: [u8; 32] =
+                todo!("Sol2Ink Not Implemented yet: blockhash(block.number - 1)");
+            let origin: AccountId = todo!("Sol2Ink Not Implemented yet: tx.origin");
+            return Ok({
+                let mut signature = [0u8; 65];
+                signature[..32].copy_from_slice(&r);
+                signature[32..64].copy_from_slice(&s);
+                signature[64] = v;
+                let mut key = [0u8; 33];
+                match ink::env::ecdsa_recover(&signature, &hash, &mut key) {
+                    Ok(()) => {
+                        let mut account = [0u8; 32];
+                        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&key, &mut account);
+                        AccountId::from(account)
+                    }
+                    Err(_) => AccountId::from([0u8; 32]),
+                }
+            });
+        }
+
+    }
+}
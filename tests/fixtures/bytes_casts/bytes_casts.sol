@@ -0,0 +1,18 @@
+contract Casts {
+    bytes32 key;
+    bytes4 selector;
+    uint256 counter;
+
+    function convert(uint32 id, uint256 value, bytes32 hash, uint24 small) public returns (uint256) {
+        selector = bytes4(id);
+        uint32 back = uint32(selector);
+        key = bytes32(value);
+        uint256 fromHash = uint256(hash);
+        bytes3 tiny = bytes3(small);
+        uint24 restored = uint24(tiny);
+        key = bytes32(0);
+        selector = bytes4(0x12345678);
+        counter = uint256(keccak256(abi.encodePacked(id)));
+        return uint256(key);
+    }
+}
@@ -0,0 +1,88 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod casts {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub key: [u8; 32],
+        pub selector: [u8; 4],
+        pub counter: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Casts {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Casts {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn convert(
+            &mut self,
+            id: u32,
+            value: u128,
+            hash: [u8; 32],
+            small: u32,
+        ) -> Result<u128, Error> {
+            self.data.selector = id.to_be_bytes();
+            let back: u32 = u32::from_be_bytes(self.data.selector);
+            self.data.key = {
+                let mut bytes = [0; 32];
+                bytes[16..].copy_from_slice(&value.to_be_bytes());
+                bytes
+            };
+            let from_hash: u128 = u128::from_be_bytes(<[u8; 16]>::try_from(&hash[16..]).unwrap());
+            let tiny: [u8; 3] = <[u8; 3]>::try_from(&small.to_be_bytes()[1..]).unwrap();
+            let restored: u32 = u32::from_be_bytes({
+                let mut bytes = [0; 4];
+                bytes[1..].copy_from_slice(&tiny);
+                bytes
+            });
+            self.data.key = [0; 32];
+            self.data.selector = [0x12, 0x34, 0x56, 0x78];
+            self.data.counter = u128::from_be_bytes(
+                <[u8; 16]>::try_from(
+                    &{
+                        let mut output = [0u8; 32];
+                        ink_env::hash_bytes::<ink_env::hash::Keccak256>(
+                            &(id).encode(),
+                            &mut output,
+                        );
+                        output
+                    }[16..],
+                )
+                .unwrap(),
+            );
+            return Ok(u128::from_be_bytes(
+                <[u8; 16]>::try_from(&self.data.key[16..]).unwrap(),
+            ));
+        }
+
+    }
+}
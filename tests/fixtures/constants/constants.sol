@@ -0,0 +1,28 @@
+contract Roles {
+    bytes32 public constant MINTER_ROLE = keccak256("MINTER_ROLE");
+    bytes32 public constant DEFAULT_ADMIN_ROLE = 0x00;
+    uint256 constant UNIT = 10**18;
+    uint256 constant CAP = 1_000_000 * UNIT;
+    uint64 constant MAX = type(uint64).max;
+    int8 constant MIN = type(int8).min;
+    bytes32 constant PACKED = keccak256(abi.encodePacked("ROLE", uint16(1), true));
+    bytes32 constant DIGEST = sha256("abc");
+    uint256 constant LARGE = 2**200;
+    bytes32 constant ENCODED = keccak256(abi.encode("ROLE"));
+    string public constant NAME = "Token";
+    address constant ZERO = address(0);
+    address public immutable owner;
+    uint256 counter = 5;
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function describe(address account) public view returns (string memory) {
+        if (account == ZERO) {
+            return NAME;
+        }
+        string memory name = NAME;
+        return name;
+    }
+}
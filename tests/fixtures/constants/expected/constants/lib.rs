@@ -0,0 +1,105 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod roles {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+    pub const MINTER_ROLE: [u8; 32] = [
+        0x9f, 0x2d, 0xf0, 0xfe, 0xd2, 0xc7, 0x76, 0x48, 0xde, 0x58, 0x60, 0xa4, 0xcc, 0x50, 0x8c,
+        0xd0, 0x81, 0x8c, 0x85, 0xb8, 0xb8, 0xa1, 0xab, 0x4c, 0xee, 0xef, 0x8d, 0x98, 0x1c, 0x89,
+        0x56, 0xa6,
+    ];
+    pub const DEFAULT_ADMIN_ROLE: [u8; 32] = [0; 32];
+    pub const UNIT: u128 = 1000000000000000000;
+    pub const CAP: u128 = 1000000000000000000000000;
+    pub const MAX: u64 = 18446744073709551615;
+    pub const MIN: i8 = -128;
+    pub const PACKED: [u8; 32] = [
+        0x56, 0x2d, 0x23, 0x20, 0x0f, 0xce, 0xf0, 0x1c, 0x9b, 0x8a, 0x40, 0x9a, 0x6d, 0x6e, 0x14,
+        0xb5, 0x48, 0x89, 0x13, 0x95, 0xe8, 0x13, 0x51, 0xef, 0x14, 0xc2, 0x53, 0xf1, 0x8a, 0x81,
+        0x7f, 0xac,
+    ];
+    pub const DIGEST: [u8; 32] = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+        0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+        0x15, 0xad,
+    ];
+    pub const LARGE: u128 = 2.pow(200);
+    pub const ENCODED: [u8; 32] = {
+        let mut output = [0u8; 32];
+        ink_env::hash_bytes::<ink_env::hash::Keccak256>(&("ROLE").encode(), &mut output);
+        output
+    };
+    pub const NAME: &'static str = "Token";
+    pub const ZERO: [u8; 32] = [0; 32];
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub counter: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Roles {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Roles {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.counter = 5;
+                instance.data.owner = instance.env().caller();
+            })
+        }
+
+        #[ink(message)]
+        pub fn minter_role(&self) -> Result<[u8; 32], Error> {
+            return Ok(MINTER_ROLE);
+        }
+
+        #[ink(message)]
+        pub fn default_admin_role(&self) -> Result<[u8; 32], Error> {
+            return Ok(DEFAULT_ADMIN_ROLE);
+        }
+
+        #[ink(message)]
+        pub fn name(&self) -> Result<String, Error> {
+            return Ok(String::from(NAME));
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> Result<AccountId, Error> {
+            return Ok(self.data.owner);
+        }
+
+        #[ink(message)]
+        pub fn describe(&self, account: AccountId) -> Result<String, Error> {
+            if account == AccountId::from(ZERO) {
+                return Ok(String::from(NAME));
+            }
+            let name: String = String::from(NAME);
+            return Ok(name);
+        }
+
+    }
+}
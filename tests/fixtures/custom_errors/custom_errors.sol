@@ -0,0 +1,17 @@
+error Unauthorized();
+
+contract Bank {
+    /// Not enough funds
+    error InsufficientBalance(uint256 available, uint256 required);
+
+    mapping(address => uint256) balances;
+
+    function withdraw(uint256 amount) public {
+        if (msg.sender == address(0)) {
+            revert Unauthorized();
+        }
+        uint256 balance = balances[msg.sender];
+        require(balance >= amount, InsufficientBalance(balance, amount));
+        revert("not implemented");
+    }
+}
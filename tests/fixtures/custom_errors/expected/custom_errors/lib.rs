@@ -0,0 +1,73 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod bank {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::AccountIdExt;
+    use openbrush::traits::Storage;
+    use openbrush::traits::ZERO_ADDRESS;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+        ///Not enough funds
+        InsufficientBalance {
+            available: u128,
+            required: u128,
+        },
+        Unauthorized,
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub balances: Mapping<AccountId, u128>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Bank {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Bank {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn withdraw(&mut self, amount: u128) -> Result<(), Error> {
+            if self.env().caller().is_zero() {
+                return Err(Error::Unauthorized);
+            }
+            let balance: u128 = self
+                .data
+                .balances
+                .get(&self.env().caller())
+                .unwrap_or_default();
+            if balance < amount {
+                return Err(Error::InsufficientBalance {
+                    available: balance,
+                    required: amount,
+                });
+            }
+            return Err(Error::Custom(String::from("not implemented")));
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,13 @@
+contract Shop {
+    enum Status { Placed, Shipped }
+
+    Status status;
+
+    function set(uint8 value) public returns (uint8) {
+        status = Status(value);
+        if (status == Status.Shipped) {
+            return 1;
+        }
+        return uint8(status);
+    }
+}
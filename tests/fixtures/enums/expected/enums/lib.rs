@@ -0,0 +1,82 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod shop {
+    use ink_prelude::string::String;
+    use ink_storage::traits::PackedLayout;
+    use ink_storage::traits::SpreadAllocate;
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    #[derive(
+        Default, Debug, Clone, Copy, PartialEq, Encode, Decode, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
+    pub enum Status {
+        #[default]
+        Placed = 0,
+        Shipped = 1,
+    }
+
+    impl Status {
+        /// Converts the discriminant to the value of the enum
+        ///
+        /// returns None if the discriminant is out of the range of the enum
+        pub fn from_index<T: TryInto<u8>>(index: T) -> Option<Self> {
+            match index.try_into().ok()? {
+                0 => Some(Self::Placed),
+                1 => Some(Self::Shipped),
+                _ => None,
+            }
+        }
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub status: Status,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Shop {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Shop {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, value: u8) -> Result<u8, Error> {
+            self.data.status = Status::from_index(value)
+                .ok_or(Error::Custom(String::from("Enum value out of range")))?;
+            if self.data.status == Status::Shipped {
+                return Ok(1);
+            }
+            return Ok((self.data.status as u8));
+        }
+
+    }
+}
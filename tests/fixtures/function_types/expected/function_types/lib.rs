@@ -0,0 +1,57 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod hook {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub total: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Hook {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Hook {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        fn _double(&self, value: u128) -> Result<u128, Error> {
+            return Ok(value
+                .checked_mul(2)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?);
+        }
+
+        #[ink(message)]
+        pub fn apply(&mut self, value: u128) -> Result<u128, Error> {
+            // Sol2Ink Not Implemented yet: function(uint256) returns (uint256) callback = double;
+            self.data.total = self._double(value)?;
+            return Ok(self.data.total);
+        }
+
+    }
+}
@@ -0,0 +1,14 @@
+contract Hook {
+    function(uint256) internal returns (uint256) hook;
+    uint256 total;
+
+    function double(uint256 value) internal pure returns (uint256) {
+        return value * 2;
+    }
+
+    function apply(uint256 value) public returns (uint256) {
+        function(uint256) internal pure returns (uint256) callback = double;
+        total = double(value);
+        return total;
+    }
+}
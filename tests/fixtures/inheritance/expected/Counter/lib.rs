@@ -0,0 +1,101 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod counter {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::modifier_definition;
+    use openbrush::modifiers;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Counter`, accessed by its modifiers
+    pub trait CounterStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Counter;
+    }
+
+    impl CounterStorage for Counter {
+        fn contract(&mut self) -> &mut Counter {
+            self
+        }
+    }
+
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: CounterStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().env().caller() != instance.contract().data.owner {
+            return Err(Error::Custom(String::from(
+                "Owned: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct OwnerChanged {
+        owner: AccountId,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub count: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Counter {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new(start: u128) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                let owner: AccountId = instance.env().caller();
+                instance.data.owner = owner;
+                instance.data.count = start;
+            })
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn change_owner(&mut self, owner: AccountId) -> Result<(), Error> {
+            self.data.owner = owner;
+            self.env().emit_event(OwnerChanged { owner });
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn increment(&mut self) -> Result<u128, Error> {
+            self.data.count = self
+                .data
+                .count
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            return Ok(self.data.count);
+        }
+
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod owned {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::modifier_definition;
+    use openbrush::modifiers;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Owned`, accessed by its modifiers
+    pub trait OwnedStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Owned;
+    }
+
+    impl OwnedStorage for Owned {
+        fn contract(&mut self) -> &mut Owned {
+            self
+        }
+    }
+
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: OwnedStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().env().caller() != instance.contract().data.owner {
+            return Err(Error::Custom(String::from(
+                "Owned: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct OwnerChanged {
+        owner: AccountId,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Owned {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Owned {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.owner = owner;
+            })
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn change_owner(&mut self, owner: AccountId) -> Result<(), Error> {
+            self.data.owner = owner;
+            self.env().emit_event(OwnerChanged { owner });
+            Ok(())
+        }
+
+    }
+}
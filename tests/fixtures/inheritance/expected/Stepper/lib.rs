@@ -0,0 +1,124 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod stepper {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::modifier_definition;
+    use openbrush::modifiers;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Stepper`, accessed by its modifiers
+    pub trait StepperStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Stepper;
+    }
+
+    impl StepperStorage for Stepper {
+        fn contract(&mut self) -> &mut Stepper {
+            self
+        }
+    }
+
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: StepperStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().env().caller() != instance.contract().data.owner {
+            return Err(Error::Custom(String::from(
+                "Owned: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct OwnerChanged {
+        owner: AccountId,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub count: u128,
+        pub step: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Stepper {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Stepper {
+        #[ink(constructor)]
+        pub fn new(step: u128) -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                let owner: AccountId = instance.env().caller();
+                instance.data.owner = owner;
+                let start: u128 = 1;
+                instance.data.count = start;
+                instance.data.step = step;
+            })
+        }
+
+        #[modifiers(only_owner)]
+        fn _owned_change_owner(&mut self, owner: AccountId) -> Result<(), Error> {
+            self.data.owner = owner;
+            self.env().emit_event(OwnerChanged { owner });
+            Ok(())
+        }
+
+        #[modifiers(only_owner)]
+        fn _counter_increment(&mut self) -> Result<u128, Error> {
+            self.data.count = self
+                .data
+                .count
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            return Ok(self.data.count);
+        }
+
+        #[ink(message)]
+        pub fn increment(&mut self) -> Result<u128, Error> {
+            self.data.count = self
+                .data
+                .count
+                .checked_add(
+                    self.data
+                        .step
+                        .checked_sub(1)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+                )
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            return Ok(self._counter_increment()?);
+        }
+
+        #[ink(message)]
+        pub fn change_owner(&mut self, owner: AccountId) -> Result<(), Error> {
+            self._owned_change_owner(owner)?;
+            self.data.count = 0;
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,50 @@
+contract Owned {
+    address owner;
+
+    event OwnerChanged(address owner);
+
+    constructor(address owner_) {
+        owner = owner_;
+    }
+
+    modifier onlyOwner() {
+        require(msg.sender == owner, "Owned: caller is not the owner");
+        _;
+    }
+
+    function changeOwner(address owner_) public virtual onlyOwner {
+        owner = owner_;
+        emit OwnerChanged(owner_);
+    }
+}
+
+contract Counter is Owned {
+    uint256 count;
+
+    constructor(uint256 start) Owned(msg.sender) {
+        count = start;
+    }
+
+    function increment() public virtual onlyOwner returns (uint256) {
+        count += 1;
+        return count;
+    }
+}
+
+contract Stepper is Counter(1) {
+    uint256 step;
+
+    constructor(uint256 step_) {
+        step = step_;
+    }
+
+    function increment() public override returns (uint256) {
+        count += step - 1;
+        return super.increment();
+    }
+
+    function changeOwner(address owner_) public override {
+        super.changeOwner(owner_);
+        count = 0;
+    }
+}
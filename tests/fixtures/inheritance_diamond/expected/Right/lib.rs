@@ -0,0 +1,65 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod right {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    #[ink(event)]
+    pub struct Set {
+        value: u128,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub value: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Right {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Right {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        fn _base_set(&mut self, value: u128) -> Result<(), Error> {
+            self.data.value = value;
+            self.env().emit_event(Set { value });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, value: u128) -> Result<(), Error> {
+            self._base_set(
+                value
+                    .checked_mul(2)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            )?;
+            Ok(())
+        }
+
+    }
+}
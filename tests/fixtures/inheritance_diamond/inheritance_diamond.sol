@@ -0,0 +1,29 @@
+contract Base {
+    uint256 value;
+
+    event Set(uint256 value);
+
+    function set(uint256 value_) public virtual {
+        value = value_;
+        emit Set(value_);
+    }
+}
+
+contract Left is Base {
+    function set(uint256 value_) public virtual override {
+        super.set(value_ + 1);
+    }
+}
+
+contract Right is Base {
+    function set(uint256 value_) public virtual override {
+        super.set(value_ * 2);
+    }
+}
+
+// linearized as `Diamond, Right, Left, Base`, so `super` in `Right` calls `Left`
+contract Diamond is Left, Right {
+    function set(uint256 value_) public override(Left, Right) {
+        super.set(value_);
+    }
+}
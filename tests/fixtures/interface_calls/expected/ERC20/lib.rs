@@ -0,0 +1,31 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+use ink_prelude::string::String;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+#[derive(Debug, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    Custom(String),
+}
+
+#[openbrush::wrapper]
+pub type ERC20Ref = dyn ERC20;
+
+#[openbrush::trait_definition]
+pub trait ERC20 {
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        amount: u128,
+    ) -> Result<bool, Error>;
+
+}
@@ -0,0 +1,56 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod escrow {
+    use erc_20::*;
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+        ERC20Error(erc_20::Error),
+    }
+
+    impl From<erc_20::Error> for Error {
+        fn from(error: erc_20::Error) -> Self {
+            Error::ERC20Error(error)
+        }
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {}
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Escrow {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Escrow {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn deposit(&mut self, token: AccountId, amount: u128) -> Result<(), Error> {
+            ERC20Ref::transfer_from(&token, self.env().caller(), self.env().account_id(), amount)?;
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,11 @@
+interface IERC20 {
+    function transferFrom(address from, address to, uint256 amount)
+        external
+        returns (bool);
+}
+
+contract Escrow {
+    function deposit(address token, uint256 amount) public {
+        IERC20(token).transferFrom(msg.sender, address(this), amount);
+    }
+}
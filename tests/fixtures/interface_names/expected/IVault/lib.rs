@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+use ink_prelude::string::String;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+#[derive(Debug, Encode, Decode, PartialEq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Error {
+    Custom(String),
+}
+
+#[openbrush::wrapper]
+pub type IVaultRef = dyn IVault;
+
+#[openbrush::trait_definition]
+pub trait IVault {
+    #[ink(message)]
+    fn deposit(&mut self, amount: u128) -> Result<(), Error>;
+
+}
@@ -0,0 +1,64 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod router {
+    use i_vault::*;
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use oracle::*;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+        IVaultError(i_vault::Error),
+        OracleError(oracle::Error),
+    }
+
+    impl From<i_vault::Error> for Error {
+        fn from(error: i_vault::Error) -> Self {
+            Error::IVaultError(error)
+        }
+    }
+
+    impl From<oracle::Error> for Error {
+        fn from(error: oracle::Error) -> Self {
+            Error::OracleError(error)
+        }
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {}
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Router {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Router {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn route(&mut self, oracle: AccountId, vault: AccountId) -> Result<(), Error> {
+            IVaultRef::deposit(&vault, OracleRef::price(&oracle)?)?;
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,21 @@
+interface Oracle {
+    function price() external view returns (uint256);
+}
+
+interface IVault {
+    function deposit(uint256 amount) external;
+}
+
+contract Vault {
+    uint256 total;
+
+    function deposit(uint256 amount) public {
+        total += amount;
+    }
+}
+
+contract Router {
+    function route(address oracle, address vault) public {
+        IVault(vault).deposit(Oracle(oracle).price());
+    }
+}
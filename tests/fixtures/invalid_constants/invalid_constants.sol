@@ -0,0 +1,6 @@
+contract Invalid {
+    uint256 constant NEGATIVE = 1 - 2;
+    uint256 constant RATIO = 10 / 0;
+    uint8 constant SMALL = 300;
+    uint256 constant HUGE = 2**256;
+}
@@ -0,0 +1,96 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod keywords {
+    use ink_prelude::string::String;
+    use ink_storage::traits::PackedLayout;
+    use ink_storage::traits::SpreadAllocate;
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    #[ink(event)]
+    pub struct Moved {
+        r#move: AccountId,
+        r#ref: u128,
+    }
+
+    #[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
+    pub struct Pair {
+        pub r#match: u128,
+        pub r#impl: u128,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub r#type: u128,
+        pub r#loop: u128,
+        pub pairs: Mapping<u128, Pair>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Keywords {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Keywords {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn r#type(&self) -> Result<u128, Error> {
+            return Ok(self.data.r#type);
+        }
+
+        #[ink(message)]
+        pub fn r#fn(&mut self, r#match: u128, r#impl: u128) -> Result<u128, Error> {
+            let mut r#move = Default::default();
+            let r#mut: u128 = r#match
+                .checked_add(r#impl)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            let pair: Pair = Pair { r#match, r#impl };
+            self.data.pairs.insert(&r#mut, &(pair));
+            self.data.r#loop = pair.r#match;
+            r#move = r#mut;
+            self.env().emit_event(Moved {
+                r#move: self.env().caller(),
+                r#ref: r#move,
+            });
+            Ok(r#move)
+        }
+
+        fn _trait(&self, r#where: u128) -> Result<u128, Error> {
+            return Ok(r#where);
+        }
+
+        #[ink(message)]
+        pub fn r#dyn(&self) -> Result<u128, Error> {
+            return Ok(self._trait(self.data.r#loop)?);
+        }
+
+    }
+}
@@ -0,0 +1,31 @@
+pragma solidity ^0.8.0;
+
+contract Keywords {
+    struct Pair {
+        uint256 match;
+        uint256 impl;
+    }
+
+    event Moved(address move, uint256 ref);
+
+    uint256 public type_;
+    uint256 loop;
+    mapping(uint256 => Pair) pairs;
+
+    function fn(uint256 match, uint256 impl) public returns (uint256 move) {
+        uint256 mut = match + impl;
+        Pair memory pair = Pair(match, impl);
+        pairs[mut] = pair;
+        loop = pair.match;
+        move = mut;
+        emit Moved(msg.sender, move);
+    }
+
+    function trait(uint256 where) internal pure returns (uint256) {
+        return where;
+    }
+
+    function dyn() public view returns (uint256) {
+        return trait(loop);
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+use ink_prelude::string::String;
+use ink_prelude::vec::Vec;
+use ink_storage::traits::PackedLayout;
+use ink_storage::traits::SpreadAllocate;
+use ink_storage::traits::SpreadLayout;
+use openbrush::traits::AccountId;
+use scale::Decode;
+use scale::Encode;
+
+///sol2ink: --package-prefix my --ink-version ~3.4.0
+#[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+)]
+pub struct Balance {
+    pub value: u128,
+}
+
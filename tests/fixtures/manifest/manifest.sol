@@ -0,0 +1,8 @@
+// sol2ink: --package-prefix my --ink-version ~3.4.0
+struct Balance {
+    uint256 value;
+}
+
+contract ERC20 {
+    Balance balance;
+}
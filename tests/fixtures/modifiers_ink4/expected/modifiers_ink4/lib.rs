@@ -0,0 +1,116 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///sol2ink: --target ink4
+#[ink::contract]
+pub mod guarded {
+    use ink::prelude::string::String;
+    use openbrush::modifier_definition;
+    use openbrush::modifiers;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Guarded`, accessed by its modifiers
+    pub trait GuardedStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Guarded;
+    }
+
+    impl GuardedStorage for Guarded {
+        fn contract(&mut self) -> &mut Guarded {
+            self
+        }
+    }
+
+    #[modifier_definition]
+    pub fn non_reentrant<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: GuardedStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().data.locked {
+            return Err(Error::Custom(String::from("locked")));
+        }
+        instance.contract().data.locked = true;
+        let result = body(instance)?;
+        instance.contract().data.locked = false;
+        Ok(result)
+    }
+
+    #[modifier_definition]
+    pub fn when_positive<T, F, R>(instance: &mut T, body: F, value: u128) -> Result<R, Error>
+    where
+        T: GuardedStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+        R: Default,
+    {
+        let mut result = None;
+        if value == 0 {
+            return Ok(result.unwrap_or_default());
+        }
+        result = Some(body(instance)?);
+        Ok(result.unwrap_or_default())
+    }
+
+    #[modifier_definition]
+    pub fn twice<T, F, R>(instance: &mut T, mut body: F) -> Result<R, Error>
+    where
+        T: GuardedStorage,
+        F: FnMut(&mut T) -> Result<R, Error>,
+        R: Default,
+    {
+        let mut result = None;
+        result = Some(body(instance)?);
+        result = Some(body(instance)?);
+        Ok(result.unwrap_or_default())
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub locked: bool,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Guarded {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Guarded {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            instance
+        }
+
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        #[modifiers(when_positive(value))]
+        #[modifiers(twice)]
+        pub fn run(&mut self, value: u128) -> Result<(), Error> {
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[modifiers(non_reentrant)]
+        pub fn is_locked(&mut self) -> Result<bool, Error> {
+            return Ok(self.data.locked);
+        }
+
+    }
+}
@@ -0,0 +1,29 @@
+// sol2ink: --target ink4
+contract Guarded {
+    bool locked;
+
+    modifier nonReentrant() {
+        require(!locked, "locked");
+        locked = true;
+        _;
+        locked = false;
+    }
+
+    modifier whenPositive(uint256 value) {
+        if (value == 0) {
+            return;
+        }
+        _;
+    }
+
+    modifier twice() {
+        _;
+        _;
+    }
+
+    function run(uint256 value) public nonReentrant whenPositive(value) twice {}
+
+    function isLocked() public view nonReentrant returns (bool) {
+        return locked;
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///sol2ink: --mangling arity --selectors overloads
+#[openbrush::contract]
+pub mod token {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {}
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Token {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Token {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message, selector = 0x6a627842)]
+        pub fn mint_1(&mut self, to: AccountId) -> Result<(), Error> {
+            self.mint_address_uint_256(to, 1)?;
+            Ok(())
+        }
+
+        #[ink(message, selector = 0x40c10f19)]
+        pub fn mint_address_uint_256(&mut self, to: AccountId, amount: u128) -> Result<(), Error> {
+            Ok(())
+        }
+
+        #[ink(message, selector = 0xd0def521)]
+        pub fn mint_address_string(&mut self, to: AccountId, uri: String) -> Result<(), Error> {
+            self.mint_address_string(to, uri)?;
+            Ok(())
+        }
+
+    }
+}
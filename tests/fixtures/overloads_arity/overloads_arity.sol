@@ -0,0 +1,12 @@
+// sol2ink: --mangling arity --selectors overloads
+contract Token {
+    function mint(address to) public {
+        mint(to, 1);
+    }
+
+    function mint(address to, uint256 amount) public {}
+
+    function mint(address to, string memory uri) public {
+        mint(to, uri);
+    }
+}
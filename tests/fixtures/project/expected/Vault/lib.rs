@@ -0,0 +1,124 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod vault {
+    use common::*;
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    /// The storage of `Vault`, accessed by its modifiers
+    pub trait VaultStorage {
+        /// returns the contract whose function is modified
+        fn contract(&mut self) -> &mut Vault;
+    }
+
+    impl VaultStorage for Vault {
+        fn contract(&mut self) -> &mut Vault {
+            self
+        }
+    }
+
+    #[modifier_definition]
+    pub fn only_owner<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: VaultStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().env().caller() != instance.contract().data.owner {
+            return Err(Error::Custom(String::from(
+                "Owned: caller is not the owner",
+            )));
+        }
+        body(instance)
+    }
+
+    #[modifier_definition]
+    pub fn when_not_paused<T, F, R>(instance: &mut T, body: F) -> Result<R, Error>
+    where
+        T: VaultStorage,
+        F: FnOnce(&mut T) -> Result<R, Error>,
+    {
+        if instance.contract().data.paused {
+            return Err(Error::Custom(String::from("Pausable: paused")));
+        }
+        body(instance)
+    }
+
+    #[ink(event)]
+    pub struct Deposited {
+        owner: AccountId,
+        amount: u128,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+        pub paused: bool,
+        pub deposits: Mapping<u128, Deposit>,
+        pub count: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Vault {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Vault {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.owner = instance.env().caller();
+            })
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn pause(&mut self) -> Result<(), Error> {
+            self.data.paused = true;
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn deposit(&mut self, amount: u128) -> Result<u128, Error> {
+            self.data.count = self
+                .data
+                .count
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.deposits.insert(
+                &self.data.count,
+                &(Deposit {
+                    owner: self.env().caller(),
+                    amount,
+                }),
+            );
+            self.env().emit_event(Deposited {
+                owner: self.env().caller(),
+                amount,
+            });
+            return Ok(self.data.count);
+        }
+
+    }
+}
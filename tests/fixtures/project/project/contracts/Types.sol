@@ -0,0 +1,8 @@
+pragma solidity ^0.8.0;
+
+struct Deposit {
+    address owner;
+    uint256 amount;
+}
+
+event Deposited(address owner, uint256 amount);
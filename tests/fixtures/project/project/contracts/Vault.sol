@@ -0,0 +1,16 @@
+pragma solidity ^0.8.0;
+
+import "@acme/security/Pausable.sol";
+import {Deposit, Deposited} from "./Types.sol";
+
+contract Vault is Pausable {
+    mapping(uint256 => Deposit) deposits;
+    uint256 count;
+
+    function deposit(uint256 amount) public whenNotPaused returns (uint256) {
+        count += 1;
+        deposits[count] = Deposit({owner: msg.sender, amount: amount});
+        emit Deposited(msg.sender, amount);
+        return count;
+    }
+}
@@ -0,0 +1,9 @@
+contract Token {
+    string public name;
+    mapping(address => uint256) public balanceOf;
+    mapping(address => mapping(address => uint256)) public allowance;
+    uint256 internal supply;
+    address private owner;
+
+    function _burn() internal {}
+}
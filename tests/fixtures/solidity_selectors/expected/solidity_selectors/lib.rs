@@ -0,0 +1,65 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///sol2ink: --selectors solidity
+#[openbrush::contract]
+pub mod token {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub balance_of: Mapping<AccountId, u128>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Token {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Token {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message, selector = 0x70a08231)]
+        pub fn balance_of(&self, arg_0: AccountId) -> Result<u128, Error> {
+            return Ok(self.data.balance_of.get(&arg_0).unwrap_or_default());
+        }
+
+        #[ink(message, selector = 0xa9059cbb)]
+        pub fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error> {
+            return Ok(true);
+        }
+
+        fn _move(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        #[ink(message, payable)]
+        pub fn receive(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,12 @@
+// sol2ink: --selectors solidity
+contract Token {
+    mapping(address => uint256) public balanceOf;
+
+    function transfer(address to, uint256 amount) public returns (bool) {
+        return true;
+    }
+
+    function _move() internal {}
+
+    receive() external payable {}
+}
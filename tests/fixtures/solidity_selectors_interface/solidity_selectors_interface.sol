@@ -0,0 +1,4 @@
+// sol2ink: --selectors solidity
+interface IERC20 {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
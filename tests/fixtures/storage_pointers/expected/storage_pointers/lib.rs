@@ -0,0 +1,165 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod ballot {
+    use ink_prelude::string::String;
+    use ink_prelude::vec::Vec;
+    use ink_storage::traits::PackedLayout;
+    use ink_storage::traits::SpreadAllocate;
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    #[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
+    pub struct Proposal {
+        pub votes: u128,
+    }
+
+    #[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
+    pub struct Voter {
+        pub weight: u128,
+        pub delegated: bool,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub proposals: Mapping<u128, Proposal>,
+        /// The `voted` of the `proposals`
+        pub proposals_voted: Mapping<(u128, AccountId), bool>,
+        pub voters: Mapping<u32, Voter>,
+        /// The length of the `voters`
+        pub voters_length: u32,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Ballot {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Ballot {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn vote(&mut self, id: u128) -> Result<(), Error> {
+            let p_key: u128 = id;
+            if self
+                .data
+                .proposals_voted
+                .get(&(p_key, self.env().caller()))
+                .unwrap_or_default()
+            {
+                return Err(Error::Custom(String::from("voted")));
+            }
+            self.data
+                .proposals_voted
+                .insert(&(p_key, self.env().caller()), &(true));
+            let mut entry = self.data.proposals.get(&p_key).unwrap_or_default();
+            entry.votes = self
+                .data
+                .proposals
+                .get(&p_key)
+                .unwrap_or_default()
+                .votes
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.proposals.insert(&p_key, &(entry));
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn delegate(&mut self, index: u128) -> Result<u128, Error> {
+            let voter_key: u128 = index;
+            let mut entry = self
+                .data
+                .voters
+                .get(&(voter_key as u32))
+                .unwrap_or_default();
+            entry.delegated = true;
+            self.data.voters.insert(&(voter_key as u32), &(entry));
+            let mut entry = self
+                .data
+                .voters
+                .get(&(voter_key as u32))
+                .unwrap_or_default();
+            entry.weight = 0;
+            self.data.voters.insert(&(voter_key as u32), &(entry));
+            return Ok(self
+                .data
+                .voters
+                .get(&(voter_key as u32))
+                .unwrap_or_default()
+                .weight);
+        }
+
+        #[ink(message)]
+        pub fn copy(&mut self, index: u128) -> Result<(), Error> {
+            let voter: Voter = self
+                .data
+                .voters
+                .get(
+                    &(index
+                        .checked_add(1)
+                        .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                        as u32),
+                )
+                .unwrap_or_default();
+            voter.weight = 1;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn skip(&mut self, index: u128) -> Result<(), Error> {
+            let next: u128 = index;
+            let voter_key: u128 = next;
+            next = next
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            let mut entry = self
+                .data
+                .voters
+                .get(&(voter_key as u32))
+                .unwrap_or_default();
+            entry.weight = next;
+            self.data.voters.insert(&(voter_key as u32), &(entry));
+            let voter_key: u128 = next;
+            let mut entry = self
+                .data
+                .voters
+                .get(&(voter_key as u32))
+                .unwrap_or_default();
+            entry.delegated = true;
+            self.data.voters.insert(&(voter_key as u32), &(entry));
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,41 @@
+contract Ballot {
+    struct Proposal {
+        uint256 votes;
+        mapping(address => bool) voted;
+    }
+    struct Voter {
+        uint256 weight;
+        bool delegated;
+    }
+
+    mapping(uint256 => Proposal) proposals;
+    Voter[] voters;
+
+    function vote(uint256 id) public {
+        Proposal storage p = proposals[id];
+        require(!p.voted[msg.sender], "voted");
+        p.voted[msg.sender] = true;
+        p.votes += 1;
+    }
+
+    function delegate(uint256 index) public returns (uint256) {
+        Voter storage voter = voters[index];
+        voter.delegated = true;
+        voter.weight = 0;
+        return voter.weight;
+    }
+
+    function copy(uint256 index) public {
+        Voter storage voter = voters[index + 1];
+        voter.weight = 1;
+    }
+
+    function skip(uint256 index) public {
+        uint256 next = index;
+        Voter storage voter = voters[next];
+        next += 1;
+        voter.weight = next;
+        voter = voters[next];
+        voter.delegated = true;
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod ballot {
+    use ink_prelude::string::String;
+    use ink_storage::traits::PackedLayout;
+    use ink_storage::traits::SpreadAllocate;
+    use ink_storage::traits::SpreadLayout;
+    use openbrush::storage::Mapping;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    #[ink(event)]
+    pub struct Moved {
+        to: Point,
+    }
+
+    #[derive(Default, Clone, Encode, Decode, Debug, SpreadLayout, PackedLayout, SpreadAllocate)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+    )]
+    pub struct Proposal {
+        pub votes: u128,
+    }
+
+    #[derive(Default, Clone, Encode, Decode, Debug, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Point {
+        pub x: u128,
+    }
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub proposals: Mapping<u128, Proposal>,
+        /// The `voted` of the `proposals`
+        pub proposals_voted: Mapping<(u128, AccountId), bool>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Ballot {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Ballot {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message)]
+        pub fn vote(&mut self, id: u128) -> Result<(), Error> {
+            if self
+                .data
+                .proposals_voted
+                .get(&(id, self.env().caller()))
+                .unwrap_or_default()
+            {
+                return Err(Error::Custom(String::from("voted")));
+            }
+            self.data
+                .proposals_voted
+                .insert(&(id, self.env().caller()), &(true));
+            let mut entry = self.data.proposals.get(&id).unwrap_or_default();
+            entry.votes = self
+                .data
+                .proposals
+                .get(&id)
+                .unwrap_or_default()
+                .votes
+                .checked_add(1)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.proposals.insert(&id, &(entry));
+            self.env().emit_event(Moved {
+                to: Point { x: id },
+            });
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,20 @@
+contract Ballot {
+    struct Proposal {
+        uint256 votes;
+        mapping(address => bool) voted;
+    }
+    struct Point {
+        uint256 x;
+    }
+
+    event Moved(Point to);
+
+    mapping(uint256 => Proposal) proposals;
+
+    function vote(uint256 id) public {
+        require(!proposals[id].voted[msg.sender], "voted");
+        proposals[id].voted[msg.sender] = true;
+        proposals[id].votes += 1;
+        emit Moved(Point(id));
+    }
+}
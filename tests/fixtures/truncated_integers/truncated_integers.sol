@@ -0,0 +1,12 @@
+contract Narrow {
+    uint256 total;
+    uint128 step;
+    int256 delta;
+
+    function add(uint256 amount, int256 change) public returns (uint256) {
+        uint256 previous = total;
+        total = previous + amount;
+        delta += change;
+        return total;
+    }
+}
@@ -0,0 +1,18 @@
+interface IOracle {
+    function price(address asset) external returns (uint256);
+}
+
+contract Consumer {
+    IOracle oracle;
+    uint256 last;
+
+    function update(address asset) public {
+        try oracle.price(asset) returns (uint256 value) {
+            last = value;
+        } catch Error(string memory reason) {
+            revert(reason);
+        } catch Panic(uint code) {
+            last = code;
+        }
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[ink::contract]
+pub mod consumer {
+    use ink::prelude::string::String;
+    use openbrush::traits::Storage;
+    use oracle::*;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+        OracleError(oracle::Error),
+    }
+
+    impl From<oracle::Error> for Error {
+        fn from(error: oracle::Error) -> Self {
+            Error::OracleError(error)
+        }
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub oracle: AccountId,
+        pub last: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Consumer {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Consumer {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            instance
+        }
+
+        #[ink(message)]
+        pub fn update(&mut self, asset: AccountId) -> Result<(), Error> {
+            match OracleRef::price_builder(&self.data.oracle, asset).try_invoke() {
+                Ok(Ok(Ok(value))) => {
+                    self.data.last = value;
+                }
+                Ok(Ok(Err(oracle::Error::Custom(reason)))) => {
+                    return Err(Error::Custom(reason));
+                }
+                Err(ink::env::Error::CalleeTrapped) => {
+                    let code: u128 = Default::default();
+                    self.data.last = code;
+                }
+                Ok(Ok(Err(error))) => {
+                    return Err(Error::from(error));
+                }
+                _ => {
+                    return Err(Error::Custom(String::from("Cross-contract call failed")));
+                }
+            }
+            Ok(())
+        }
+
+    }
+}
@@ -0,0 +1,118 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///sol2ink: --u256
+#[openbrush::contract]
+pub mod wide {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use primitive_types::U256;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub total: U256,
+        pub delta: i128,
+        pub step: u128,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Wide {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Wide {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance.data.total = U256::from(1);
+                instance.data.step = 2;
+            })
+        }
+
+        #[ink(message)]
+        pub fn grow(&mut self, amount: u128) -> Result<U256, Error> {
+            let limit: U256 = U256::MAX
+                .checked_sub(U256::from(2).pow(U256::from(200)))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            if self.data.total < U256::from(10) {
+                self.data.total = self
+                    .data
+                    .total
+                    .checked_pow(U256::from(2))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?
+                    .checked_add(U256::from(amount))
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            }
+            self.data.total = self
+                .data
+                .total
+                .checked_add(U256::from_dec_str("340282366920938463463374607431768211456").unwrap())
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            let low: u64 = (limit.low_u128() as u64);
+            let packed: [u8; 32] = {
+                let mut bytes = [0; 32];
+                self.data.total.to_big_endian(&mut bytes);
+                bytes
+            };
+            self.data.total = U256::from(packed);
+            return Ok(if limit > self.data.total {
+                limit
+            } else {
+                U256::from(0)
+            });
+        }
+
+        #[ink(message)]
+        pub fn mix(&mut self, amount: u128) -> Result<U256, Error> {
+            let sum: U256 = self
+                .data
+                .total
+                .checked_add(U256::from(amount))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            let stepped: U256 = U256::from(
+                amount
+                    .checked_mul(self.data.step)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            )
+            .checked_add(sum)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.total = self
+                .data
+                .total
+                .checked_add(U256::from(amount))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            if U256::from(amount) > self.data.total {
+                return Ok(U256::from(amount));
+            }
+            let scaled: U256 = U256::from(
+                amount
+                    .checked_mul(2)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            );
+            return Ok(if stepped > scaled {
+                stepped
+            } else {
+                U256::from(amount)
+            });
+        }
+
+    }
+}
@@ -0,0 +1,29 @@
+// sol2ink: --u256
+contract Wide {
+    uint256 total = 1;
+    int256 delta;
+    uint128 step = 2;
+
+    function grow(uint128 amount) public returns (uint256) {
+        uint256 limit = type(uint256).max - 2 ** 200;
+        if (total < 10) {
+            total = total ** 2 + uint256(amount);
+        }
+        total += 340282366920938463463374607431768211456;
+        uint64 low = uint64(limit);
+        bytes32 packed = bytes32(total);
+        total = uint256(packed);
+        return limit > total ? limit : 0;
+    }
+
+    function mix(uint128 amount) public returns (uint256) {
+        uint256 sum = total + amount;
+        uint256 stepped = amount * step + sum;
+        total += amount;
+        if (amount > total) {
+            return amount;
+        }
+        uint256 scaled = amount * 2;
+        return stepped > scaled ? stepped : amount;
+    }
+}
@@ -0,0 +1,98 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+///sol2ink: --target ink4 --u256
+#[ink::contract]
+pub mod wide {
+    use ink::prelude::string::String;
+    use ink::storage::Mapping;
+    use openbrush::traits::Storage;
+    use primitive_types::U256;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+    pub const NAME: &'static str = "Wide";
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub step: u128,
+        pub supply: U256,
+        pub balances: Mapping<AccountId, U256>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Wide {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Wide {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            instance.data.step = 2;
+            instance
+        }
+
+        #[ink(message)]
+        pub fn mix(&self, amount: u128, total: U256) -> Result<U256, Error> {
+            let sum: U256 = total
+                .checked_add(U256::from(amount))
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            let stepped: U256 = U256::from(
+                amount
+                    .checked_mul(self.data.step)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            )
+            .checked_add(sum)
+            .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            if U256::from(amount) > total {
+                return Ok(U256::from(amount));
+            }
+            let scaled: U256 = U256::from(
+                amount
+                    .checked_mul(2)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?,
+            );
+            return Ok(if stepped > scaled {
+                stepped
+            } else {
+                U256::from(amount)
+            });
+        }
+
+        #[ink(message)]
+        pub fn mint(&mut self, amount: U256) -> Result<String, Error> {
+            self.data.supply = self
+                .data
+                .supply
+                .checked_add(amount)
+                .ok_or(Error::Custom(String::from("Arithmetic overflow")))?;
+            self.data.balances.insert(
+                &Self::env().caller(),
+                &(self
+                    .data
+                    .balances
+                    .get(&Self::env().caller())
+                    .unwrap_or_default()
+                    .checked_add(amount)
+                    .ok_or(Error::Custom(String::from("Arithmetic overflow")))?),
+            );
+            return Ok(String::from(NAME));
+        }
+
+    }
+}
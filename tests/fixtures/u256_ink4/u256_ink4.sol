@@ -0,0 +1,23 @@
+// sol2ink: --target ink4 --u256
+contract Wide {
+    string constant NAME = "Wide";
+    uint128 step = 2;
+    uint256 supply;
+    mapping(address => uint256) balances;
+
+    function mix(uint128 amount, uint256 total) public view returns (uint256) {
+        uint256 sum = total + amount;
+        uint256 stepped = amount * step + sum;
+        if (amount > total) {
+            return amount;
+        }
+        uint256 scaled = amount * 2;
+        return stepped > scaled ? stepped : amount;
+    }
+
+    function mint(uint256 amount) public returns (string memory) {
+        supply += amount;
+        balances[msg.sender] += amount;
+        return NAME;
+    }
+}
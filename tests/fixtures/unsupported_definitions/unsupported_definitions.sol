@@ -0,0 +1,15 @@
+pragma solidity ^0.8.19;
+
+uint256 constant LIMIT = 10;
+type Price is uint128;
+using Math for uint256 global;
+
+library Math {
+    function twice(uint256 a) internal pure returns (uint256) {
+        return a * 2;
+    }
+}
+
+contract Shop {
+    uint256 total;
+}
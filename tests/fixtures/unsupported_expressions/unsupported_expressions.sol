@@ -0,0 +1,9 @@
+contract Child {}
+
+contract Factory {
+    Child child;
+
+    function create() public {
+        child = new Child();
+    }
+}
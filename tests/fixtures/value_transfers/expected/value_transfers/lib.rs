@@ -0,0 +1,62 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![feature(min_specialization)]
+
+// Generated with Sol2Ink v1.0.0
+// https://github.com/Supercolony-net/sol2ink
+
+#[openbrush::contract]
+pub mod vault {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use openbrush::traits::Storage;
+    use scale::Decode;
+    use scale::Encode;
+
+    #[derive(Debug, Encode, Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        Custom(String),
+    }
+
+
+    pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+    #[derive(Default, Debug)]
+    #[openbrush::upgradeable_storage(STORAGE_KEY)]
+    pub struct Data {
+        pub owner: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, SpreadAllocate, Storage)]
+    pub struct Vault {
+        #[storage_field]
+        data: Data,
+    }
+
+    impl Vault {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
+        }
+
+        #[ink(message, payable)]
+        pub fn receive(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn withdraw(&mut self, to: AccountId, amount: u128) -> Result<(), Error> {
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| Error::Custom(String::from("Transfer failed")))?;
+            let sent: bool = self.env().transfer(self.data.owner, amount).is_ok();
+            let success: bool = self.env().transfer(to, amount).is_ok();
+            if !success {
+                return Err(Error::Custom(String::from("failed")));
+            }
+            Ok(())
+        }
+
+    }
+}
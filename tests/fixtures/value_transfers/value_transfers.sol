@@ -0,0 +1,12 @@
+contract Vault {
+    address payable owner;
+
+    receive() external payable {}
+
+    function withdraw(address to, uint256 amount) public {
+        payable(to).transfer(amount);
+        bool sent = owner.send(amount);
+        (bool success, ) = to.call{value: amount}("");
+        require(success, "failed");
+    }
+}